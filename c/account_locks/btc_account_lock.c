@@ -0,0 +1,247 @@
+#include "blake2b.h"
+#include "ckb_syscalls.h"
+#include "gw_syscalls.h"
+#include "ripemd160.h"
+#include "secp256k1_helper.h"
+#include "stdio.h"
+
+/* Defines */
+#define HEADER_INDEX 0
+#define BLAKE2B_BLOCK_SIZE 32
+#define BLAKE160_SIZE 20
+#define SCRIPT_SIZE 32768
+#define MAX_WITNESS_SIZE 32768
+#define PUBKEY_SIZE 65
+#define COMPRESSED_PUBKEY_SIZE 33
+#define SHA256_SIZE 32
+#define SIGNATURE_SIZE 65
+/* Errors */
+#define ERROR_ARGUMENTS_LEN -1
+#define ERROR_ENCODING -2
+#define ERROR_SYSCALL -3
+#define ERROR_SECP_RECOVER_PUBKEY -11
+#define ERROR_SECP_VERIFICATION -12
+#define ERROR_SECP_PARSE_PUBKEY -13
+#define ERROR_SECP_PARSE_SIGNATURE -14
+#define ERROR_SECP_SERIALIZE_PUBKEY -15
+#define ERROR_SCRIPT_TOO_LONG -21
+#define ERROR_WITNESS_SIZE -22
+#define ERROR_INCORRECT_SINCE_FLAGS -23
+#define ERROR_INCORRECT_SINCE_VALUE -24
+#define ERROR_MESSAGE_SIZE -25
+#define ERROR_PUBKEY_BLAKE160_HASH -31
+/* Others */
+/* varint(len(prefix)) | prefix */
+#define BTC_SIGNING_PREFIX                                                     \
+  ("\x18"                                                                      \
+   "Bitcoin Signed Message:\n")
+/* the message is signed in hex */
+#define BTC_MESSAGE_SIZE (BLAKE2B_BLOCK_SIZE * 2)
+
+int load_pubkey_hash(uint8_t pubkey_hash[BLAKE160_SIZE]) {
+  unsigned char script[SCRIPT_SIZE];
+  uint64_t len = SCRIPT_SIZE;
+  int ret = ckb_load_script(script, &len, 0);
+  if (ret != CKB_SUCCESS) {
+    return ERROR_SYSCALL;
+  }
+  if (len > SCRIPT_SIZE) {
+    return ERROR_SCRIPT_TOO_LONG;
+  }
+  mol_seg_t script_seg;
+  script_seg.ptr = (uint8_t *)script;
+  script_seg.size = len;
+
+  if (MolReader_Script_verify(&script_seg, false) != MOL_OK) {
+    return ERROR_ENCODING;
+  }
+
+  mol_seg_t args_seg = MolReader_Script_get_args(&script_seg);
+  mol_seg_t args_bytes_seg = MolReader_Bytes_raw_bytes(&args_seg);
+  if (args_bytes_seg.size != BLAKE160_SIZE) {
+    return ERROR_ARGUMENTS_LEN;
+  }
+  memcpy(pubkey_hash, args_bytes_seg.ptr, BLAKE160_SIZE);
+  return 0;
+}
+
+/* Extract lock from WitnessArgs */
+int extract_witness_lock(uint8_t *witness, uint64_t len,
+                         mol_seg_t *lock_bytes_seg) {
+  mol_seg_t witness_seg;
+  witness_seg.ptr = witness;
+  witness_seg.size = len;
+
+  if (MolReader_WitnessArgs_verify(&witness_seg, false) != MOL_OK) {
+    return ERROR_ENCODING;
+  }
+  mol_seg_t lock_seg = MolReader_WitnessArgs_get_lock(&witness_seg);
+
+  if (MolReader_BytesOpt_is_none(&lock_seg)) {
+    return ERROR_ENCODING;
+  }
+  *lock_bytes_seg = MolReader_Bytes_raw_bytes(&lock_seg);
+  return 0;
+}
+
+/* Load message from cell's data */
+int load_message(uint8_t message[BLAKE2B_BLOCK_SIZE]) {
+  uint64_t len = BLAKE2B_BLOCK_SIZE;
+  int ret =
+      ckb_checked_load_cell_data(message, &len, 0, 0, CKB_SOURCE_GROUP_INPUT);
+  if (ret != CKB_SUCCESS) {
+    return ERROR_SYSCALL;
+  }
+
+  if (len != BLAKE2B_BLOCK_SIZE) {
+    return ERROR_MESSAGE_SIZE;
+  }
+
+  return 0;
+}
+
+/* load signature from witness */
+int load_signature_from_witness(uint8_t signature[SIGNATURE_SIZE]) {
+  uint8_t temp[MAX_WITNESS_SIZE] = {0};
+  // Load the first witness, or the witness of the same index as the first input
+  // using current script.
+  uint64_t witness_len = MAX_WITNESS_SIZE;
+  int ret = ckb_load_witness(temp, &witness_len, 0, 0, CKB_SOURCE_GROUP_INPUT);
+  if (ret != CKB_SUCCESS) {
+    return ERROR_SYSCALL;
+  }
+
+  if (witness_len > MAX_WITNESS_SIZE) {
+    return ERROR_WITNESS_SIZE;
+  }
+
+  // We treat the first witness as WitnessArgs, and extract the lock field
+  mol_seg_t lock_bytes_seg;
+  ret = extract_witness_lock(temp, witness_len, &lock_bytes_seg);
+  if (ret != 0) {
+    return ERROR_ENCODING;
+  }
+
+  if (lock_bytes_seg.size != SIGNATURE_SIZE) {
+    return ERROR_ENCODING;
+  }
+
+  memcpy(signature, lock_bytes_seg.ptr, SIGNATURE_SIZE);
+
+  return 0;
+}
+
+/* sha256(sha256(varint(len(prefix)) | prefix | varint(len(msg)) | msg)) */
+void calculate_signing_message(unsigned char msg[BLAKE2B_BLOCK_SIZE],
+                               unsigned char signing_message[SHA256_SIZE]) {
+  const char *hex_chars = "0123456789abcdef";
+  unsigned char data[sizeof(BTC_SIGNING_PREFIX) - 1 + 1 + BTC_MESSAGE_SIZE] =
+      BTC_SIGNING_PREFIX;
+  size_t offset = sizeof(BTC_SIGNING_PREFIX) - 1;
+  data[offset++] = BTC_MESSAGE_SIZE;
+  for (int i = 0; i < BLAKE2B_BLOCK_SIZE; i++) {
+    data[offset++] = hex_chars[msg[i] >> 4];
+    data[offset++] = hex_chars[msg[i] & 0xf];
+  }
+  unsigned char hash[SHA256_SIZE];
+  secp256k1_sha256 hasher;
+  secp256k1_sha256_initialize(&hasher);
+  secp256k1_sha256_write(&hasher, data, sizeof(data));
+  secp256k1_sha256_finalize(&hasher, hash);
+  secp256k1_sha256_initialize(&hasher);
+  secp256k1_sha256_write(&hasher, hash, SHA256_SIZE);
+  secp256k1_sha256_finalize(&hasher, signing_message);
+}
+
+/* recover pubkey from the signature: header | r | s,
+ * header is 27 + recid, plus 4 if the pubkey is compressed */
+int recover_pubkey(unsigned char recovered_pubkey[PUBKEY_SIZE],
+                   size_t *pubkey_size, unsigned char sig[SIGNATURE_SIZE],
+                   unsigned char msg[BLAKE2B_BLOCK_SIZE]) {
+  secp256k1_context context;
+  uint8_t secp_data[CKB_SECP256K1_DATA_SIZE];
+  int ret = ckb_secp256k1_custom_load_data(secp_data);
+  if (ret != 0) {
+    return ret;
+  }
+  ret = ckb_secp256k1_custom_verify_only_initialize(&context, secp_data);
+  if (ret != 0) {
+    return ret;
+  }
+
+  uint8_t header = sig[HEADER_INDEX];
+  if (header < 27 || header > 34) {
+    return ERROR_SECP_PARSE_SIGNATURE;
+  }
+  int compressed = header >= 31;
+  int recid = (header - 27) & 3;
+  secp256k1_ecdsa_recoverable_signature signature;
+  if (secp256k1_ecdsa_recoverable_signature_parse_compact(
+          &context, &signature, sig + 1, recid) == 0) {
+    return ERROR_SECP_PARSE_SIGNATURE;
+  }
+
+  unsigned char signing_message[SHA256_SIZE];
+  calculate_signing_message(msg, signing_message);
+
+  // From the recoverable signature, we can derive the public key used.
+  secp256k1_pubkey pubkey;
+  if (secp256k1_ecdsa_recover(&context, &pubkey, &signature,
+                              signing_message) != 1) {
+    return ERROR_SECP_RECOVER_PUBKEY;
+  }
+
+  *pubkey_size = compressed ? COMPRESSED_PUBKEY_SIZE : PUBKEY_SIZE;
+  if (secp256k1_ec_pubkey_serialize(
+          &context, recovered_pubkey, pubkey_size, &pubkey,
+          compressed ? SECP256K1_EC_COMPRESSED : SECP256K1_EC_UNCOMPRESSED) !=
+      1) {
+    return ERROR_SECP_SERIALIZE_PUBKEY;
+  }
+
+  return 0;
+}
+
+int main() {
+  /* Load pubkey hash */
+  uint8_t pubkey_hash[BLAKE160_SIZE] = {0};
+  int ret = load_pubkey_hash(pubkey_hash);
+  if (ret != 0) {
+    return ret;
+  }
+  /* Load signature */
+  uint8_t signature[SIGNATURE_SIZE] = {0};
+  ret = load_signature_from_witness(signature);
+  if (ret != 0) {
+    return ret;
+  }
+
+  /* Load message */
+  uint8_t message[BLAKE2B_BLOCK_SIZE] = {0};
+  ret = load_message(message);
+  if (ret != 0) {
+    return ret;
+  }
+
+  /* recover pubkey */
+  uint8_t recovered_pubkey[PUBKEY_SIZE] = {0};
+  size_t pubkey_size = 0;
+  ret = recover_pubkey(recovered_pubkey, &pubkey_size, signature, message);
+  if (ret != 0) {
+    return ret;
+  }
+
+  /* check pubkey hash: ripemd160(sha256(pubkey)) */
+  unsigned char pubkey_sha256[SHA256_SIZE];
+  secp256k1_sha256 hasher;
+  secp256k1_sha256_initialize(&hasher);
+  secp256k1_sha256_write(&hasher, recovered_pubkey, pubkey_size);
+  secp256k1_sha256_finalize(&hasher, pubkey_sha256);
+  uint8_t recovered_pubkey_hash[RIPEMD160_SIZE];
+  ripemd160(pubkey_sha256, SHA256_SIZE, recovered_pubkey_hash);
+  if (memcmp(pubkey_hash, recovered_pubkey_hash, BLAKE160_SIZE) != 0) {
+    return ERROR_PUBKEY_BLAKE160_HASH;
+  }
+
+  return CKB_SUCCESS;
+}
@@ -0,0 +1,133 @@
+#ifndef GW_RIPEMD160_H_
+#define GW_RIPEMD160_H_
+
+/* A minimal RIPEMD-160 implementation, used to calculate Bitcoin pubkey hash
+ */
+
+#include <stddef.h>
+#include <stdint.h>
+#include <string.h>
+
+#define RIPEMD160_SIZE 20
+
+static const uint8_t RIPEMD160_R[80] = {
+    0, 1,  2,  3,  4,  5,  6,  7,  8,  9,  10, 11, 12, 13, 14, 15,
+    7, 4,  13, 1,  10, 6,  15, 3,  12, 0,  9,  5,  2,  14, 11, 8,
+    3, 10, 14, 4,  9,  15, 8,  1,  2,  7,  0,  6,  13, 11, 5,  12,
+    1, 9,  11, 10, 0,  8,  12, 4,  13, 3,  7,  15, 14, 5,  6,  2,
+    4, 0,  5,  9,  7,  12, 2,  10, 14, 1,  3,  8,  11, 6,  15, 13};
+
+static const uint8_t RIPEMD160_RR[80] = {
+    5,  14, 7,  0,  9, 2,  11, 4,  13, 6,  15, 8,  1,  10, 3,  12,
+    6,  11, 3,  7,  0, 13, 5,  10, 14, 15, 8,  12, 4,  9,  1,  2,
+    15, 5,  1,  3,  7, 14, 6,  9,  11, 8,  12, 2,  10, 0,  4,  13,
+    8,  6,  4,  1,  3, 11, 15, 0,  5,  12, 2,  13, 9,  7,  10, 14,
+    12, 15, 10, 4,  1, 5,  8,  7,  6,  2,  13, 14, 0,  3,  9,  11};
+
+static const uint8_t RIPEMD160_S[80] = {
+    11, 14, 15, 12, 5,  8,  7,  9,  11, 13, 14, 15, 6,  7,  9,  8,
+    7,  6,  8,  13, 11, 9,  7,  15, 7,  12, 15, 9,  11, 7,  13, 12,
+    11, 13, 6,  7,  14, 9,  13, 15, 14, 8,  13, 6,  5,  12, 7,  5,
+    11, 12, 14, 15, 14, 15, 9,  8,  9,  14, 5,  6,  8,  6,  5,  12,
+    9,  15, 5,  11, 6,  8,  13, 12, 5,  12, 13, 14, 11, 8,  5,  6};
+
+static const uint8_t RIPEMD160_SS[80] = {
+    8,  9,  9,  11, 13, 15, 15, 5,  7,  7,  8,  11, 14, 14, 12, 6,
+    9,  13, 15, 7,  12, 8,  9,  11, 7,  7,  12, 7,  6,  15, 13, 11,
+    9,  7,  15, 11, 8,  6,  6,  14, 12, 13, 5,  14, 13, 13, 7,  5,
+    15, 5,  8,  11, 14, 14, 6,  14, 6,  9,  12, 9,  12, 5,  15, 8,
+    8,  5,  12, 9,  12, 5,  14, 6,  8,  13, 6,  5,  15, 13, 11, 11};
+
+static const uint32_t RIPEMD160_K[5] = {0x00000000, 0x5A827999, 0x6ED9EBA1,
+                                        0x8F1BBCDC, 0xA953FD4E};
+static const uint32_t RIPEMD160_KK[5] = {0x50A28BE6, 0x5C4DD124, 0x6D703EF3,
+                                         0x7A6D76E9, 0x00000000};
+
+static inline uint32_t _ripemd160_rol(uint32_t x, uint32_t n) {
+  return (x << n) | (x >> (32 - n));
+}
+
+static inline uint32_t _ripemd160_f(int j, uint32_t x, uint32_t y,
+                                    uint32_t z) {
+  switch (j / 16) {
+    case 0:
+      return x ^ y ^ z;
+    case 1:
+      return (x & y) | (~x & z);
+    case 2:
+      return (x | ~y) ^ z;
+    case 3:
+      return (x & z) | (y & ~z);
+    default:
+      return x ^ (y | ~z);
+  }
+}
+
+static void _ripemd160_compress(uint32_t h[5], const uint8_t block[64]) {
+  uint32_t x[16];
+  for (int i = 0; i < 16; i++) {
+    x[i] = (uint32_t)block[i * 4] | ((uint32_t)block[i * 4 + 1] << 8) |
+           ((uint32_t)block[i * 4 + 2] << 16) |
+           ((uint32_t)block[i * 4 + 3] << 24);
+  }
+  uint32_t al = h[0], bl = h[1], cl = h[2], dl = h[3], el = h[4];
+  uint32_t ar = h[0], br = h[1], cr = h[2], dr = h[3], er = h[4];
+  for (int j = 0; j < 80; j++) {
+    uint32_t t = _ripemd160_rol(al + _ripemd160_f(j, bl, cl, dl) +
+                                    x[RIPEMD160_R[j]] + RIPEMD160_K[j / 16],
+                                RIPEMD160_S[j]) +
+                 el;
+    al = el;
+    el = dl;
+    dl = _ripemd160_rol(cl, 10);
+    cl = bl;
+    bl = t;
+    t = _ripemd160_rol(ar + _ripemd160_f(79 - j, br, cr, dr) +
+                           x[RIPEMD160_RR[j]] + RIPEMD160_KK[j / 16],
+                       RIPEMD160_SS[j]) +
+        er;
+    ar = er;
+    er = dr;
+    dr = _ripemd160_rol(cr, 10);
+    cr = br;
+    br = t;
+  }
+  uint32_t t = h[1] + cl + dr;
+  h[1] = h[2] + dl + er;
+  h[2] = h[3] + el + ar;
+  h[3] = h[4] + al + br;
+  h[4] = h[0] + bl + cr;
+  h[0] = t;
+}
+
+void ripemd160(const uint8_t *data, size_t len,
+               uint8_t output[RIPEMD160_SIZE]) {
+  uint32_t h[5] = {0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476,
+                   0xC3D2E1F0};
+  size_t offset = 0;
+  for (; offset + 64 <= len; offset += 64) {
+    _ripemd160_compress(h, data + offset);
+  }
+  /* padding: 0x80, zeros, then the bit length in little endian */
+  uint8_t block[128] = {0};
+  size_t remain = len - offset;
+  memcpy(block, data + offset, remain);
+  block[remain] = 0x80;
+  size_t block_len = remain + 9 > 64 ? 128 : 64;
+  uint64_t bit_len = (uint64_t)len * 8;
+  for (int i = 0; i < 8; i++) {
+    block[block_len - 8 + i] = (uint8_t)(bit_len >> (i * 8));
+  }
+  _ripemd160_compress(h, block);
+  if (block_len == 128) {
+    _ripemd160_compress(h, block + 64);
+  }
+  for (int i = 0; i < 5; i++) {
+    output[i * 4] = (uint8_t)h[i];
+    output[i * 4 + 1] = (uint8_t)(h[i] >> 8);
+    output[i * 4 + 2] = (uint8_t)(h[i] >> 16);
+    output[i * 4 + 3] = (uint8_t)(h[i] >> 24);
+  }
+}
+
+#endif /* GW_RIPEMD160_H_ */
@@ -8,8 +8,6 @@
 #include "gw_errors.h"
 #include "stddef.h"
 
-typedef unsigned __int128 uint128_t;
-
 /* common functions */
 
 /* Implement of gw_blake2b_hash_fn
@@ -51,6 +49,16 @@ void gw_build_nonce_key(uint32_t id, uint8_t key[GW_KEY_BYTES]) {
   gw_build_account_field_key(id, GW_ACCOUNT_NONCE, key);
 }
 
+/* Same as build_sudt_allowance_key in gw-common
+ * key: owner_id | GW_SUDT_ALLOWANCE | spender_id
+ */
+void gw_build_sudt_allowance_key(uint32_t owner_id, uint32_t spender_id,
+                                 uint8_t key[GW_KEY_BYTES]) {
+  gw_build_account_field_key(owner_id, GW_SUDT_ALLOWANCE, key);
+  memcpy(key + sizeof(uint32_t) + 1, (uint8_t *)(&spender_id),
+         sizeof(uint32_t));
+}
+
 void gw_build_script_hash_to_account_id_key(uint8_t script_hash[GW_KEY_BYTES],
                                             uint8_t raw_key[GW_KEY_BYTES]) {
   uint8_t type = GW_ACCOUNT_SCRIPT_HASH_TO_ID;
@@ -61,6 +69,27 @@ void gw_build_script_hash_to_account_id_key(uint8_t script_hash[GW_KEY_BYTES],
   blake2b_final(&blake2b_ctx, raw_key, GW_KEY_BYTES);
 }
 
+int _is_zero_hash(const uint8_t hash[32]) {
+  for (int i = 0; i < 32; i++) {
+    if (hash[i] != 0) {
+      return 0;
+    }
+  }
+  return 1;
+}
+
+/* Same as build_short_script_hash_to_script_hash_key in gw-common */
+void gw_build_short_script_hash_to_script_hash_key(
+    const uint8_t short_script_hash[GW_SHORT_SCRIPT_HASH_LEN],
+    uint8_t raw_key[GW_KEY_BYTES]) {
+  uint8_t prefix[5] = {0, 0, 0, 0, GW_SHORT_SCRIPT_HASH_TO_SCRIPT_HASH};
+  blake2b_state blake2b_ctx;
+  blake2b_init(&blake2b_ctx, GW_KEY_BYTES);
+  blake2b_update(&blake2b_ctx, prefix, 5);
+  blake2b_update(&blake2b_ctx, short_script_hash, GW_SHORT_SCRIPT_HASH_LEN);
+  blake2b_final(&blake2b_ctx, raw_key, GW_KEY_BYTES);
+}
+
 int gw_parse_transaction_context(gw_transaction_context_t *transaction_context,
                                  mol_seg_t *src) {
   if (MolReader_RawL2Transaction_verify(src, false) != MOL_OK) {
@@ -12,6 +12,8 @@
  *
  * * query(account_id) -> balance
  * * transfer(to, amount, fee)
+ * * approve(spender, amount): the spender contract can transfer the amount
+ *   from the sender by the transfer sUDT syscall
  *
  * # Mint & Burn
  *
@@ -35,6 +37,7 @@
 /* MSG_TYPE */
 #define MSG_QUERY 0
 #define MSG_TRANSFER 1
+#define MSG_APPROVE 2
 
 int main() {
   /* initialize context */
@@ -89,6 +92,17 @@ int main() {
     if (ret != 0) {
       return ret;
     }
+  } else if (msg.item_id == MSG_APPROVE) {
+    /* Approve */
+    mol_seg_t spender_seg = MolReader_SUDTApprove_get_spender(&msg.seg);
+    mol_seg_t amount_seg = MolReader_SUDTApprove_get_amount(&msg.seg);
+    uint32_t owner_id = ctx.transaction_context.from_id;
+    uint32_t spender_id = *(uint32_t *)spender_seg.ptr;
+    uint128_t amount = *(uint128_t *)amount_seg.ptr;
+    int ret = sudt_approve(&ctx, sudt_id, owner_id, spender_id, amount);
+    if (ret != 0) {
+      return ret;
+    }
   } else {
     return GW_ERROR_UNKNOWN_ARGS;
   }
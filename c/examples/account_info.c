@@ -0,0 +1,83 @@
+/*
+ * The AccountInfo is a layer2 contract example of the account queries.
+ *
+ * The transaction's args:
+ * - query (1 byte), followed by the query args:
+ *   - 0 nonce: account_id (4 bytes)
+ *   - 1 balance: sudt_id (4 bytes), account_id (4 bytes)
+ *   - 2 script hash: short script hash (20 bytes)
+ *   - 3 account count
+ *
+ * The result of the query is set as the return data, the integers are
+ * encoded in little endian.
+ */
+
+#include "ckb_syscalls.h"
+#include "gw_syscalls.h"
+#include "stdio.h"
+
+#define ERROR_INVALID_DATA 10
+
+#define QUERY_NONCE 0
+#define QUERY_BALANCE 1
+#define QUERY_SCRIPT_HASH 2
+#define QUERY_ACCOUNT_COUNT 3
+
+int main() {
+  gw_context_t ctx = {0};
+  int ret = gw_context_init(&ctx);
+  if (ret != 0) {
+    return ret;
+  }
+  uint32_t args_len = ctx.transaction_context.args_len;
+  if (args_len < 1) {
+    return ERROR_INVALID_DATA;
+  }
+  uint8_t *args = ctx.transaction_context.args + 1;
+  args_len -= 1;
+
+  uint8_t result[32] = {0};
+  uint32_t result_len = 0;
+  switch (ctx.transaction_context.args[0]) {
+    case QUERY_NONCE: {
+      if (args_len != 4) {
+        return ERROR_INVALID_DATA;
+      }
+      ret = ctx.sys_get_account_nonce(&ctx, *(uint32_t *)args,
+                                      (uint32_t *)result);
+      result_len = 4;
+      break;
+    }
+    case QUERY_BALANCE: {
+      if (args_len != 8) {
+        return ERROR_INVALID_DATA;
+      }
+      ret = ctx.sys_get_sudt_balance(&ctx, *(uint32_t *)args,
+                                     *(uint32_t *)(args + 4),
+                                     (uint128_t *)result);
+      result_len = 16;
+      break;
+    }
+    case QUERY_SCRIPT_HASH: {
+      ret = ctx.sys_get_script_hash_by_short_script_hash(&ctx, args, args_len,
+                                                         result);
+      result_len = 32;
+      break;
+    }
+    case QUERY_ACCOUNT_COUNT: {
+      ret = ctx.sys_get_account_count(&ctx, (uint32_t *)result);
+      result_len = 4;
+      break;
+    }
+    default:
+      return ERROR_INVALID_DATA;
+  }
+  if (ret != 0) {
+    return ret;
+  }
+  ret = ctx.sys_set_program_return_data(&ctx, result, result_len);
+  if (ret != 0) {
+    return ret;
+  }
+  return gw_finalize(&ctx);
+}
@@ -0,0 +1,71 @@
+/*
+ * The Proxy is a layer2 contract example of contract-to-contract calls.
+ *
+ * The transaction's args:
+ * - flags (1 byte): bit 0 set means the proxy fails if the callee fails
+ * - to_id (4 bytes): the callee account
+ * - the remaining bytes: the args passed to the callee
+ *
+ * The proxy counts the calls in its own state, then calls the callee and
+ * returns the callee's exit code (1 byte) followed by the callee's return data.
+ * sys_call returns GW_ERROR_NOT_SUPPORTED for now, so the callee isn't executed.
+ */
+
+#include "ckb_syscalls.h"
+#include "gw_syscalls.h"
+#include "stdio.h"
+
+#define ERROR_INVALID_DATA 10
+#define PROPAGATE_FAILURE 1
+#define ARGS_HEADER_SIZE 5
+#define MAX_RETURN_DATA_SIZE 1024
+
+int increase_calls(gw_context_t *ctx);
+
+int main() {
+  gw_context_t ctx = {0};
+  int ret = gw_context_init(&ctx);
+  if (ret != 0) {
+    return ret;
+  }
+  if (ctx.transaction_context.args_len < ARGS_HEADER_SIZE) {
+    return ERROR_INVALID_DATA;
+  }
+  uint8_t flags = ctx.transaction_context.args[0];
+  uint32_t to_id = *(uint32_t *)(ctx.transaction_context.args + 1);
+  ret = increase_calls(&ctx);
+  if (ret != 0) {
+    return ret;
+  }
+
+  uint8_t return_data[MAX_RETURN_DATA_SIZE + 1] = {0};
+  uint64_t return_data_len = MAX_RETURN_DATA_SIZE;
+  int call_ret =
+      ctx.sys_call(&ctx, to_id, ctx.transaction_context.args + ARGS_HEADER_SIZE,
+                   ctx.transaction_context.args_len - ARGS_HEADER_SIZE,
+                   return_data + 1, &return_data_len);
+  if (call_ret != 0 && (flags & PROPAGATE_FAILURE)) {
+    return call_ret;
+  }
+  if (return_data_len > MAX_RETURN_DATA_SIZE) {
+    return_data_len = MAX_RETURN_DATA_SIZE;
+  }
+  return_data[0] = (uint8_t)call_ret;
+  ret = ctx.sys_set_program_return_data(&ctx, return_data, return_data_len + 1);
+  if (ret != 0) {
+    return ret;
+  }
+  return gw_finalize(&ctx);
+}
+
+int increase_calls(gw_context_t *ctx) {
+  uint8_t key[GW_KEY_BYTES];
+  blake2b_hash(key, (uint8_t *)"calls", 5);
+  uint8_t value[GW_VALUE_BYTES];
+  int ret = ctx->sys_load(ctx, ctx->transaction_context.to_id, key, value);
+  if (ret != 0) {
+    return ret;
+  }
+  *(uint64_t *)value += 1;
+  return ctx->sys_store(ctx, ctx->transaction_context.to_id, key, value);
+}
@@ -0,0 +1,42 @@
+/*
+ * The Transfer is a layer2 contract example of the sUDT transfer syscall.
+ *
+ * The transaction's args:
+ * - sudt_id (4 bytes)
+ * - from_id (4 bytes): the contract itself or an account approved the contract
+ * - to_id (4 bytes)
+ * - amount (16 bytes)
+ *
+ * The contract returns the error code of the transfer (1 byte) as the return
+ * data, so the failed transfers can be observed.
+ */
+
+#include "ckb_syscalls.h"
+#include "gw_syscalls.h"
+#include "stdio.h"
+
+#define ERROR_INVALID_DATA 10
+#define ARGS_SIZE 28
+
+int main() {
+  gw_context_t ctx = {0};
+  int ret = gw_context_init(&ctx);
+  if (ret != 0) {
+    return ret;
+  }
+  if (ctx.transaction_context.args_len != ARGS_SIZE) {
+    return ERROR_INVALID_DATA;
+  }
+  uint8_t *args = ctx.transaction_context.args;
+  uint32_t sudt_id = *(uint32_t *)args;
+  uint32_t from_id = *(uint32_t *)(args + 4);
+  uint32_t to_id = *(uint32_t *)(args + 8);
+  uint128_t amount = *(uint128_t *)(args + 12);
+  uint8_t transfer_ret =
+      (uint8_t)ctx.sys_transfer_sudt(&ctx, sudt_id, from_id, to_id, amount);
+  ret = ctx.sys_set_program_return_data(&ctx, &transfer_ret, 1);
+  if (ret != 0) {
+    return ret;
+  }
+  return gw_finalize(&ctx);
+}
@@ -19,6 +19,7 @@
 #define GW_SYS_LOAD 3052
 #define GW_SYS_SET_RETURN_DATA 3061
 #define GW_SYS_CREATE 3071
+#define GW_SYS_CALL 3081
 /* internal syscall only for generator */
 #define GW_SYS_LOAD_TRANSACTION 4051
 #define GW_SYS_LOAD_BLOCKINFO 4052
@@ -29,6 +30,12 @@
 #define GW_SYS_LOAD_DATA 4057
 #define GW_SYS_GET_BLOCK_HASH 4058
 #define GW_SYS_LOG 4061
+#define GW_SYS_LOAD_NONCE 4062
+#define GW_SYS_LOAD_SUDT_BALANCE 4063
+#define GW_SYS_LOAD_SCRIPT_HASH_BY_SHORT_SCRIPT_HASH 4064
+#define GW_SYS_LOAD_ACCOUNT_COUNT 4065
+#define GW_SYS_TRANSFER_SUDT 4066
+#define GW_SYS_EMIT_LOG 4067
 
 typedef struct gw_context_t {
   /* verification context */
@@ -48,6 +55,13 @@ typedef struct gw_context_t {
   gw_store_data_fn sys_store_data;
   gw_get_block_hash_fn sys_get_block_hash;
   gw_log_fn sys_log;
+  gw_emit_log_fn sys_emit_log;
+  gw_call_fn sys_call;
+  gw_get_sudt_balance_fn sys_get_sudt_balance;
+  gw_get_script_hash_by_short_script_hash_fn
+      sys_get_script_hash_by_short_script_hash;
+  gw_get_account_count_fn sys_get_account_count;
+  gw_transfer_sudt_fn sys_transfer_sudt;
 } gw_context_t;
 
 int sys_load(gw_context_t *ctx, uint32_t account_id,
@@ -69,7 +83,8 @@ int sys_store(gw_context_t *ctx, uint32_t account_id,
   }
   uint8_t raw_key[GW_KEY_BYTES];
   gw_build_account_key(account_id, key, raw_key);
-  return syscall(GW_SYS_STORE, raw_key, value, 0, 0, 0, 0);
+  /* pass the preimage of the raw key to the generator */
+  return syscall(GW_SYS_STORE, raw_key, value, account_id, key, 0, 0);
 }
 
 int sys_load_nonce(gw_context_t *ctx, uint32_t account_id,
@@ -145,6 +160,48 @@ int sys_log(gw_context_t *ctx, uint32_t account_id, uint32_t data_length,
   return syscall(GW_SYS_LOG, account_id, data_length, data, 0, 0, 0);
 }
 
+int sys_emit_log(gw_context_t *ctx, uint32_t account_id, uint8_t log_type,
+                 const uint8_t *topics, uint32_t topics_count,
+                 uint32_t data_length, const uint8_t *data) {
+  return syscall(GW_SYS_EMIT_LOG, account_id, log_type, topics, topics_count,
+                 data_length, data);
+}
+
+int sys_call(gw_context_t *ctx, uint32_t to_id, const uint8_t *args,
+             uint32_t args_len, uint8_t *return_data,
+             uint64_t *return_data_len) {
+  return syscall(GW_SYS_CALL, to_id, args, args_len, return_data,
+                 return_data_len, 0);
+}
+
+int sys_get_account_nonce(gw_context_t *ctx, uint32_t account_id,
+                          uint32_t *nonce) {
+  return syscall(GW_SYS_LOAD_NONCE, account_id, nonce, 0, 0, 0, 0);
+}
+
+int sys_get_sudt_balance(gw_context_t *ctx, uint32_t sudt_id,
+                         uint32_t account_id, uint128_t *balance) {
+  return syscall(GW_SYS_LOAD_SUDT_BALANCE, sudt_id, account_id, balance, 0, 0,
+                 0);
+}
+
+int sys_get_script_hash_by_short_script_hash(gw_context_t *ctx,
+                                             const uint8_t *short_script_hash,
+                                             uint32_t len,
+                                             uint8_t script_hash[32]) {
+  return syscall(GW_SYS_LOAD_SCRIPT_HASH_BY_SHORT_SCRIPT_HASH,
+                 short_script_hash, len, script_hash, 0, 0, 0);
+}
+
+int sys_get_account_count(gw_context_t *ctx, uint32_t *count) {
+  return syscall(GW_SYS_LOAD_ACCOUNT_COUNT, count, 0, 0, 0, 0, 0);
+}
+
+int sys_transfer_sudt(gw_context_t *ctx, uint32_t sudt_id, uint32_t from_id,
+                      uint32_t to_id, uint128_t amount) {
+  return syscall(GW_SYS_TRANSFER_SUDT, sudt_id, from_id, to_id, &amount, 0, 0);
+}
+
 int gw_context_init(gw_context_t *ctx) {
   /* setup syscalls */
   ctx->sys_load = sys_load;
@@ -159,6 +216,14 @@ int gw_context_init(gw_context_t *ctx) {
   ctx->sys_load_data = sys_load_data;
   ctx->sys_get_block_hash = sys_get_block_hash;
   ctx->sys_log = sys_log;
+  ctx->sys_emit_log = sys_emit_log;
+  ctx->sys_call = sys_call;
+  ctx->sys_get_account_nonce = sys_get_account_nonce;
+  ctx->sys_get_sudt_balance = sys_get_sudt_balance;
+  ctx->sys_get_script_hash_by_short_script_hash =
+      sys_get_script_hash_by_short_script_hash;
+  ctx->sys_get_account_count = sys_get_account_count;
+  ctx->sys_transfer_sudt = sys_transfer_sudt;
 
   /* initialize context */
   uint8_t tx_buf[GW_MAX_L2TX_SIZE] = {0};
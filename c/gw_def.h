@@ -3,6 +3,8 @@
 
 #include "stddef.h"
 
+typedef unsigned __int128 uint128_t;
+
 #define GW_KEY_BYTES 32
 #define GW_VALUE_BYTES 32
 
@@ -11,6 +13,19 @@
 #define GW_ACCOUNT_NONCE 1
 #define GW_ACCOUNT_SCRIPT_HASH 2
 #define GW_ACCOUNT_SCRIPT_HASH_TO_ID 3
+#define GW_SHORT_SCRIPT_HASH_TO_SCRIPT_HASH 5
+
+/* sUDT storage flags */
+#define GW_SUDT_ALLOWANCE 1
+
+/* Length of the short script hash (the prefix of the script hash) */
+#define GW_SHORT_SCRIPT_HASH_LEN 20
+
+/* Log type */
+#define GW_LOG_CONTRACT 0
+#define GW_LOG_SUDT_TRANSFER 1
+#define GW_LOG_ACCOUNT_CREATED 2
+#define GW_MAX_LOG_TOPICS 4
 
 /* 24KB (ethereum max contract code size) */
 #define GW_MAX_RETURN_DATA_SIZE (24 * 1024)
@@ -150,6 +165,66 @@ typedef int (*gw_get_script_hash_by_account_id_fn)(struct gw_context_t *ctx,
 typedef int (*gw_get_account_nonce_fn)(struct gw_context_t *ctx,
                                        uint32_t account_id, uint32_t *nonce);
 
+/**
+ * Get the balance of an account
+ *
+ * @param ctx        The godwoken context
+ * @param sudt_id    The sUDT account id
+ * @param account_id The account id
+ * @param balance    The pointer of the balance to save the result
+ * @return           The status code, 0 is success
+ */
+typedef int (*gw_get_sudt_balance_fn)(struct gw_context_t *ctx,
+                                      uint32_t sudt_id, uint32_t account_id,
+                                      uint128_t *balance);
+
+/**
+ * Transfer sUDT, a transfer log is emitted
+ *
+ * The contract can transfer from itself, transferring from another account
+ * consumes the allowance the account approved to the contract in the sUDT,
+ * the sender of the transaction is not trusted.
+ *
+ * @param ctx        The godwoken context
+ * @param sudt_id    The sUDT account id, CKB is also a sUDT
+ * @param from_id    The account to transfer from
+ * @param to_id      The account to transfer to
+ * @param amount     The amount to transfer
+ * @return           The status code, 0 is success, GW_ERROR_PERMISSION_DENIED
+ *                   if the allowance of the contract is insufficient,
+ *                   GW_ERROR_INVALID_SUDT if sudt_id is not a sUDT account,
+ *                   and the same errors as sudt_transfer in sudt_utils.h
+ */
+typedef int (*gw_transfer_sudt_fn)(struct gw_context_t *ctx, uint32_t sudt_id,
+                                   uint32_t from_id, uint32_t to_id,
+                                   uint128_t amount);
+
+/**
+ * Get account script_hash by the short script hash
+ *
+ * @param ctx               The godwoken context
+ * @param short_script_hash The prefix of the script hash
+ * @param len               The length of short_script_hash, must be
+ *                          GW_SHORT_SCRIPT_HASH_LEN
+ * @param script_hash       The pointer of the account script hash to save the
+ *                          result
+ * @return                  The status code, 0 is success, GW_ERROR_NOT_FOUND
+ *                          if no account has the short script hash
+ */
+typedef int (*gw_get_script_hash_by_short_script_hash_fn)(
+    struct gw_context_t *ctx, const uint8_t *short_script_hash, uint32_t len,
+    uint8_t script_hash[32]);
+
+/**
+ * Get the count of accounts
+ *
+ * @param ctx        The godwoken context
+ * @param count      The pointer of the count to save the result
+ * @return           The status code, 0 is success
+ */
+typedef int (*gw_get_account_count_fn)(struct gw_context_t *ctx,
+                                       uint32_t *count);
+
 /**
  * Get account script by account id
  */
@@ -195,4 +270,41 @@ typedef int (*gw_get_block_hash_fn)(struct gw_context_t *ctx, uint64_t number,
 typedef int (*gw_log_fn)(struct gw_context_t *ctx, uint32_t account_id,
                          uint32_t data_length, const uint8_t *data);
 
+/**
+ * Emit a typed log with topics, logs can be filtered by the topics
+ *
+ * @param ctx            The godwoken context
+ * @param account_id     The account to emit log
+ * @param log_type       GW_LOG_CONTRACT or GW_LOG_SUDT_TRANSFER
+ * @param topics         The topics (32 bytes each)
+ * @param topics_count   The count of topics, at most GW_MAX_LOG_TOPICS
+ * @param data           The log data
+ * @param data_length    The length of the log data
+ * @return               The status code, 0 is success
+ */
+typedef int (*gw_emit_log_fn)(struct gw_context_t *ctx, uint32_t account_id,
+                              uint8_t log_type, const uint8_t *topics,
+                              uint32_t topics_count, uint32_t data_length,
+                              const uint8_t *data);
+
+/**
+ * Call another account
+ *
+ * Not supported yet, both the generator and the validator return
+ * GW_ERROR_NOT_SUPPORTED since the validator can't execute the program of
+ * another account.
+ *
+ * @param ctx             The godwoken context
+ * @param to_id           The callee account
+ * @param args            The args passed to the callee
+ * @param args_len        The length of the args
+ * @param return_data     The pointer to save the return data of the callee
+ * @param return_data_len The size of the return_data buffer as input, the
+ *                        full length of the return data as output
+ * @return                The exit code of the callee, 0 is success
+ */
+typedef int (*gw_call_fn)(struct gw_context_t *ctx, uint32_t to_id,
+                          const uint8_t *args, uint32_t args_len,
+                          uint8_t *return_data, uint64_t *return_data_len);
+
 #endif /* GW_DEF_H_ */
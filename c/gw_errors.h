@@ -11,6 +11,9 @@
 #define GW_ERROR_MISMATCH_RETURN_DATA 48
 #define GW_ERROR_UNKNOWN_ARGS 49
 #define GW_ERROR_UNKNOWN_SCRIPT_CODE_HASH 50
+#define GW_ERROR_NOT_SUPPORTED 51
+#define GW_ERROR_INVALID_SUDT 52
+#define GW_ERROR_PERMISSION_DENIED 53
 /* Merkle Errors*/
 #define GW_ERROR_INVALID_PROOF_LENGTH 60
 #define GW_ERROR_INVALID_PROOF 61
@@ -3,6 +3,22 @@
 
 #include "common.h"
 
+struct gw_context_t;
+
+/* Implemented by both the generator and the validator,
+ * see the gw_*_fn types in gw_def.h */
+int sys_get_account_nonce(struct gw_context_t *ctx, uint32_t account_id,
+                          uint32_t *nonce);
+int sys_get_sudt_balance(struct gw_context_t *ctx, uint32_t sudt_id,
+                         uint32_t account_id, uint128_t *balance);
+int sys_get_script_hash_by_short_script_hash(struct gw_context_t *ctx,
+                                             const uint8_t *short_script_hash,
+                                             uint32_t len,
+                                             uint8_t script_hash[32]);
+int sys_get_account_count(struct gw_context_t *ctx, uint32_t *count);
+int sys_transfer_sudt(struct gw_context_t *ctx, uint32_t sudt_id,
+                      uint32_t from_id, uint32_t to_id, uint128_t amount);
+
 #ifdef GW_GENERATOR
 #include "generator_utils.h"
 #endif
@@ -70,6 +70,22 @@ int sudt_get_balance(gw_context_t *ctx, uint32_t sudt_id, uint32_t account_id,
   return _sudt_get_balance(ctx, sudt_id, key, balance);
 }
 
+/* Set the amount that spender_id can transfer from owner_id by the
+ * transfer sUDT syscall */
+int sudt_approve(gw_context_t *ctx, uint32_t sudt_id, uint32_t owner_id,
+                 uint32_t spender_id, uint128_t amount) {
+  bool exists = false;
+  int ret = _account_exists(ctx, spender_id, &exists);
+  if (ret != 0 || !exists) {
+    return ERROR_ACCOUNT_NOT_EXISTS;
+  }
+  uint8_t key[32] = {0};
+  gw_build_sudt_allowance_key(owner_id, spender_id, key);
+  uint8_t value[32] = {0};
+  *(uint128_t *)value = amount;
+  return ctx->sys_store(ctx, sudt_id, key, value);
+}
+
 /* Transfer Simple UDT */
 int sudt_transfer(gw_context_t *ctx, uint32_t sudt_id, uint32_t from_id,
                   uint32_t to_id, uint128_t amount) {
@@ -119,5 +135,15 @@ int sudt_transfer(gw_context_t *ctx, uint32_t sudt_id, uint32_t from_id,
   if (ret != 0) {
     return ret;
   }
-  return _sudt_set_balance(ctx, sudt_id, to_key, new_to_balance);
+  ret = _sudt_set_balance(ctx, sudt_id, to_key, new_to_balance);
+  if (ret != 0) {
+    return ret;
+  }
+
+  /* emit transfer log, topics: [from_id, to_id], data: amount */
+  uint8_t topics[64] = {0};
+  memcpy(topics, (uint8_t *)&from_id, 4);
+  memcpy(topics + 32, (uint8_t *)&to_id, 4);
+  return ctx->sys_emit_log(ctx, sudt_id, GW_LOG_SUDT_TRANSFER, topics, 2,
+                           sizeof(uint128_t), (uint8_t *)&amount);
 }
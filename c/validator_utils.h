@@ -49,6 +49,13 @@ typedef struct gw_context_t {
   gw_store_data_fn sys_store_data;
   gw_get_block_hash_fn sys_get_block_hash;
   gw_log_fn sys_log;
+  gw_emit_log_fn sys_emit_log;
+  gw_call_fn sys_call;
+  gw_get_sudt_balance_fn sys_get_sudt_balance;
+  gw_get_script_hash_by_short_script_hash_fn
+      sys_get_script_hash_by_short_script_hash;
+  gw_get_account_count_fn sys_get_account_count;
+  gw_transfer_sudt_fn sys_transfer_sudt;
 
   /* validator specific context */
   gw_account_merkle_state_t prev_account; /* RawL2Block.prev_account */
@@ -279,6 +286,30 @@ int sys_create(gw_context_t *ctx, uint8_t *script, uint32_t script_len,
     return -1;
   }
 
+  /* the first created account owns the short script hash, if the rollup
+   * config enables the index */
+  mol_seg_t short_script_hash_index_seg =
+      MolReader_RollupConfig_get_short_script_hash_index(
+          &ctx->rollup_config_seg);
+  if (*short_script_hash_index_seg.ptr == 1) {
+    uint8_t short_script_hash_key[32];
+    uint8_t short_script_hash_value[32];
+    gw_build_short_script_hash_to_script_hash_key(script_hash,
+                                                  short_script_hash_key);
+    ret = gw_state_fetch(&ctx->kv_state, short_script_hash_key,
+                         short_script_hash_value);
+    if (ret != 0) {
+      return ret;
+    }
+    if (_is_zero_hash(short_script_hash_value)) {
+      ret = gw_state_insert(&ctx->kv_state, short_script_hash_key,
+                            script_hash);
+      if (ret != 0) {
+        return -1;
+      }
+    }
+  }
+
   /* scripts slots is full */
   if (ctx->scripts_size == ctx->max_scripts_size) {
     return -1;
@@ -295,12 +326,194 @@ int sys_create(gw_context_t *ctx, uint8_t *script, uint32_t script_len,
   return 0;
 }
 
+int sys_get_account_nonce(gw_context_t *ctx, uint32_t account_id,
+                          uint32_t *nonce) {
+  uint8_t key[32];
+  uint8_t value[32];
+  gw_build_nonce_key(account_id, key);
+  int ret = gw_state_fetch(&ctx->kv_state, key, value);
+  if (ret != 0) {
+    return ret;
+  }
+  *nonce = *((uint32_t *)value);
+  return 0;
+}
+
+int sys_get_sudt_balance(gw_context_t *ctx, uint32_t sudt_id,
+                         uint32_t account_id, uint128_t *balance) {
+  uint8_t key[32] = {0};
+  uint8_t value[32];
+  memcpy(key, (uint8_t *)(&account_id), 4);
+  int ret = sys_load(ctx, sudt_id, key, value);
+  if (ret != 0) {
+    return ret;
+  }
+  *balance = *((uint128_t *)value);
+  return 0;
+}
+
+int sys_get_script_hash_by_short_script_hash(gw_context_t *ctx,
+                                             const uint8_t *short_script_hash,
+                                             uint32_t len,
+                                             uint8_t script_hash[32]) {
+  if (len != GW_SHORT_SCRIPT_HASH_LEN) {
+    return GW_ERROR_INVALID_DATA;
+  }
+  uint8_t raw_key[32];
+  gw_build_short_script_hash_to_script_hash_key(short_script_hash, raw_key);
+  int ret = gw_state_fetch(&ctx->kv_state, raw_key, script_hash);
+  if (ret != 0) {
+    return ret;
+  }
+  if (_is_zero_hash(script_hash)) {
+    return GW_ERROR_NOT_FOUND;
+  }
+  return 0;
+}
+
+int sys_get_account_count(gw_context_t *ctx, uint32_t *count) {
+  *count = ctx->prev_account.count;
+  return 0;
+}
+
+/* same as the errors in sudt_utils.h */
+#define GW_SUDT_ERROR_INSUFFICIENT_BALANCE 12
+#define GW_SUDT_ERROR_AMOUNT_OVERFLOW 13
+#define GW_SUDT_ERROR_TO_ID 14
+#define GW_SUDT_ERROR_ACCOUNT_NOT_EXISTS 15
+
+int _is_l2_sudt_account(gw_context_t *ctx, uint32_t sudt_id, bool *is_sudt) {
+  uint8_t script[GW_MAX_SCRIPT_SIZE];
+  uint32_t len = GW_MAX_SCRIPT_SIZE;
+  int ret = sys_get_account_script(ctx, sudt_id, &len, 0, script);
+  if (ret != 0) {
+    return ret;
+  }
+  mol_seg_t script_seg;
+  script_seg.ptr = script;
+  script_seg.size = len;
+  if (MolReader_Script_verify(&script_seg, false) != MOL_OK) {
+    return GW_ERROR_INVALID_DATA;
+  }
+  mol_seg_t hash_type_seg = MolReader_Script_get_hash_type(&script_seg);
+  mol_seg_t code_hash_seg = MolReader_Script_get_code_hash(&script_seg);
+  mol_seg_t sudt_type_hash_seg =
+      MolReader_RollupConfig_get_l2_sudt_validator_script_type_hash(
+          &ctx->rollup_config_seg);
+  *is_sudt = *(uint8_t *)hash_type_seg.ptr == SCRIPT_HASH_TYPE_TYPE &&
+             memcmp(code_hash_seg.ptr, sudt_type_hash_seg.ptr, 32) == 0;
+  return 0;
+}
+
+int _gw_account_exists(gw_context_t *ctx, uint32_t account_id,
+                       bool *exists) {
+  uint8_t script_hash[32];
+  int ret = sys_get_script_hash_by_account_id(ctx, account_id, script_hash);
+  if (ret != 0) {
+    return ret;
+  }
+  *exists = !_is_zero_hash(script_hash);
+  return 0;
+}
+
+int sys_transfer_sudt(gw_context_t *ctx, uint32_t sudt_id, uint32_t from_id,
+                      uint32_t to_id, uint128_t amount) {
+  uint32_t contract_id = ctx->transaction_context.to_id;
+  if (from_id == to_id) {
+    return GW_SUDT_ERROR_TO_ID;
+  }
+  bool is_sudt = false;
+  int ret = _is_l2_sudt_account(ctx, sudt_id, &is_sudt);
+  if (ret != 0) {
+    return ret;
+  }
+  if (!is_sudt) {
+    return GW_ERROR_INVALID_SUDT;
+  }
+  bool exists = false;
+  ret = _gw_account_exists(ctx, from_id, &exists);
+  if (ret != 0 || !exists) {
+    return GW_SUDT_ERROR_ACCOUNT_NOT_EXISTS;
+  }
+  ret = _gw_account_exists(ctx, to_id, &exists);
+  if (ret != 0 || !exists) {
+    return GW_SUDT_ERROR_ACCOUNT_NOT_EXISTS;
+  }
+
+  /* spending another account's balance consumes the allowance */
+  uint8_t allowance_key[32] = {0};
+  uint8_t allowance_value[32] = {0};
+  if (from_id != contract_id) {
+    gw_build_sudt_allowance_key(from_id, contract_id, allowance_key);
+    ret = sys_load(ctx, sudt_id, allowance_key, allowance_value);
+    if (ret != 0) {
+      return ret;
+    }
+    if (*(uint128_t *)allowance_value < amount) {
+      return GW_ERROR_PERMISSION_DENIED;
+    }
+    *(uint128_t *)allowance_value -= amount;
+  }
+
+  uint128_t from_balance = 0;
+  ret = sys_get_sudt_balance(ctx, sudt_id, from_id, &from_balance);
+  if (ret != 0) {
+    return ret;
+  }
+  if (from_balance < amount) {
+    return GW_SUDT_ERROR_INSUFFICIENT_BALANCE;
+  }
+  uint128_t to_balance = 0;
+  ret = sys_get_sudt_balance(ctx, sudt_id, to_id, &to_balance);
+  if (ret != 0) {
+    return ret;
+  }
+  if (to_balance + amount < to_balance) {
+    return GW_SUDT_ERROR_AMOUNT_OVERFLOW;
+  }
+
+  uint8_t key[32] = {0};
+  uint8_t value[32] = {0};
+  memcpy(key, (uint8_t *)(&from_id), 4);
+  *(uint128_t *)value = from_balance - amount;
+  ret = sys_store(ctx, sudt_id, key, value);
+  if (ret != 0) {
+    return ret;
+  }
+  memset(key, 0, 32);
+  memset(value, 0, 32);
+  memcpy(key, (uint8_t *)(&to_id), 4);
+  *(uint128_t *)value = to_balance + amount;
+  ret = sys_store(ctx, sudt_id, key, value);
+  if (ret != 0) {
+    return ret;
+  }
+  if (from_id != contract_id) {
+    return sys_store(ctx, sudt_id, allowance_key, allowance_value);
+  }
+  return 0;
+}
+
 int sys_log(gw_context_t *ctx, uint32_t account_id, uint32_t data_length,
             const uint8_t *data) {
   /* do nothing */
   return 0;
 }
 
+int sys_emit_log(gw_context_t *ctx, uint32_t account_id, uint8_t log_type,
+                 const uint8_t *topics, uint32_t topics_count,
+                 uint32_t data_length, const uint8_t *data) {
+  /* do nothing */
+  return 0;
+}
+
+int sys_call(gw_context_t *ctx, uint32_t to_id, const uint8_t *args,
+             uint32_t args_len, uint8_t *return_data,
+             uint64_t *return_data_len) {
+  /* the validator can't execute the program of another account */
+  return GW_ERROR_NOT_SUPPORTED;
+}
+
 /* Find cell by type hash */
 int _find_cell_by_type_hash(uint8_t type_hash[32], uint64_t source,
                             uint64_t *index) {
@@ -682,7 +895,8 @@ int _check_account_script_is_allowed(mol_seg_t *script_seg,
 
   /* check allowed contract list */
   mol_seg_t contract_list_seg =
-      MolReader_RollupConfig_get_allowed_eoa_type_hashes(rollup_config_seg);
+      MolReader_RollupConfig_get_allowed_contract_type_hashes(
+          rollup_config_seg);
   len = MolReader_Byte32Vec_length(&contract_list_seg);
   for (uint32_t i = 0; i < len; i++) {
     mol_seg_res_t allowed_code_hash_res =
@@ -715,6 +929,14 @@ int gw_context_init(gw_context_t *ctx) {
   ctx->sys_store_data = sys_store_data;
   ctx->sys_load_data = sys_load_data;
   ctx->sys_log = sys_log;
+  ctx->sys_emit_log = sys_emit_log;
+  ctx->sys_call = sys_call;
+  ctx->sys_get_account_nonce = sys_get_account_nonce;
+  ctx->sys_get_sudt_balance = sys_get_sudt_balance;
+  ctx->sys_get_script_hash_by_short_script_hash =
+      sys_get_script_hash_by_short_script_hash;
+  ctx->sys_get_account_count = sys_get_account_count;
+  ctx->sys_transfer_sudt = sys_transfer_sudt;
 
   /* initialize context */
   uint8_t rollup_script_hash[32] = {0};
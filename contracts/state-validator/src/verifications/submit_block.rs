@@ -37,6 +37,7 @@ use gw_types::{
         WithdrawalRequest,
     },
     prelude::*,
+    MAX_SIGNATURE_SIZE,
 };
 
 fn build_assets_map_from_cells<'a, I: Iterator<Item = &'a CellValue>>(
@@ -209,6 +210,7 @@ fn mint_layer2_sudt(
     context: &mut BlockContext,
     deposit_cells: &[DepositionRequestCell],
 ) -> Result<(), Error> {
+    let short_script_hash_index: u8 = config.short_script_hash_index().into();
     for request in deposit_cells {
         // check that account's script is a valid EOA script
         if request.account_script.hash_type() != ScriptHashType::Type.into() {
@@ -225,7 +227,13 @@ fn mint_layer2_sudt(
         // find or create EOA
         let id = match context.get_account_id_by_script_hash(&request.account_script_hash.into())? {
             Some(id) => id,
-            None => context.create_account(request.account_script_hash)?,
+            None => {
+                let id = context.create_account(request.account_script_hash)?;
+                if short_script_hash_index == 1 {
+                    context.register_short_script_hash(&request.account_script_hash)?;
+                }
+                id
+            }
         };
         // mint CKB
         context.mint_sudt(CKB_SUDT_ACCOUNT_ID, id, request.value.capacity.into())?;
@@ -241,7 +249,13 @@ fn mint_layer2_sudt(
         let l2_sudt_script_hash: [u8; 32] = l2_sudt_script.hash();
         let sudt_id = match context.get_account_id_by_script_hash(&l2_sudt_script_hash.into())? {
             Some(id) => id,
-            None => context.create_account(l2_sudt_script_hash.into())?,
+            None => {
+                let id = context.create_account(l2_sudt_script_hash.into())?;
+                if short_script_hash_index == 1 {
+                    context.register_short_script_hash(&l2_sudt_script_hash.into())?;
+                }
+                id
+            }
         };
         // prevent fake CKB SUDT, the caller should filter these invalid depositions
         if sudt_id == CKB_SUDT_ACCOUNT_ID {
@@ -260,6 +274,9 @@ fn burn_layer2_sudt(
     block: &L2Block,
 ) -> Result<(), Error> {
     for request in block.withdrawals() {
+        if request.signature().len() > MAX_SIGNATURE_SIZE {
+            return Err(Error::InvalidWithdrawalRequest);
+        }
         let raw = request.raw();
         let l2_sudt_script_hash: [u8; 32] =
             build_l2_sudt_script(config, raw.sudt_script_hash().unpack()).hash();
@@ -444,6 +461,15 @@ fn check_block_transactions(context: &BlockContext, block: &L2Block) -> Result<(
         return Err(Error::InvalidTxsState);
     }
 
+    // check signature size
+    if block
+        .transactions()
+        .into_iter()
+        .any(|tx| tx.signature().len() > MAX_SIGNATURE_SIZE)
+    {
+        return Err(Error::InvalidTxsState);
+    }
+
     let leaves = block
         .transactions()
         .into_iter()
@@ -6,7 +6,7 @@ use anyhow::{anyhow, Result};
 use gw_common::{
     h256_ext::H256Ext, merkle_utils::calculate_merkle_root, smt::Blake2bHasher, state::State, H256,
 };
-use gw_generator::{traits::StateExt, Generator};
+use gw_generator::{parallel::ParallelExecutor, traits::StateExt, Generator};
 use gw_store::{
     chain_view::ChainView,
     state_db::{StateDBTransaction, StateDBVersion},
@@ -21,11 +21,16 @@ use gw_types::{
     },
     prelude::*,
 };
+use std::collections::HashSet;
 
 pub struct ProduceBlockResult {
     pub block: L2Block,
     pub global_state: GlobalState,
+    /// txs which can't be packaged on the parent state, the ones left out because the block is full
+    /// and the ones following a left out or unused entry of the same account are not included
     pub unused_transactions: Vec<L2Transaction>,
+    /// withdrawals which can't be packaged on the parent state, the ones left out because the block is full
+    /// and the ones following a left out or unused entry of the same account are not included
     pub unused_withdrawal_requests: Vec<WithdrawalRequest>,
 }
 
@@ -41,6 +46,7 @@ pub struct ProduceBlockParam<'a> {
     pub rollup_config: &'a RollupConfig,
     pub rollup_config_hash: &'a H256,
     pub max_withdrawal_capacity: u128,
+    pub max_block_cycles: u64,
 }
 
 /// Produce block
@@ -60,6 +66,7 @@ pub fn produce_block<'a>(param: ProduceBlockParam<'a>) -> Result<ProduceBlockRes
         rollup_config,
         rollup_config_hash,
         max_withdrawal_capacity,
+        max_block_cycles,
     } = param;
     // create overlay storage
     let state_db = {
@@ -74,17 +81,28 @@ pub fn produce_block<'a>(param: ProduceBlockParam<'a>) -> Result<ProduceBlockRes
     state.tracker_mut().enable();
     let prev_account_state_root = state.calculate_root()?;
     let prev_account_state_count = state.get_account_count()?;
+    // verify the signatures in parallel
+    let withdrawal_signatures =
+        generator.check_withdrawal_request_signatures(&state, &withdrawal_requests);
     // verify the withdrawals
     let mut used_withdrawal_requests = Vec::with_capacity(withdrawal_requests.len());
     let mut unused_withdrawal_requests = Vec::with_capacity(withdrawal_requests.len());
     let mut total_withdrawal_capacity: u128 = 0;
-    for request in withdrawal_requests {
+    // accounts which have a skipped or unused entry, the following entries of them
+    // would fail on the nonce, so they are left in the mem pool for the next blocks
+    let mut skipped_accounts: HashSet<u32> = HashSet::new();
+    for (request, signature) in withdrawal_requests.into_iter().zip(withdrawal_signatures) {
+        let account_script_hash: H256 = request.raw().account_script_hash().unpack();
+        let account_id = state.get_account_id_by_script_hash(&account_script_hash)?;
+        if let Some(account_id) = account_id {
+            if skipped_accounts.contains(&account_id) {
+                continue;
+            }
+        }
         // check withdrawal request
-        if generator
-            .check_withdrawal_request_signature(&state, &request)
-            .is_err()
-        {
+        if signature.is_err() {
             unused_withdrawal_requests.push(request);
+            skipped_accounts.extend(account_id);
             continue;
         }
         if generator
@@ -92,15 +110,17 @@ pub fn produce_block<'a>(param: ProduceBlockParam<'a>) -> Result<ProduceBlockRes
             .is_err()
         {
             unused_withdrawal_requests.push(request);
+            skipped_accounts.extend(account_id);
             continue;
         }
         let capacity: u64 = request.raw().capacity().unpack();
         let new_total_withdrwal_capacity = total_withdrawal_capacity
             .checked_add(capacity as u128)
             .ok_or(anyhow!("total withdrawal capacity overflow"))?;
-        // skip package withdrwal if overdraft the Rollup capacity
+        // skip package withdrwal if overdraft the Rollup capacity,
+        // it's left in the mem pool for the next blocks
         if new_total_withdrwal_capacity > max_withdrawal_capacity {
-            unused_withdrawal_requests.push(request);
+            skipped_accounts.extend(account_id);
             continue;
         }
         total_withdrawal_capacity = new_total_withdrwal_capacity;
@@ -111,11 +131,12 @@ pub fn produce_block<'a>(param: ProduceBlockParam<'a>) -> Result<ProduceBlockRes
             }
             Err(_err) => {
                 unused_withdrawal_requests.push(request);
+                skipped_accounts.extend(account_id);
             }
         }
     }
     // update deposits
-    state.apply_deposition_requests(&deposition_requests)?;
+    state.apply_deposition_requests(rollup_config, &deposition_requests)?;
     // calculate state after withdrawals & deposits
     let compacted_prev_root_hash = state.calculate_compacted_account_root()?;
     // execute txs
@@ -132,28 +153,48 @@ pub fn produce_block<'a>(param: ProduceBlockParam<'a>) -> Result<ProduceBlockRes
         .block_producer_id(block_producer_id.pack())
         .build();
     let chain_view = ChainView::new(db.clone(), parent_block_hash.into());
-    for tx in txs {
+    // verify the tx signatures in parallel
+    let tx_signatures = generator.check_transaction_signatures(&state, &txs);
+    let raw_txs = txs.iter().map(|tx| tx.raw()).collect();
+    let mut executor = ParallelExecutor::new(generator, &chain_view, &state, block_info, raw_txs);
+    let mut total_cycles: u64 = 0;
+    for (tx_index, (tx, signature)) in txs.into_iter().zip(tx_signatures).enumerate() {
+        let sender_id: u32 = tx.raw().from_id().unpack();
+        if skipped_accounts.contains(&sender_id) {
+            continue;
+        }
         // 1. verify tx
-        if generator.check_transaction_signature(&state, &tx).is_err() {
+        if signature.is_err() {
             unused_transactions.push(tx);
+            skipped_accounts.insert(sender_id);
             continue;
         }
         if generator.verify_transaction(&state, &tx).is_err() {
             unused_transactions.push(tx);
+            skipped_accounts.insert(sender_id);
             continue;
         }
         // 2. execute txs
-        let raw_tx = tx.raw();
-        let run_result =
-            match generator.execute_transaction(&chain_view, &state, &block_info, &raw_tx) {
-                Ok(run_result) => run_result,
-                Err(_) => {
-                    unused_transactions.push(tx);
-                    continue;
-                }
-            };
+        let run_result = match executor.execute(&state, tx_index) {
+            Ok(run_result) => run_result,
+            Err(_) => {
+                unused_transactions.push(tx);
+                skipped_accounts.insert(sender_id);
+                continue;
+            }
+        };
+        // skip package tx if exceeded the block cycles budget,
+        // it and the following txs of the sender are left in the mem pool for the next blocks
+        let new_total_cycles = match total_cycles.checked_add(run_result.used_cycles) {
+            Some(cycles) if cycles <= max_block_cycles => cycles,
+            _ => {
+                skipped_accounts.insert(sender_id);
+                continue;
+            }
+        };
+        total_cycles = new_total_cycles;
         // 3. apply tx state
-        state.apply_run_result(&run_result)?;
+        executor.apply(&mut state, &run_result)?;
         // 4. build tx receipt
         let tx_witness_hash = tx.witness_hash();
         let compacted_post_account_root = state.calculate_compacted_account_root()?;
@@ -176,6 +217,7 @@ pub fn produce_block<'a>(param: ProduceBlockParam<'a>) -> Result<ProduceBlockRes
                     .collect::<Vec<_>>()
                     .pack(),
             )
+            .used_cycles(run_result.used_cycles.pack())
             .build();
         used_transactions.push(tx);
         tx_receipts.push(receipt);
@@ -315,6 +315,10 @@ impl Chain {
                     "reverted l2block must be current tip"
                 );
                 db.detach_block(&l2block)?;
+                self.mem_pool
+                    .lock()
+                    .status_tracker_mut()
+                    .revert_block(&l2block);
             }
             _ => {
                 // do nothing
@@ -357,9 +361,18 @@ impl Chain {
         }
         db.commit()?;
         // update mem pool state
-        self.mem_pool
-            .lock()
-            .notify_new_tip(self.local_state.tip.hash().into())?;
+        {
+            let mut mem_pool = self.mem_pool.lock();
+            mem_pool.notify_new_tip(self.local_state.tip.hash().into())?;
+            let last_finalized_block_number: u64 = self
+                .local_state
+                .last_global_state
+                .last_finalized_block_number()
+                .unpack();
+            mem_pool
+                .status_tracker_mut()
+                .finalize(last_finalized_block_number);
+        }
         // check consistency of account SMT
         {
             // check account SMT, should be able to calculate account state root
@@ -408,7 +421,7 @@ impl Chain {
             last_synced: genesis_header_info,
             last_global_state: genesis_global_state,
         };
-        // reset account SMT to genesis
+        // reset account SMT to genesis, the preimage index is rebuilt by the replay
         // TODO use version based storage
         let state_db = StateDBTransaction::from_version(
             db.clone(),
@@ -423,7 +436,7 @@ impl Chain {
             self.rollup_config(),
         )?;
         // replay blocks
-        for number in 1..tip_number {
+        for number in 1..=tip_number {
             let block_hash = db
                 .get_block_hash_by_number(number)?
                 .expect("get l2block")
@@ -526,6 +539,10 @@ impl Chain {
         )?;
         db.attach_block(l2block.clone())?;
         tree.submit_tree()?;
+        self.mem_pool
+            .lock()
+            .status_tracker_mut()
+            .commit_block(&l2block);
         self.local_state.tip = l2block;
         Ok(None)
     }
@@ -10,6 +10,13 @@ pub const GW_ACCOUNT_SCRIPT_HASH: u8 = 2;
 /* prefix */
 pub const GW_SCRIPT_HASH_TO_ID_PREFIX: [u8; 5] = [0, 0, 0, 0, 3];
 pub const GW_DATA_HASH_PREFIX: [u8; 5] = [0, 0, 0, 0, 4];
+pub const GW_SHORT_SCRIPT_HASH_TO_SCRIPT_HASH_PREFIX: [u8; 5] = [0, 0, 0, 0, 5];
+/* sUDT storage flags */
+pub const GW_SUDT_ALLOWANCE: u8 = 1;
+
+/// Length of the short script hash, which is the prefix of the script hash,
+/// the same length as an Ethereum address
+pub const SHORT_SCRIPT_HASH_LEN: usize = 20;
 
 /* Generate a SMT key
  * raw_key: blake2b(id | type | key)
@@ -33,6 +40,16 @@ pub fn build_account_field_key(id: u32, type_: u8) -> H256 {
     key.into()
 }
 
+/// The key of an allowance in the storage of a sUDT account: owner_id | flag | spender_id,
+/// the balance keys are the account ids only, so they never collide
+pub fn build_sudt_allowance_key(owner_id: u32, spender_id: u32) -> H256 {
+    let mut key: [u8; 32] = H256::zero().into();
+    key[..4].copy_from_slice(&owner_id.to_le_bytes());
+    key[4] = GW_SUDT_ALLOWANCE;
+    key[5..9].copy_from_slice(&spender_id.to_le_bytes());
+    key.into()
+}
+
 pub fn build_script_hash_to_account_id_key(script_hash: &[u8]) -> H256 {
     let mut key: [u8; 32] = H256::zero().into();
     let mut hasher = new_blake2b();
@@ -42,6 +59,16 @@ pub fn build_script_hash_to_account_id_key(script_hash: &[u8]) -> H256 {
     key.into()
 }
 
+/// The key maps a short script hash to the script hash of the first account created with it
+pub fn build_short_script_hash_to_script_hash_key(short_script_hash: &[u8]) -> H256 {
+    let mut key: [u8; 32] = H256::zero().into();
+    let mut hasher = new_blake2b();
+    hasher.update(&GW_SHORT_SCRIPT_HASH_TO_SCRIPT_HASH_PREFIX);
+    hasher.update(short_script_hash);
+    hasher.finalize(&mut key);
+    key.into()
+}
+
 pub fn build_data_hash_key(data_hash: &[u8]) -> H256 {
     let mut key: [u8; 32] = H256::zero().into();
     let mut hasher = new_blake2b();
@@ -64,6 +91,11 @@ pub trait State {
     fn get_account_count(&self) -> Result<u32, Error>;
     fn set_account_count(&mut self, count: u32) -> Result<(), Error>;
     fn calculate_root(&self) -> Result<H256, Error>;
+    /// Read a value without side effects, a state tracking the touched keys doesn't record it,
+    /// the default is the same as `get_raw`
+    fn get_raw_untracked(&self, key: &H256) -> Result<H256, Error> {
+        self.get_raw(key)
+    }
 
     // implementations
     fn get_value(&self, id: u32, key: &H256) -> Result<H256, Error> {
@@ -73,6 +105,12 @@ pub trait State {
     fn update_value(&mut self, id: u32, key: &H256, value: H256) -> Result<(), Error> {
         let raw_key = build_account_key(id, key.as_slice());
         self.update_raw(raw_key, value)?;
+        self.record_account_key(id, key)?;
+        Ok(())
+    }
+    /// Record the preimage of an account key,
+    /// a state may index it to iterate the storage of an account, the default do nothing
+    fn record_account_key(&mut self, _id: u32, _key: &H256) -> Result<(), Error> {
         Ok(())
     }
     /// Create a new account
@@ -95,6 +133,21 @@ pub trait State {
         Ok(id)
     }
 
+    /// Map the short script hash of an account to the script hash,
+    /// the first created account owns the short script hash.
+    ///
+    /// It's only called if the rollup config enables the short script hash index
+    /// since it changes the state root
+    fn register_short_script_hash(&mut self, script_hash: &H256) -> Result<(), Error> {
+        let short_script_hash_key = build_short_script_hash_to_script_hash_key(
+            &script_hash.as_slice()[..SHORT_SCRIPT_HASH_LEN],
+        );
+        if self.get_raw(&short_script_hash_key)?.is_zero() {
+            self.update_raw(short_script_hash_key, *script_hash)?;
+        }
+        Ok(())
+    }
+
     fn get_script_hash(&self, id: u32) -> Result<H256, Error> {
         let value = self.get_raw(&build_account_field_key(id, GW_ACCOUNT_SCRIPT_HASH).into())?;
         Ok(value.into())
@@ -123,6 +176,20 @@ pub trait State {
         Ok(Some(id))
     }
 
+    /// Get the script hash by the short script hash
+    fn get_script_hash_by_short_script_hash(
+        &self,
+        short_script_hash: &[u8],
+    ) -> Result<Option<H256>, Error> {
+        let value = self.get_raw(&build_short_script_hash_to_script_hash_key(
+            short_script_hash,
+        ))?;
+        if value.is_zero() {
+            return Ok(None);
+        }
+        Ok(Some(value))
+    }
+
     fn get_sudt_balance(&self, sudt_id: u32, id: u32) -> Result<u128, Error> {
         // get balance
         let balance = self.get_value(sudt_id, &H256::from_u32(id))?;
@@ -142,21 +209,21 @@ pub trait State {
 
     /// Mint SUDT token on layer2
     fn mint_sudt(&mut self, sudt_id: u32, id: u32, amount: u128) -> Result<(), Error> {
-        let raw_key = build_account_key(sudt_id, &H256::from_u32(id).as_slice());
+        let key = H256::from_u32(id);
         // calculate balance
-        let mut balance = self.get_raw(&raw_key)?.to_u128();
+        let mut balance = self.get_value(sudt_id, &key)?.to_u128();
         balance = balance.checked_add(amount).ok_or(Error::AmountOverflow)?;
-        self.update_raw(raw_key, H256::from_u128(balance))?;
+        self.update_value(sudt_id, &key, H256::from_u128(balance))?;
         Ok(())
     }
 
     /// burn SUDT
     fn burn_sudt(&mut self, sudt_id: u32, id: u32, amount: u128) -> Result<(), Error> {
-        let raw_key = build_account_key(sudt_id, &H256::from_u32(id).as_slice());
+        let key = H256::from_u32(id);
         // calculate balance
-        let mut balance = self.get_raw(&raw_key)?.to_u128();
+        let mut balance = self.get_value(sudt_id, &key)?.to_u128();
         balance = balance.checked_sub(amount).ok_or(Error::AmountOverflow)?;
-        self.update_raw(raw_key, H256::from_u128(balance))?;
+        self.update_value(sudt_id, &key, H256::from_u128(balance))?;
         Ok(())
     }
 
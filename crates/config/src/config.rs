@@ -7,11 +7,15 @@ pub struct Config {
     pub store: StoreConfig,
     pub genesis: GenesisConfig,
     pub block_producer: Option<BlockProducerConfig>,
+    pub mem_pool: MemPoolConfig,
+    pub generator: GeneratorConfig,
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct BlockProducerConfig {
     pub account_id: u32,
+    /// Max cycles of all the txs in a block
+    pub max_block_cycles: u64,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -25,7 +29,80 @@ pub struct ChainConfig {
     pub rollup_config: RollupConfig,
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeneratorConfig {
+    /// Backends loaded at startup in addition to the builtin ones
+    pub backends: Vec<BackendConfig>,
+    /// Threads executing the txs of a block and verifying the signatures in parallel,
+    /// txs are executed sequentially if it is less than 2
+    pub execution_threads: usize,
+    /// VM engine running the layer2 programs
+    pub vm_engine: VMEngine,
+}
+
+impl Default for GeneratorConfig {
+    fn default() -> Self {
+        GeneratorConfig {
+            backends: Vec::new(),
+            execution_threads: 1,
+            vm_engine: VMEngine::default(),
+        }
+    }
+}
+
+/// Engines of CKB-VM, all of them produce the same results and consume the same cycles
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VMEngine {
+    /// The assembly machine, falls back to `Interpreter` if the generator is built
+    /// without the `detect-asm` feature
+    Asm,
+    /// The pure Rust interpreter
+    Interpreter,
+    /// The interpreter executing instructions step by step,
+    /// records the executed instructions of a tx in the trace mode. It is the slowest one
+    Instrumented,
+}
+
+impl Default for VMEngine {
+    fn default() -> Self {
+        VMEngine::Asm
+    }
+}
+
+/// A layer2 backend, the binaries are loaded from files
+#[derive(Clone, Debug, PartialEq)]
+pub struct BackendConfig {
+    pub validator_path: PathBuf,
+    pub generator_path: PathBuf,
+    /// Expected code hash of the validator binary,
+    /// which is the code hash of the account scripts with the `Data` hash type
+    pub validator_code_hash: [u8; 32],
+    /// Type hash of the cell which the validator is deployed in,
+    /// which is the code hash of the account scripts with the `Type` hash type
+    pub validator_script_type_hash: Option<[u8; 32]>,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct StoreConfig {
     pub path: PathBuf,
 }
+
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct MemPoolConfig {
+    /// Max txs & withdrawals of an account in the pool, 0 means unlimited
+    pub max_pending_per_account: usize,
+    /// Min fee of txs, denominated in CKB.
+    /// Only the CKB transfers pay fees to the block producer,
+    /// so other txs are rejected if it is not 0
+    pub min_fee: u128,
+    /// Account script code hashes which are allowed to push txs & withdrawals,
+    /// `RollupConfig.allowed_eoa_type_hashes` is used if this list is empty,
+    /// all accounts are allowed if both are empty
+    pub allowed_code_hashes: Vec<[u8; 32]>,
+    /// Account script code hashes which are denied to push txs & withdrawals
+    pub denied_code_hashes: Vec<[u8; 32]>,
+    /// Max txs & withdrawals an account can push in a rate limit window, 0 means unlimited
+    pub rate_limit_per_account: usize,
+    /// Rate limit window in seconds
+    pub rate_limit_window_secs: u64,
+}
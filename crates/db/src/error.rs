@@ -1,7 +1,7 @@
 use thiserror::Error;
 
 /// DB Error
-#[derive(Error, Debug, Clone)]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
 #[error("DB error {message}")]
 pub struct Error {
     pub message: String,
@@ -3,7 +3,7 @@
 /// Column families alias type
 pub type Col = &'static str;
 /// Total column number
-pub const COLUMNS: u32 = 18;
+pub const COLUMNS: u32 = 20;
 /// Column store meta data
 pub const COLUMN_META: Col = "0";
 /// Column store chain index
@@ -40,6 +40,10 @@ pub const COLUMN_DATA: Col = "15";
 pub const COLUMN_BLOCK_DEPOSITION_REQUESTS: Col = "16";
 /// Column custodian assets
 pub const COLUMN_CUSTODIAN_ASSETS: Col = "17";
+/// Column log index
+pub const COLUMN_LOG_INDEX: Col = "18";
+/// Column preimages of the account keys
+pub const COLUMN_ACCOUNT_KEY_PREIMAGE: Col = "19";
 
 /// chain id
 pub const META_CHAIN_ID_KEY: &[u8] = b"CHAIN_ID";
@@ -51,6 +55,8 @@ pub const META_BLOCK_SMT_ROOT_KEY: &[u8] = b"BLOCK_SMT_ROOT_KEY";
 pub const META_ACCOUNT_SMT_ROOT_KEY: &[u8] = b"ACCOUNT_SMT_ROOT_KEY";
 /// account SMT count
 pub const META_ACCOUNT_SMT_COUNT_KEY: &[u8] = b"ACCOUNT_SMT_COUNT_KEY";
+/// the account key preimage index is enabled
+pub const META_PREIMAGE_INDEX_ENABLED_KEY: &[u8] = b"PREIMAGE_INDEX_ENABLED_KEY";
 
 /// CHAIN_SPEC_HASH_KEY tracks the hash of chain spec which created current database
 pub const CHAIN_SPEC_HASH_KEY: &[u8] = b"chain-spec-hash";
@@ -1,5 +1,5 @@
 use gw_common::H256;
-use gw_types::{bytes::Bytes, packed::Signature};
+use gw_types::bytes::Bytes;
 
 use crate::error::LockAlgorithmError;
 
@@ -16,7 +16,7 @@ impl LockAlgorithm for AlwaysSuccess {
     fn verify_signature(
         &self,
         _lock_args: Bytes,
-        _signature: Signature,
+        _signature: Bytes,
         _message: H256,
     ) -> Result<bool, LockAlgorithmError> {
         Ok(true)
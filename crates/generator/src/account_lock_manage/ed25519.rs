@@ -0,0 +1,60 @@
+use super::LockAlgorithm;
+use crate::error::LockAlgorithmError;
+use ed25519_dalek::{PublicKey, Signature, PUBLIC_KEY_LENGTH, SIGNATURE_LENGTH};
+use gw_common::blake2b::new_blake2b;
+use gw_common::H256;
+use gw_types::bytes::Bytes;
+use std::convert::TryFrom;
+
+const BLAKE160_SIZE: usize = 20;
+
+#[derive(Debug, Default)]
+pub struct Ed25519;
+
+/// Usage
+/// register Ed25519 to AccountLockManage
+///
+/// manage.register_lock_algorithm(code_hash, Box::new(Ed25519::default()));
+///
+/// The lock args is blake160(pubkey), the signature is pubkey | ed25519 signature of the message,
+/// since the pubkey can't be recovered from an ed25519 signature.
+///
+/// NOTICE only the layer2 verification is implemented, there is no layer1 lock script for it yet,
+/// so Generator::new rejects a rollup config allowing it as an EOA type.
+impl LockAlgorithm for Ed25519 {
+    fn has_onchain_lock(&self) -> bool {
+        false
+    }
+
+    fn verify_signature(
+        &self,
+        lock_args: Bytes,
+        signature: Bytes,
+        message: H256,
+    ) -> Result<bool, LockAlgorithmError> {
+        if lock_args.len() != BLAKE160_SIZE {
+            return Err(LockAlgorithmError::InvalidLockArgs);
+        }
+        if signature.len() != PUBLIC_KEY_LENGTH + SIGNATURE_LENGTH {
+            return Err(LockAlgorithmError::InvalidSignature);
+        }
+        let (pubkey, signature) = signature.split_at(PUBLIC_KEY_LENGTH);
+        let pubkey_hash = {
+            let mut buf = [0u8; 32];
+            let mut hasher = new_blake2b();
+            hasher.update(pubkey);
+            hasher.finalize(&mut buf);
+            let mut pubkey_hash = [0u8; BLAKE160_SIZE];
+            pubkey_hash.copy_from_slice(&buf[..BLAKE160_SIZE]);
+            pubkey_hash
+        };
+        if pubkey_hash[..] != lock_args[..] {
+            return Ok(false);
+        }
+        let pubkey =
+            PublicKey::from_bytes(pubkey).map_err(|_| LockAlgorithmError::InvalidSignature)?;
+        let signature =
+            Signature::try_from(signature).map_err(|_| LockAlgorithmError::InvalidSignature)?;
+        Ok(pubkey.verify_strict(message.as_slice(), &signature).is_ok())
+    }
+}
@@ -1,30 +1,109 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Mutex,
+};
 
 use gw_common::H256;
-use gw_types::{bytes::Bytes, packed::Signature};
+use gw_types::bytes::Bytes;
 
 pub mod always_success;
+pub mod ed25519;
+pub mod multisig;
 pub mod secp256k1;
+pub mod webauthn;
 
 use crate::error::LockAlgorithmError;
 
-pub trait LockAlgorithm {
+/// Max verified signatures cached by AccountLockManage
+const SIGNATURE_CACHE_SIZE: usize = 20_000;
+
+/// A signature to verify, the witness hash of the signed tx or withdrawal request
+/// is the key of the verified signatures cache
+#[derive(Debug, Clone)]
+pub struct SignatureRequest {
+    pub witness_hash: H256,
+    pub lock_code_hash: H256,
+    pub lock_args: Bytes,
+    pub signature: Bytes,
+    pub message: H256,
+}
+
+pub trait LockAlgorithm: Send + Sync {
+    /// Verify the signature of the message,
+    /// the signature is variable-length, each algorithm checks the format by itself
     fn verify_signature(
         &self,
         lock_args: Bytes,
-        signature: Signature,
+        signature: Bytes,
         message: H256,
     ) -> Result<bool, LockAlgorithmError>;
+
+    /// Whether the signatures can be verified by a layer1 account lock script, e.g. in a challenge,
+    /// an algorithm without one can't be used by the allowed EOA types of a rollup
+    fn has_onchain_lock(&self) -> bool {
+        true
+    }
+
+    /// Verify a batch of signatures, return the results in the order of requests,
+    /// an algorithm supporting batch verification can override it
+    fn verify_signatures(
+        &self,
+        requests: &[SignatureRequest],
+    ) -> Vec<Result<bool, LockAlgorithmError>> {
+        requests
+            .iter()
+            .map(|request| {
+                self.verify_signature(
+                    request.lock_args.clone(),
+                    request.signature.clone(),
+                    request.message,
+                )
+            })
+            .collect()
+    }
+}
+
+// witness hash -> message of the verified signatures, the oldest one is evicted if it's full
+#[derive(Default)]
+struct SignatureCache {
+    messages: HashMap<H256, H256>,
+    witness_hashes: VecDeque<H256>,
+}
+
+impl SignatureCache {
+    // the message commits to the lock script of the signer,
+    // so a cached signature is reused only if the message is the same
+    fn contains(&self, request: &SignatureRequest) -> bool {
+        self.messages.get(&request.witness_hash) == Some(&request.message)
+    }
+
+    fn insert(&mut self, request: &SignatureRequest) {
+        if self
+            .messages
+            .insert(request.witness_hash, request.message)
+            .is_none()
+        {
+            self.witness_hashes.push_back(request.witness_hash);
+        }
+        if self.witness_hashes.len() > SIGNATURE_CACHE_SIZE {
+            if let Some(witness_hash) = self.witness_hashes.pop_front() {
+                self.messages.remove(&witness_hash);
+            }
+        }
+    }
 }
 
 pub struct AccountLockManage {
     locks: HashMap<H256, Box<dyn LockAlgorithm>>,
+    verified: Mutex<SignatureCache>,
 }
 
 impl Default for AccountLockManage {
     fn default() -> Self {
         AccountLockManage {
             locks: Default::default(),
+            verified: Default::default(),
         }
     }
 }
@@ -37,4 +116,119 @@ impl AccountLockManage {
     pub fn get_lock_algorithm(&self, code_hash: &H256) -> Option<&Box<dyn LockAlgorithm>> {
         self.locks.get(code_hash)
     }
+
+    /// Verify a signature, the verified signatures are cached
+    pub fn verify_signature(&self, request: &SignatureRequest) -> Result<(), LockAlgorithmError> {
+        if self.is_verified(request) {
+            return Ok(());
+        }
+        let lock_algo = self
+            .get_lock_algorithm(&request.lock_code_hash)
+            .ok_or(LockAlgorithmError::UnknownAccountLock)?;
+        let valid_signature = lock_algo.verify_signature(
+            request.lock_args.clone(),
+            request.signature.clone(),
+            request.message,
+        )?;
+        if !valid_signature {
+            return Err(LockAlgorithmError::InvalidSignature);
+        }
+        self.verified.lock().expect("lock").insert(request);
+        Ok(())
+    }
+
+    /// Verify a batch of signatures in parallel, return the results in the order of requests.
+    /// The signatures are grouped by lock algorithms and split into chunks,
+    /// the worker threads verify the chunks, the cached signatures are skipped.
+    pub fn verify_signatures(
+        &self,
+        requests: &[SignatureRequest],
+        threads: usize,
+    ) -> Vec<Result<(), LockAlgorithmError>> {
+        let mut results = vec![Ok(()); requests.len()];
+        let mut groups: HashMap<H256, Vec<usize>> = HashMap::default();
+        for (index, request) in requests.iter().enumerate() {
+            if self.is_verified(request) {
+                continue;
+            }
+            if !self.locks.contains_key(&request.lock_code_hash) {
+                results[index] = Err(LockAlgorithmError::UnknownAccountLock);
+                continue;
+            }
+            // it's overwritten by the verification result
+            results[index] = Err(LockAlgorithmError::InvalidSignature);
+            groups
+                .entry(request.lock_code_hash)
+                .or_default()
+                .push(index);
+        }
+        let threads = threads.max(1);
+        let unverified_count: usize = groups.values().map(Vec::len).sum();
+        let chunk_size = ((unverified_count + threads - 1) / threads).max(1);
+        let chunks: Vec<(H256, Vec<usize>)> = groups
+            .iter()
+            .flat_map(|(code_hash, indexes)| {
+                indexes
+                    .chunks(chunk_size)
+                    .map(move |chunk| (*code_hash, chunk.to_vec()))
+            })
+            .collect();
+
+        let verify_chunk = |(code_hash, indexes): &(H256, Vec<usize>)| {
+            let chunk: Vec<SignatureRequest> =
+                indexes.iter().map(|&i| requests[i].clone()).collect();
+            self.locks[code_hash].verify_signatures(&chunk)
+        };
+        let chunk_results = if threads > 1 && chunks.len() > 1 {
+            let mut chunk_results = vec![Vec::new(); chunks.len()];
+            let next_chunk = AtomicUsize::new(0);
+            crossbeam_utils::thread::scope(|scope| {
+                let workers: Vec<_> = (0..threads.min(chunks.len()))
+                    .map(|_| {
+                        let (chunks, next_chunk, verify_chunk) =
+                            (&chunks, &next_chunk, &verify_chunk);
+                        scope.spawn(move |_| {
+                            let mut verified = Vec::new();
+                            loop {
+                                let index = next_chunk.fetch_add(1, Ordering::SeqCst);
+                                if index >= chunks.len() {
+                                    break;
+                                }
+                                verified.push((index, verify_chunk(&chunks[index])));
+                            }
+                            verified
+                        })
+                    })
+                    .collect();
+                for worker in workers {
+                    for (index, verified) in worker.join().expect("verify signatures") {
+                        chunk_results[index] = verified;
+                    }
+                }
+            })
+            .expect("verify signatures");
+            chunk_results
+        } else {
+            chunks.iter().map(verify_chunk).collect()
+        };
+
+        let mut cache = self.verified.lock().expect("lock");
+        for ((_code_hash, indexes), verified) in chunks.iter().zip(chunk_results) {
+            for (&index, result) in indexes.iter().zip(verified) {
+                results[index] = match result {
+                    Ok(true) => {
+                        cache.insert(&requests[index]);
+                        Ok(())
+                    }
+                    Ok(false) => Err(LockAlgorithmError::InvalidSignature),
+                    Err(err) => Err(err),
+                };
+            }
+        }
+        results
+    }
+
+    fn is_verified(&self, request: &SignatureRequest) -> bool {
+        self.verified.lock().expect("lock").contains(request)
+    }
 }
@@ -0,0 +1,110 @@
+use super::secp256k1::{parse_signature, SECP256K1};
+use super::LockAlgorithm;
+use crate::error::LockAlgorithmError;
+use gw_common::blake2b::new_blake2b;
+use gw_common::H256;
+use gw_types::bytes::Bytes;
+use secp256k1::recovery::{RecoverableSignature, RecoveryId};
+
+const BLAKE160_SIZE: usize = 20;
+const SIGNATURE_SIZE: usize = 65;
+// threshold | pubkeys_count
+const MULTISIG_HEADER_SIZE: usize = 2;
+
+/// The parsed multisig lock args
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultisigArgs {
+    pub threshold: u8,
+    pub pubkey_hashes: Vec<[u8; BLAKE160_SIZE]>,
+}
+
+impl MultisigArgs {
+    /// args: threshold | pubkeys_count | blake160(pubkey) * pubkeys_count
+    pub fn from_slice(args: &[u8]) -> Result<Self, LockAlgorithmError> {
+        if args.len() < MULTISIG_HEADER_SIZE {
+            return Err(LockAlgorithmError::InvalidLockArgs);
+        }
+        let threshold = args[0];
+        let pubkeys_count = args[1] as usize;
+        if threshold == 0
+            || threshold as usize > pubkeys_count
+            || args.len() != MULTISIG_HEADER_SIZE + pubkeys_count * BLAKE160_SIZE
+        {
+            return Err(LockAlgorithmError::InvalidLockArgs);
+        }
+        let pubkey_hashes = args[MULTISIG_HEADER_SIZE..]
+            .chunks_exact(BLAKE160_SIZE)
+            .map(|chunk| {
+                let mut pubkey_hash = [0u8; BLAKE160_SIZE];
+                pubkey_hash.copy_from_slice(chunk);
+                pubkey_hash
+            })
+            .collect();
+        Ok(MultisigArgs {
+            threshold,
+            pubkey_hashes,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Bytes {
+        let mut args = vec![self.threshold, self.pubkey_hashes.len() as u8];
+        for pubkey_hash in &self.pubkey_hashes {
+            args.extend_from_slice(pubkey_hash);
+        }
+        args.into()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Secp256k1Multisig;
+
+/// Usage
+/// register Secp256k1Multisig to AccountLockManage
+///
+/// manage.register_lock_algorithm(code_hash, Box::new(Secp256k1Multisig::default()));
+///
+/// The signature is `threshold` secp256k1 recoverable signatures of the message,
+/// the signers must be in the same order of the pubkey hashes in the lock args, and each pubkey can only sign once.
+impl LockAlgorithm for Secp256k1Multisig {
+    fn verify_signature(
+        &self,
+        lock_args: Bytes,
+        signature: Bytes,
+        message: H256,
+    ) -> Result<bool, LockAlgorithmError> {
+        let args = MultisigArgs::from_slice(&lock_args)?;
+        if signature.len() != args.threshold as usize * SIGNATURE_SIZE {
+            return Err(LockAlgorithmError::InvalidSignature);
+        }
+        let msg = secp256k1::Message::from_slice(message.as_slice())
+            .map_err(|_| LockAlgorithmError::InvalidSignature)?;
+
+        let mut pubkey_hashes = args.pubkey_hashes.iter();
+        for signature in signature.chunks_exact(SIGNATURE_SIZE) {
+            let signature: RecoverableSignature = {
+                let signature = parse_signature(signature)?;
+                let recid = RecoveryId::from_i32(signature[64] as i32)
+                    .map_err(|_| LockAlgorithmError::InvalidSignature)?;
+                RecoverableSignature::from_compact(&signature[..64], recid)
+                    .map_err(|_| LockAlgorithmError::InvalidSignature)?
+            };
+            let pubkey = SECP256K1
+                .recover(&msg, &signature)
+                .map_err(|_| LockAlgorithmError::InvalidSignature)?;
+            let pubkey_hash = {
+                let mut buf = [0u8; 32];
+                let mut hasher = new_blake2b();
+                hasher.update(&pubkey.serialize());
+                hasher.finalize(&mut buf);
+                let mut pubkey_hash = [0u8; BLAKE160_SIZE];
+                pubkey_hash.copy_from_slice(&buf[..BLAKE160_SIZE]);
+                pubkey_hash
+            };
+            // skip the pubkeys which do not sign
+            if !pubkey_hashes.any(|expected| expected == &pubkey_hash) {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
@@ -2,10 +2,11 @@ use super::LockAlgorithm;
 use crate::error::LockAlgorithmError;
 use gw_common::blake2b::new_blake2b;
 use gw_common::H256;
-use gw_types::prelude::*;
-use gw_types::{bytes::Bytes, packed::Signature};
+use gw_types::bytes::Bytes;
 use lazy_static::lazy_static;
+use ripemd160::Ripemd160;
 use secp256k1::recovery::{RecoverableSignature, RecoveryId};
+use sha2::Sha256;
 use sha3::{Digest, Keccak256};
 
 lazy_static! {
@@ -23,7 +24,7 @@ impl LockAlgorithm for Secp256k1 {
     fn verify_signature(
         &self,
         lock_args: Bytes,
-        signature: Signature,
+        signature: Bytes,
         message: H256,
     ) -> Result<bool, LockAlgorithmError> {
         if lock_args.len() != 20 {
@@ -32,7 +33,7 @@ impl LockAlgorithm for Secp256k1 {
         let mut expected_pubkey_hash = [0u8; 20];
         expected_pubkey_hash.copy_from_slice(&lock_args);
         let signature: RecoverableSignature = {
-            let signature: [u8; 65] = signature.unpack();
+            let signature = parse_signature(&signature)?;
             let recid = RecoveryId::from_i32(signature[64] as i32)
                 .map_err(|_| LockAlgorithmError::InvalidSignature)?;
             let data = &signature[..64];
@@ -71,7 +72,7 @@ impl LockAlgorithm for Secp256k1Eth {
     fn verify_signature(
         &self,
         lock_args: Bytes,
-        signature: Signature,
+        signature: Bytes,
         message: H256,
     ) -> Result<bool, LockAlgorithmError> {
         if lock_args.len() != 20 {
@@ -88,7 +89,7 @@ impl LockAlgorithm for Secp256k1Eth {
         let mut expected_pubkey_hash = [0u8; 20];
         expected_pubkey_hash.copy_from_slice(&lock_args);
         let signature: RecoverableSignature = {
-            let signature: [u8; 65] = signature.unpack();
+            let signature = parse_signature(&signature)?;
             let recid = RecoveryId::from_i32(signature[64] as i32)
                 .map_err(|_| LockAlgorithmError::InvalidSignature)?;
             let data = &signature[..64];
@@ -115,12 +116,149 @@ impl LockAlgorithm for Secp256k1Eth {
     }
 }
 
+// the secp256k1 recoverable signature is 65 bytes
+pub(crate) fn parse_signature(signature: &[u8]) -> Result<[u8; 65], LockAlgorithmError> {
+    if signature.len() != 65 {
+        return Err(LockAlgorithmError::InvalidSignature);
+    }
+    let mut buf = [0u8; 65];
+    buf.copy_from_slice(signature);
+    Ok(buf)
+}
+
+// recover the pubkey from a 65 bytes compact signature and the recovery id
+fn recover_pubkey(
+    compact: &[u8],
+    recid: i32,
+    message: &[u8],
+) -> Result<secp256k1::PublicKey, LockAlgorithmError> {
+    let recid = RecoveryId::from_i32(recid).map_err(|_| LockAlgorithmError::InvalidSignature)?;
+    let signature = RecoverableSignature::from_compact(compact, recid)
+        .map_err(|_| LockAlgorithmError::InvalidSignature)?;
+    let msg = secp256k1::Message::from_slice(message)
+        .map_err(|_| LockAlgorithmError::InvalidSignature)?;
+    SECP256K1
+        .recover(&msg, &signature)
+        .map_err(|_| LockAlgorithmError::InvalidSignature)
+}
+
+#[derive(Debug, Default)]
+pub struct Secp256k1Tron;
+
+/// Usage
+/// register Secp256k1Tron to AccountLockManage
+///
+/// manage.register_lock_algorithm(code_hash, Box::new(Secp256k1Tron::default()));
+///
+/// The lock args is the 20 bytes Tron address without the `0x41` prefix,
+/// the signature is the `r | s | v` of the signed message: keccak256("\x19TRON Signed Message:\n32" | message)
+impl LockAlgorithm for Secp256k1Tron {
+    fn verify_signature(
+        &self,
+        lock_args: Bytes,
+        signature: Bytes,
+        message: H256,
+    ) -> Result<bool, LockAlgorithmError> {
+        if lock_args.len() != 20 {
+            return Err(LockAlgorithmError::InvalidLockArgs);
+        }
+        let mut hasher = Keccak256::new();
+        hasher.update("\x19TRON Signed Message:\n32");
+        hasher.update(message.as_slice());
+        let signing_message = hasher.finalize();
+
+        let signature = parse_signature(&signature)?;
+        // Tron wallets use 27 or 28 as v
+        let recid = match signature[64] {
+            v @ 27..=28 => v - 27,
+            v => v,
+        };
+        let pubkey = recover_pubkey(&signature[..64], recid as i32, &signing_message)?;
+        let pubkey_hash = {
+            let mut hasher = Keccak256::new();
+            hasher.update(&pubkey.serialize_uncompressed()[1..]);
+            let buf = hasher.finalize();
+            let mut pubkey_hash = [0u8; 20];
+            pubkey_hash.copy_from_slice(&buf[12..]);
+            pubkey_hash
+        };
+        Ok(pubkey_hash[..] == lock_args[..])
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Secp256k1Bitcoin;
+
+/// Usage
+/// register Secp256k1Bitcoin to AccountLockManage
+///
+/// manage.register_lock_algorithm(code_hash, Box::new(Secp256k1Bitcoin::default()));
+///
+/// The lock args is the P2PKH pubkey hash: ripemd160(sha256(pubkey)),
+/// the signature is the `header | r | s` of the Bitcoin signed message of the hex encoded message.
+impl LockAlgorithm for Secp256k1Bitcoin {
+    fn verify_signature(
+        &self,
+        lock_args: Bytes,
+        signature: Bytes,
+        message: H256,
+    ) -> Result<bool, LockAlgorithmError> {
+        if lock_args.len() != 20 {
+            return Err(LockAlgorithmError::InvalidLockArgs);
+        }
+        let signing_message = bitcoin_signing_message(&message);
+
+        let signature = parse_signature(&signature)?;
+        // header: 27 + recid, plus 4 if the pubkey is compressed
+        let header = signature[0];
+        if header < 27 || header > 34 {
+            return Err(LockAlgorithmError::InvalidSignature);
+        }
+        let compressed = header >= 31;
+        let recid = (header - 27) & 3;
+        let pubkey = recover_pubkey(&signature[1..], recid as i32, &signing_message)?;
+        let pubkey_hash = if compressed {
+            hash160(&pubkey.serialize())
+        } else {
+            hash160(&pubkey.serialize_uncompressed())
+        };
+        Ok(pubkey_hash[..] == lock_args[..])
+    }
+}
+
+const BITCOIN_SIGNING_PREFIX: &[u8] = b"\x18Bitcoin Signed Message:\n";
+
+/// sha256(sha256(varint(len(prefix)) | prefix | varint(len(msg)) | msg)), msg is the hex encoded message
+pub fn bitcoin_signing_message(message: &H256) -> [u8; 32] {
+    const HEX_CHARS: &[u8] = b"0123456789abcdef";
+    let mut hex_message = Vec::with_capacity(64);
+    for byte in message.as_slice() {
+        hex_message.push(HEX_CHARS[(byte >> 4) as usize]);
+        hex_message.push(HEX_CHARS[(byte & 0xf) as usize]);
+    }
+    let mut hasher = Sha256::new();
+    hasher.update(BITCOIN_SIGNING_PREFIX);
+    hasher.update(&[hex_message.len() as u8]);
+    hasher.update(&hex_message);
+    let buf = Sha256::digest(&hasher.finalize());
+    let mut signing_message = [0u8; 32];
+    signing_message.copy_from_slice(&buf[..]);
+    signing_message
+}
+
+// ripemd160(sha256(data))
+fn hash160(data: &[u8]) -> [u8; 20] {
+    let buf = Ripemd160::digest(&Sha256::digest(data));
+    let mut hash = [0u8; 20];
+    hash.copy_from_slice(&buf[..]);
+    hash
+}
+
 #[test]
 fn test_secp256k1_eth() {
     let message = H256::from([0u8; 32]);
-    let test_signature = Signature::from_slice(
-        &hex::decode("c2ae67217b65b785b1add7db1e9deb1df2ae2c7f57b9c29de0dfc40c59ab8d47341a863876660e3d0142b71248338ed71d2d4eb7ca078455565733095ac25a5800").expect("hex decode"))
-        .expect("create signature structure");
+    let test_signature = Bytes::from(
+        hex::decode("c2ae67217b65b785b1add7db1e9deb1df2ae2c7f57b9c29de0dfc40c59ab8d47341a863876660e3d0142b71248338ed71d2d4eb7ca078455565733095ac25a5800").expect("hex decode"));
     let address =
         Bytes::from(hex::decode("ffafb3db9377769f5b59bfff6cd2cf942a34ab17").expect("hex decode"));
     let eth = Secp256k1Eth {};
@@ -129,3 +267,95 @@ fn test_secp256k1_eth() {
         .expect("verify signature");
     assert!(result);
 }
+
+#[cfg(test)]
+fn sign_recoverable(message: &[u8]) -> (secp256k1::PublicKey, i32, [u8; 64]) {
+    let privkey = secp256k1::SecretKey::from_slice(&[7u8; 32]).expect("privkey");
+    let pubkey = secp256k1::PublicKey::from_secret_key(&SECP256K1, &privkey);
+    let msg = secp256k1::Message::from_slice(message).expect("message");
+    let (recid, compact) = SECP256K1
+        .sign_recoverable(&msg, &privkey)
+        .serialize_compact();
+    (pubkey, recid.to_i32(), compact)
+}
+
+#[test]
+fn test_secp256k1_tron_recid() {
+    let message = H256::from([3u8; 32]);
+    let mut hasher = Keccak256::new();
+    hasher.update("\x19TRON Signed Message:\n32");
+    hasher.update(message.as_slice());
+    let (pubkey, recid, compact) = sign_recoverable(&hasher.finalize());
+    let address = {
+        let mut hasher = Keccak256::new();
+        hasher.update(&pubkey.serialize_uncompressed()[1..]);
+        Bytes::from(hasher.finalize()[12..].to_vec())
+    };
+    let verify = |v: u8| {
+        let mut signature = compact.to_vec();
+        signature.push(v);
+        Secp256k1Tron::default().verify_signature(address.clone(), signature.into(), message)
+    };
+
+    // both the raw recid and 27 + recid are accepted
+    assert_eq!(verify(recid as u8), Ok(true));
+    assert_eq!(verify(recid as u8 + 27), Ok(true));
+    // the other recids recover another pubkey or nothing
+    for v in (0..=3).chain(27..=28) {
+        if v != recid as u8 && v != recid as u8 + 27 {
+            assert_ne!(verify(v), Ok(true), "v: {}", v);
+        }
+    }
+    // v out of the range
+    assert_eq!(verify(29), Err(LockAlgorithmError::InvalidSignature));
+    assert_eq!(verify(4), Err(LockAlgorithmError::InvalidSignature));
+}
+
+#[test]
+fn test_bitcoin_signing_message() {
+    let mut message = [0u8; 32];
+    for (i, byte) in message.iter_mut().enumerate() {
+        *byte = 0xe0 + i as u8;
+    }
+    // the message is encoded as 64 lowercase hex chars, the varint of its length is a single byte
+    let expected = {
+        let mut data = b"\x18Bitcoin Signed Message:\n".to_vec();
+        data.push(64);
+        data.extend_from_slice(b"e0e1e2e3e4e5e6e7e8e9eaebecedeeeff0f1f2f3f4f5f6f7f8f9fafbfcfdfeff");
+        Sha256::digest(&Sha256::digest(&data))
+    };
+    let signing_message = bitcoin_signing_message(&message.into());
+    assert_eq!(signing_message[..], expected[..]);
+    assert_eq!(
+        hex::encode(signing_message),
+        "fc0202ed36235da3717a0f15207580d36035eec7acc65d9c5467cae6c9faaf1f"
+    );
+}
+
+#[test]
+fn test_secp256k1_bitcoin_header() {
+    let message = H256::from([3u8; 32]);
+    let (pubkey, recid, compact) = sign_recoverable(&bitcoin_signing_message(&message));
+    let compressed_hash = Bytes::from(hash160(&pubkey.serialize()).to_vec());
+    let uncompressed_hash = Bytes::from(hash160(&pubkey.serialize_uncompressed()).to_vec());
+    let verify = |header: u8, pubkey_hash: &Bytes| {
+        let mut signature = vec![header];
+        signature.extend_from_slice(&compact);
+        Secp256k1Bitcoin::default().verify_signature(pubkey_hash.clone(), signature.into(), message)
+    };
+
+    let header = 27 + recid as u8;
+    assert_eq!(verify(header, &uncompressed_hash), Ok(true));
+    assert_eq!(verify(header + 4, &compressed_hash), Ok(true));
+    // the header decides the pubkey format
+    assert_eq!(verify(header, &compressed_hash), Ok(false));
+    assert_eq!(verify(header + 4, &uncompressed_hash), Ok(false));
+    assert_eq!(
+        verify(26, &compressed_hash),
+        Err(LockAlgorithmError::InvalidSignature)
+    );
+    assert_eq!(
+        verify(35, &compressed_hash),
+        Err(LockAlgorithmError::InvalidSignature)
+    );
+}
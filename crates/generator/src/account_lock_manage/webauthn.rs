@@ -0,0 +1,162 @@
+use super::LockAlgorithm;
+use crate::error::LockAlgorithmError;
+use gw_common::blake2b::new_blake2b;
+use gw_common::H256;
+use gw_types::bytes::Bytes;
+use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+use sha2::{Digest, Sha256};
+use std::convert::TryFrom;
+
+const BLAKE160_SIZE: usize = 20;
+const COMPRESSED_PUBKEY_SIZE: usize = 33;
+const SIGNATURE_SIZE: usize = 64;
+// pubkey | signature | authenticator_data_len
+const WEBAUTHN_HEADER_SIZE: usize = COMPRESSED_PUBKEY_SIZE + SIGNATURE_SIZE + 4;
+// rp_id_hash | flags | sign_count
+const MIN_AUTHENTICATOR_DATA_SIZE: usize = 37;
+const RP_ID_HASH_SIZE: usize = 32;
+const FLAGS_OFFSET: usize = 32;
+const FLAG_USER_PRESENT: u8 = 0x01;
+const CLIENT_DATA_TYPE_GET: &str = "webauthn.get";
+
+/// The parsed WebAuthn assertion
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebAuthnSignature {
+    pub pubkey: [u8; COMPRESSED_PUBKEY_SIZE],
+    pub signature: [u8; SIGNATURE_SIZE],
+    pub authenticator_data: Bytes,
+    pub client_data_json: Bytes,
+}
+
+impl WebAuthnSignature {
+    /// signature: compressed pubkey | r | s | authenticator_data_len(u32 LE) | authenticator_data | client_data_json
+    pub fn from_slice(signature: &[u8]) -> Result<Self, LockAlgorithmError> {
+        if signature.len() < WEBAUTHN_HEADER_SIZE {
+            return Err(LockAlgorithmError::InvalidSignature);
+        }
+        let mut pubkey = [0u8; COMPRESSED_PUBKEY_SIZE];
+        pubkey.copy_from_slice(&signature[..COMPRESSED_PUBKEY_SIZE]);
+        let mut sig = [0u8; SIGNATURE_SIZE];
+        sig.copy_from_slice(&signature[COMPRESSED_PUBKEY_SIZE..WEBAUTHN_HEADER_SIZE - 4]);
+        let authenticator_data_len = {
+            let mut buf = [0u8; 4];
+            buf.copy_from_slice(&signature[WEBAUTHN_HEADER_SIZE - 4..WEBAUTHN_HEADER_SIZE]);
+            u32::from_le_bytes(buf) as usize
+        };
+        let rest = &signature[WEBAUTHN_HEADER_SIZE..];
+        if authenticator_data_len < MIN_AUTHENTICATOR_DATA_SIZE
+            || authenticator_data_len > rest.len()
+        {
+            return Err(LockAlgorithmError::InvalidSignature);
+        }
+        let (authenticator_data, client_data_json) = rest.split_at(authenticator_data_len);
+        Ok(WebAuthnSignature {
+            pubkey,
+            signature: sig,
+            authenticator_data: Bytes::from(authenticator_data.to_vec()),
+            client_data_json: Bytes::from(client_data_json.to_vec()),
+        })
+    }
+
+    pub fn to_bytes(&self) -> Bytes {
+        let mut signature = Vec::with_capacity(
+            WEBAUTHN_HEADER_SIZE + self.authenticator_data.len() + self.client_data_json.len(),
+        );
+        signature.extend_from_slice(&self.pubkey);
+        signature.extend_from_slice(&self.signature);
+        signature.extend_from_slice(&(self.authenticator_data.len() as u32).to_le_bytes());
+        signature.extend_from_slice(&self.authenticator_data);
+        signature.extend_from_slice(&self.client_data_json);
+        signature.into()
+    }
+
+    /// The data signed by the authenticator: authenticator_data | sha256(client_data_json)
+    pub fn signed_data(&self) -> Vec<u8> {
+        let mut data = self.authenticator_data.to_vec();
+        data.extend_from_slice(&Sha256::digest(&self.client_data_json));
+        data
+    }
+}
+
+/// The WebAuthn challenge of a message, it's the base64url encoded message without padding
+pub fn webauthn_challenge(message: &H256) -> String {
+    base64::encode_config(message.as_slice(), base64::URL_SAFE_NO_PAD)
+}
+
+#[derive(Debug)]
+pub struct Secp256r1WebAuthn {
+    rp_id_hash: [u8; RP_ID_HASH_SIZE],
+}
+
+impl Secp256r1WebAuthn {
+    /// The assertions must be created for the relying party `rp_id`, e.g. "wallet.example"
+    pub fn new(rp_id: &str) -> Self {
+        let mut rp_id_hash = [0u8; RP_ID_HASH_SIZE];
+        rp_id_hash.copy_from_slice(&Sha256::digest(rp_id.as_bytes()));
+        Secp256r1WebAuthn { rp_id_hash }
+    }
+}
+
+/// Usage
+/// register Secp256r1WebAuthn to AccountLockManage
+///
+/// manage.register_lock_algorithm(code_hash, Box::new(Secp256r1WebAuthn::new(rp_id)));
+///
+/// The lock args is blake160(compressed secp256r1 pubkey), the signature is a WebAuthn assertion,
+/// the RP ID hash of the authenticator data must be the hash of `rp_id`,
+/// and the challenge in the client data JSON must be the message.
+///
+/// NOTICE only the layer2 verification is implemented, there is no layer1 lock script for it yet,
+/// so Generator::new rejects a rollup config allowing it as an EOA type.
+impl LockAlgorithm for Secp256r1WebAuthn {
+    fn has_onchain_lock(&self) -> bool {
+        false
+    }
+
+    fn verify_signature(
+        &self,
+        lock_args: Bytes,
+        signature: Bytes,
+        message: H256,
+    ) -> Result<bool, LockAlgorithmError> {
+        if lock_args.len() != BLAKE160_SIZE {
+            return Err(LockAlgorithmError::InvalidLockArgs);
+        }
+        let assertion = WebAuthnSignature::from_slice(&signature)?;
+        let pubkey_hash = {
+            let mut buf = [0u8; 32];
+            let mut hasher = new_blake2b();
+            hasher.update(&assertion.pubkey);
+            hasher.finalize(&mut buf);
+            let mut pubkey_hash = [0u8; BLAKE160_SIZE];
+            pubkey_hash.copy_from_slice(&buf[..BLAKE160_SIZE]);
+            pubkey_hash
+        };
+        if pubkey_hash[..] != lock_args[..] {
+            return Ok(false);
+        }
+
+        // the assertion must be created for the relying party
+        if assertion.authenticator_data[..RP_ID_HASH_SIZE] != self.rp_id_hash[..] {
+            return Ok(false);
+        }
+        // the user must be present
+        if assertion.authenticator_data[FLAGS_OFFSET] & FLAG_USER_PRESENT == 0 {
+            return Ok(false);
+        }
+        // check the client data is an assertion of the message
+        let client_data: serde_json::Value = serde_json::from_slice(&assertion.client_data_json)
+            .map_err(|_| LockAlgorithmError::InvalidSignature)?;
+        if client_data["type"].as_str() != Some(CLIENT_DATA_TYPE_GET)
+            || client_data["challenge"].as_str() != Some(webauthn_challenge(&message).as_str())
+        {
+            return Ok(false);
+        }
+
+        let pubkey = VerifyingKey::from_sec1_bytes(&assertion.pubkey)
+            .map_err(|_| LockAlgorithmError::InvalidSignature)?;
+        let signature = Signature::try_from(&assertion.signature[..])
+            .map_err(|_| LockAlgorithmError::InvalidSignature)?;
+        Ok(pubkey.verify(&assertion.signed_data(), &signature).is_ok())
+    }
+}
@@ -0,0 +1,62 @@
+//! Account script allow-lists
+//!
+//! The same rules as the on-chain scripts: an EOA must use a `Type` hash type script
+//! whose code hash is in `RollupConfig.allowed_eoa_type_hashes`, and a contract account
+//! must use one whose code hash is in `RollupConfig.allowed_contract_type_hashes`.
+//!
+//! NOTICE an empty list disables the check, which is only for devnets and tests,
+//! the on-chain scripts reject all accounts in this case.
+
+use crate::error::AccountError;
+use gw_types::{
+    core::ScriptHashType,
+    packed::{Byte32Vec, RollupConfig, Script},
+    prelude::*,
+};
+
+fn is_allowed(allowed_type_hashes: Byte32Vec, script: &Script) -> bool {
+    if allowed_type_hashes.is_empty() {
+        return true;
+    }
+    if script.hash_type() != ScriptHashType::Type.into() {
+        return false;
+    }
+    let code_hash = script.code_hash();
+    allowed_type_hashes
+        .into_iter()
+        .any(|type_hash| type_hash.as_slice() == code_hash.as_slice())
+}
+
+/// Check the script of an external owned account
+pub fn check_eoa_script(rollup_config: &RollupConfig, script: &Script) -> Result<(), AccountError> {
+    if is_allowed(rollup_config.allowed_eoa_type_hashes(), script) {
+        Ok(())
+    } else {
+        Err(AccountError::UnknownEOAScript {
+            script_hash: script.hash().into(),
+        })
+    }
+}
+
+/// Check the script of a contract account
+pub fn check_contract_script(
+    rollup_config: &RollupConfig,
+    script: &Script,
+) -> Result<(), AccountError> {
+    if is_allowed(rollup_config.allowed_contract_type_hashes(), script) {
+        Ok(())
+    } else {
+        Err(AccountError::UnknownContractScript {
+            script_hash: script.hash().into(),
+        })
+    }
+}
+
+/// Check the script of a new account, which can be either an EOA or a contract
+pub fn check_account_script(
+    rollup_config: &RollupConfig,
+    script: &Script,
+) -> Result<(), AccountError> {
+    check_eoa_script(rollup_config, script)
+        .or_else(|_| check_contract_script(rollup_config, script))
+}
@@ -3,15 +3,19 @@ use crate::builtin_scripts::{
     SUDT_GENERATOR, SUDT_VALIDATOR, SUDT_VALIDATOR_CODE_HASH,
 };
 use crate::code_hash;
+use crate::error::BackendError;
 use gw_common::H256;
+use gw_config::BackendConfig;
 use gw_types::bytes::Bytes;
-use std::collections::HashMap;
+use std::{collections::HashMap, fs, path::Path, sync::RwLock};
 
 #[derive(Clone)]
 pub struct Backend {
     pub validator: Bytes,
     pub generator: Bytes,
     pub validator_code_hash: H256,
+    /// Type hash of the validator cell on layer1, `None` if the validator has no type script
+    pub validator_script_type_hash: Option<H256>,
 }
 
 impl Backend {
@@ -21,18 +25,70 @@ impl Backend {
             validator,
             generator,
             validator_code_hash,
+            validator_script_type_hash: None,
         }
     }
+
+    /// Load the binaries from files,
+    /// the code hash of the validator must equal to the `validator_code_hash` of the config
+    pub fn from_config(config: &BackendConfig) -> Result<Backend, BackendError> {
+        let validator = read_binary(&config.validator_path)?;
+        let generator = read_binary(&config.generator_path)?;
+        let mut backend = Backend::from_binaries(validator, generator);
+        backend.validator_script_type_hash = config.validator_script_type_hash.map(Into::into);
+        let expected: H256 = config.validator_code_hash.into();
+        if backend.validator_code_hash != expected {
+            return Err(BackendError::CodeHashMismatch {
+                expected,
+                actual: backend.validator_code_hash,
+            });
+        }
+        Ok(backend)
+    }
 }
 
-#[derive(Clone)]
+fn read_binary(path: &Path) -> Result<Bytes, BackendError> {
+    fs::read(path)
+        .map(Into::into)
+        .map_err(|err| BackendError::LoadBinary {
+            path: path.to_path_buf(),
+            reason: err.to_string(),
+        })
+}
+
+#[derive(Default)]
+struct Backends {
+    by_code_hash: HashMap<H256, Backend>,
+    // validator script type hash -> validator code hash
+    by_type_hash: HashMap<H256, H256>,
+}
+
+impl Backends {
+    fn insert(&mut self, backend: Backend) {
+        // remove the stale type hash index of the replaced backend
+        if let Some(old) = self.by_code_hash.get(&backend.validator_code_hash) {
+            if let Some(type_hash) = old.validator_script_type_hash {
+                self.by_type_hash.remove(&type_hash);
+            }
+        }
+        if let Some(type_hash) = backend.validator_script_type_hash {
+            self.by_type_hash
+                .insert(type_hash, backend.validator_code_hash);
+        }
+        self.by_code_hash
+            .insert(backend.validator_code_hash, backend);
+    }
+}
+
+/// Backends indexed by the validator code hash and the validator script type hash,
+/// new backends can be registered while the generator is running
 pub struct BackendManage {
-    backends: HashMap<H256, Backend>,
+    backends: RwLock<Backends>,
 }
 
 impl Default for BackendManage {
     fn default() -> Self {
-        let mut backend_manage = BackendManage {
+        let backend_manage = BackendManage {
             backends: Default::default(),
         };
 
@@ -41,6 +97,7 @@ impl Default for BackendManage {
             validator: META_CONTRACT_VALIDATOR.clone(),
             generator: META_CONTRACT_GENERATOR.clone(),
             validator_code_hash: META_CONTRACT_VALIDATOR_CODE_HASH.clone(),
+            validator_script_type_hash: None,
         });
 
         // Simple UDT
@@ -48,6 +105,7 @@ impl Default for BackendManage {
             validator: SUDT_VALIDATOR.clone(),
             generator: SUDT_GENERATOR.clone(),
             validator_code_hash: SUDT_VALIDATOR_CODE_HASH.clone(),
+            validator_script_type_hash: None,
         });
 
         backend_manage
@@ -55,11 +113,56 @@ impl Default for BackendManage {
 }
 
 impl BackendManage {
-    pub fn register_backend(&mut self, backend: Backend) {
-        self.backends.insert(backend.validator_code_hash, backend);
+    /// The builtin backends plus the backends declared in the config
+    pub fn from_config(configs: &[BackendConfig]) -> Result<Self, BackendError> {
+        let backend_manage = BackendManage::default();
+        backend_manage.load_backends(configs)?;
+        Ok(backend_manage)
+    }
+
+    /// Load backends from files and register them,
+    /// nothing is registered if any of the backends fails to load.
+    /// Returns the validator code hashes of the loaded backends
+    pub fn load_backends(&self, configs: &[BackendConfig]) -> Result<Vec<H256>, BackendError> {
+        let backends = configs
+            .iter()
+            .map(Backend::from_config)
+            .collect::<Result<Vec<_>, _>>()?;
+        let code_hashes = backends
+            .iter()
+            .map(|backend| backend.validator_code_hash)
+            .collect();
+        let mut registered = self.backends.write().expect("backends lock");
+        for backend in backends {
+            registered.insert(backend);
+        }
+        Ok(code_hashes)
+    }
+
+    pub fn register_backend(&self, backend: Backend) {
+        self.backends
+            .write()
+            .expect("backends lock")
+            .insert(backend);
+    }
+
+    /// Get backend by the validator code hash
+    pub fn get_backend(&self, code_hash: &H256) -> Option<Backend> {
+        self.backends
+            .read()
+            .expect("backends lock")
+            .by_code_hash
+            .get(code_hash)
+            .cloned()
     }
 
-    pub fn get_backend(&self, code_hash: &H256) -> Option<&Backend> {
-        self.backends.get(code_hash)
+    /// Get backend by the validator script type hash
+    pub fn get_backend_by_type_hash(&self, type_hash: &H256) -> Option<Backend> {
+        let backends = self.backends.read().expect("backends lock");
+        backends
+            .by_type_hash
+            .get(type_hash)
+            .and_then(|code_hash| backends.by_code_hash.get(code_hash))
+            .cloned()
     }
 }
@@ -24,4 +24,16 @@ lazy_static! {
         include_bytes!("../../../c/build/account_locks/eth-account-lock")
             .to_vec()
             .into();
+    pub static ref TRON_ACCOUNT_LOCK: Bytes =
+        include_bytes!("../../../c/build/account_locks/tron-account-lock")
+            .to_vec()
+            .into();
+    pub static ref BTC_ACCOUNT_LOCK: Bytes =
+        include_bytes!("../../../c/build/account_locks/btc-account-lock")
+            .to_vec()
+            .into();
+    pub static ref MULTISIG_ACCOUNT_LOCK: Bytes =
+        include_bytes!("../../../c/build/account_locks/multisig-account-lock")
+            .to_vec()
+            .into();
 }
@@ -1,6 +1,7 @@
 use ckb_vm::Error as VMError;
 use gw_common::{error::Error as StateError, sparse_merkle_tree::error::Error as SMTError, H256};
-use gw_types::packed::ChallengeTarget;
+use gw_types::{core::LogType, packed::ChallengeTarget};
+use std::path::PathBuf;
 use thiserror::Error;
 
 /// Error
@@ -34,6 +35,8 @@ pub enum LockAlgorithmError {
     InvalidSignature,
     #[error("Unknown account lock")]
     UnknownAccountLock,
+    #[error("Allowed EOA type {0:?} has no on-chain lock")]
+    NoOnchainLock(H256),
 }
 
 impl From<LockAlgorithmError> for Error {
@@ -86,6 +89,10 @@ pub enum AccountError {
     NonceOverflow,
     #[error("can't find script for account {account_id}")]
     ScriptNotFound { account_id: u32 },
+    #[error("script {script_hash:?} is not an allowed EOA script")]
+    UnknownEOAScript { script_hash: H256 },
+    #[error("script {script_hash:?} is not an allowed contract script")]
+    UnknownContractScript { script_hash: H256 },
 }
 
 impl From<AccountError> for Error {
@@ -119,6 +126,8 @@ pub enum TransactionError {
     ExceededMaxReadData { max_bytes: usize, used_bytes: usize },
     #[error("Exceeded maximum write data: max bytes {max_bytes}, writen bytes {used_bytes}")]
     ExceededMaxWriteData { max_bytes: usize, used_bytes: usize },
+    #[error("Exceeded maximum cycles: max cycles {max_cycles}")]
+    ExceededMaxCycles { max_cycles: u64 },
 }
 
 impl From<VMError> for TransactionError {
@@ -189,3 +198,21 @@ impl From<StateError> for TransactionValidateError {
         Self::State(err)
     }
 }
+
+#[derive(Error, Debug, PartialEq, Clone, Eq)]
+pub enum LogError {
+    #[error("Unknown log type {0}")]
+    UnknownLogType(u8),
+    #[error("Invalid topics of {log_type:?} log")]
+    InvalidTopics { log_type: LogType },
+    #[error("Invalid data of {log_type:?} log")]
+    InvalidData { log_type: LogType },
+}
+
+#[derive(Error, Debug, PartialEq, Clone, Eq)]
+pub enum BackendError {
+    #[error("Failed to load backend binary {path:?}: {reason}")]
+    LoadBinary { path: PathBuf, reason: String },
+    #[error("Validator code hash mismatch expected {expected:?} actual {actual:?}")]
+    CodeHashMismatch { expected: H256, actual: H256 },
+}
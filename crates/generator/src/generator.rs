@@ -1,5 +1,6 @@
 use crate::{
-    account_lock_manage::AccountLockManage,
+    account_lock_manage::{AccountLockManage, SignatureRequest},
+    allowed_scripts::{check_contract_script, check_eoa_script},
     backend_manage::BackendManage,
     error::{TransactionValidateError, WithdrawalError},
 };
@@ -8,7 +9,14 @@ use crate::{
     error::{Error, TransactionError, TransactionErrorWithContext},
     sudt::build_l2_sudt_script,
 };
-use crate::{error::AccountError, syscalls::L2Syscalls, types::RunResult};
+use crate::{
+    error::AccountError,
+    parallel::ParallelExecutor,
+    syscalls::L2Syscalls,
+    trace::{ExecutionTrace, Tracer},
+    types::{CallResult, RunResult, TransactionEstimation},
+    vm::run_program,
+};
 use crate::{error::LockAlgorithmError, traits::StateExt};
 use gw_common::{
     builtins::CKB_SUDT_ACCOUNT_ID,
@@ -17,20 +25,24 @@ use gw_common::{
     state::{build_account_field_key, State, GW_ACCOUNT_NONCE},
     H256,
 };
+use gw_config::{GeneratorConfig, VMEngine};
 use gw_traits::{ChainStore, CodeStore};
 use gw_types::{
+    bytes::Bytes,
     core::{ChallengeTargetType, ScriptHashType},
     packed::{
         BlockInfo, ChallengeTarget, DepositionRequest, L2Block, L2Transaction, RawL2Block,
-        RawL2Transaction, TxReceipt, WithdrawalRequest,
+        RawL2Transaction, RollupConfig, Script, TxReceipt, WithdrawalRequest,
     },
     prelude::*,
+    MAX_SIGNATURE_SIZE,
 };
+use std::convert::TryFrom;
 
-use ckb_vm::{
-    machine::asm::{AsmCoreMachine, AsmMachine},
-    DefaultMachineBuilder,
-};
+use ckb_vm::Error as VMError;
+
+/// Max cycles a tx can consume if the rollup config leaves it as 0
+pub const DEFAULT_MAX_CYCLES: u64 = 500_000_000;
 
 // TODO ensure this value
 const MIN_WITHDRAWAL_CAPACITY: u64 = 100_00000000;
@@ -50,6 +62,10 @@ pub struct Generator {
     backend_manage: BackendManage,
     account_lock_manage: AccountLockManage,
     rollup_type_script_hash: H256,
+    rollup_config: RollupConfig,
+    max_cycles: u64,
+    execution_threads: usize,
+    vm_engine: VMEngine,
 }
 
 impl Generator {
@@ -57,18 +73,74 @@ impl Generator {
         backend_manage: BackendManage,
         account_lock_manage: AccountLockManage,
         rollup_type_script_hash: H256,
-    ) -> Self {
-        Generator {
+        rollup_config: &RollupConfig,
+        config: &GeneratorConfig,
+    ) -> Result<Self, LockAlgorithmError> {
+        // the signatures of the allowed EOA types must be verifiable by the layer1 lock scripts
+        for type_hash in rollup_config.allowed_eoa_type_hashes().into_iter() {
+            let type_hash: [u8; 32] = type_hash.unpack();
+            if let Some(lock_algo) = account_lock_manage.get_lock_algorithm(&type_hash.into()) {
+                if !lock_algo.has_onchain_lock() {
+                    return Err(LockAlgorithmError::NoOnchainLock(type_hash.into()));
+                }
+            }
+        }
+        let max_cycles = match rollup_config.max_cycles().unpack() {
+            0 => DEFAULT_MAX_CYCLES,
+            max_cycles => max_cycles,
+        };
+        Ok(Generator {
             backend_manage,
             account_lock_manage,
             rollup_type_script_hash,
-        }
+            rollup_config: rollup_config.clone(),
+            max_cycles,
+            execution_threads: config.execution_threads,
+            vm_engine: config.vm_engine,
+        })
     }
 
     pub fn rollup_type_script_hash(&self) -> &H256 {
         &self.rollup_type_script_hash
     }
 
+    pub fn rollup_config(&self) -> &RollupConfig {
+        &self.rollup_config
+    }
+
+    /// Backends of the generator, new backends can be registered at runtime
+    pub fn backend_manage(&self) -> &BackendManage {
+        &self.backend_manage
+    }
+
+    /// Threads executing the txs of a block in parallel
+    pub fn execution_threads(&self) -> usize {
+        self.execution_threads
+    }
+
+    /// VM engine running the layer2 programs
+    pub fn vm_engine(&self) -> VMEngine {
+        self.vm_engine
+    }
+
+    /// Max cycles a tx can consume, committed in the rollup config
+    pub fn max_cycles(&self) -> u64 {
+        self.max_cycles
+    }
+
+    /// Calculate the fee of resources consumed by a tx, denominated in CKB,
+    /// the fee rates are committed in the rollup config.
+    ///
+    /// The fee is only an estimation, it is not charged by the state transition
+    /// since the on-chain validator can't measure the cycles of a backend
+    pub fn calculate_fee(&self, used_cycles: u64, write_bytes: u64) -> u128 {
+        let fee_per_cycle: u64 = self.rollup_config.fee_per_cycle().unpack();
+        let fee_per_write_byte: u64 = self.rollup_config.fee_per_write_byte().unpack();
+        let cycles_fee = (used_cycles as u128).saturating_mul(fee_per_cycle as u128);
+        let write_fee = (write_bytes as u128).saturating_mul(fee_per_write_byte as u128);
+        cycles_fee.saturating_add(write_fee)
+    }
+
     pub fn account_lock_manage(&self) -> &AccountLockManage {
         &self.account_lock_manage
     }
@@ -79,6 +151,34 @@ impl Generator {
         &self,
         state: &S,
         withdrawal_request: &WithdrawalRequest,
+    ) -> Result<(), Error> {
+        self.verify_withdrawal_request_except_nonce(state, withdrawal_request)?;
+
+        // check nonce
+        let raw = withdrawal_request.raw();
+        let account_script_hash: [u8; 32] = raw.account_script_hash().unpack();
+        let id = state
+            .get_account_id_by_script_hash(&account_script_hash.into())?
+            .ok_or(AccountError::UnknownAccount)?;
+        let expected_nonce = state.get_nonce(id)?;
+        let actual_nonce: u32 = raw.nonce().unpack();
+        if actual_nonce != expected_nonce {
+            return Err(WithdrawalError::Nonce {
+                expected: expected_nonce,
+                actual: actual_nonce,
+            }
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Verify withdrawal request except the nonce,
+    /// the mem pool accepts the withdrawals following the in pool entries of the account
+    /// Notice this function do not perform signature check
+    pub fn verify_withdrawal_request_except_nonce<S: State + CodeStore>(
+        &self,
+        state: &S,
+        withdrawal_request: &WithdrawalRequest,
     ) -> Result<(), Error> {
         let raw = withdrawal_request.raw();
         let account_script_hash: [u8; 32] = raw.account_script_hash().unpack();
@@ -123,17 +223,6 @@ impl Generator {
             // user can't withdrawal CKB token via SUDT fields
             return Err(WithdrawalError::WithdrawFakedCKB.into());
         }
-
-        // check nonce
-        let expected_nonce = state.get_nonce(id)?;
-        let actual_nonce: u32 = raw.nonce().unpack();
-        if actual_nonce != expected_nonce {
-            return Err(WithdrawalError::Nonce {
-                expected: expected_nonce,
-                actual: actual_nonce,
-            }
-            .into());
-        }
         Ok(())
     }
 
@@ -143,6 +232,30 @@ impl Generator {
         state: &S,
         withdrawal_request: &WithdrawalRequest,
     ) -> Result<(), Error> {
+        let request = self.withdrawal_signature_request(state, withdrawal_request)?;
+        self.account_lock_manage.verify_signature(&request)?;
+        Ok(())
+    }
+
+    /// Check the signatures of withdrawal requests in parallel,
+    /// return the results in order, the valid signatures are cached
+    pub fn check_withdrawal_request_signatures<S: State + CodeStore>(
+        &self,
+        state: &S,
+        withdrawal_requests: &[WithdrawalRequest],
+    ) -> Vec<Result<(), Error>> {
+        let requests = withdrawal_requests
+            .iter()
+            .map(|withdrawal_request| self.withdrawal_signature_request(state, withdrawal_request))
+            .collect();
+        self.verify_signature_requests(requests)
+    }
+
+    fn withdrawal_signature_request<S: State + CodeStore>(
+        &self,
+        state: &S,
+        withdrawal_request: &WithdrawalRequest,
+    ) -> Result<SignatureRequest, Error> {
         let raw = withdrawal_request.raw();
         let account_script_hash: [u8; 32] = raw.account_script_hash().unpack();
 
@@ -150,24 +263,24 @@ impl Generator {
         let account_script = state
             .get_script(&account_script_hash.into())
             .ok_or(StateError::MissingKey)?;
+        check_eoa_script(&self.rollup_config, &account_script)?;
         let lock_code_hash: [u8; 32] = account_script.code_hash().unpack();
-        let lock_algo = self
-            .account_lock_manage
+        self.account_lock_manage
             .get_lock_algorithm(&lock_code_hash.into())
             .ok_or(LockAlgorithmError::UnknownAccountLock)?;
 
-        let message = raw.calc_message(&self.rollup_type_script_hash);
-        let valid_signature = lock_algo.verify_signature(
-            account_script.args().unpack(),
-            withdrawal_request.signature(),
-            message.into(),
-        )?;
-
-        if !valid_signature {
+        let signature: Bytes = withdrawal_request.signature().unpack();
+        if signature.len() > MAX_SIGNATURE_SIZE {
             return Err(LockAlgorithmError::InvalidSignature.into());
         }
-
-        Ok(())
+        let message = raw.calc_message(&self.rollup_type_script_hash);
+        Ok(SignatureRequest {
+            witness_hash: withdrawal_request.witness_hash().into(),
+            lock_code_hash: lock_code_hash.into(),
+            lock_args: account_script.args().unpack(),
+            signature,
+            message: message.into(),
+        })
     }
 
     /// verify transaction
@@ -200,6 +313,30 @@ impl Generator {
         state: &S,
         tx: &L2Transaction,
     ) -> Result<(), TransactionValidateError> {
+        let request = self.transaction_signature_request(state, tx)?;
+        self.account_lock_manage.verify_signature(&request)?;
+        Ok(())
+    }
+
+    /// Check the signatures of txs in parallel,
+    /// return the results in order, the valid signatures are cached
+    pub fn check_transaction_signatures<S: State + CodeStore>(
+        &self,
+        state: &S,
+        txs: &[L2Transaction],
+    ) -> Vec<Result<(), TransactionValidateError>> {
+        let requests = txs
+            .iter()
+            .map(|tx| self.transaction_signature_request(state, tx))
+            .collect();
+        self.verify_signature_requests(requests)
+    }
+
+    fn transaction_signature_request<S: State + CodeStore>(
+        &self,
+        state: &S,
+        tx: &L2Transaction,
+    ) -> Result<SignatureRequest, TransactionValidateError> {
         let raw_tx = tx.raw();
         let sender_id: u32 = raw_tx.from_id().unpack();
         let receiver_id: u32 = raw_tx.to_id().unpack();
@@ -219,25 +356,56 @@ impl Generator {
             }
             .into());
         }
+        // check the sender & receiver scripts are allowed
         let script = state.get_script(&script_hash).expect("get script");
+        check_eoa_script(&self.rollup_config, &script)?;
+        let receiver_script = state
+            .get_script(&receiver_script_hash)
+            .expect("get receiver script");
+        check_contract_script(&self.rollup_config, &receiver_script)?;
         let lock_code_hash: [u8; 32] = script.code_hash().unpack();
 
+        let signature: Bytes = tx.signature().unpack();
+        if signature.len() > MAX_SIGNATURE_SIZE {
+            return Err(LockAlgorithmError::InvalidSignature.into());
+        }
         let message = raw_tx.calc_message(
             &self.rollup_type_script_hash,
             &script_hash,
             &receiver_script_hash,
         );
+        Ok(SignatureRequest {
+            witness_hash: tx.witness_hash().into(),
+            lock_code_hash: lock_code_hash.into(),
+            lock_args: script.args().unpack(),
+            signature,
+            message: message.into(),
+        })
+    }
 
-        let lock_algo = self
-            .account_lock_manage()
-            .get_lock_algorithm(&lock_code_hash.into())
-            .ok_or(LockAlgorithmError::UnknownAccountLock)?;
-        let valid_signature =
-            lock_algo.verify_signature(script.args().unpack(), tx.signature(), message.into())?;
-        if !valid_signature {
-            return Err(LockAlgorithmError::InvalidSignature.into());
-        }
-        Ok(())
+    // verify the signature requests in batch, the failed requests are returned as is
+    fn verify_signature_requests<E: From<LockAlgorithmError>>(
+        &self,
+        requests: Vec<Result<SignatureRequest, E>>,
+    ) -> Vec<Result<(), E>> {
+        let valid_requests: Vec<SignatureRequest> = requests
+            .iter()
+            .filter_map(|request| request.as_ref().ok().cloned())
+            .collect();
+        let mut verified = self
+            .account_lock_manage
+            .verify_signatures(&valid_requests, self.execution_threads)
+            .into_iter();
+        requests
+            .into_iter()
+            .map(|request| {
+                request?;
+                verified
+                    .next()
+                    .expect("verified signature")
+                    .map_err(Into::into)
+            })
+            .collect()
     }
 
     /// Apply l2 state transition
@@ -256,12 +424,19 @@ impl Generator {
         // apply withdrawal to state
         state.apply_withdrawal_requests(&withdrawal_requests)?;
         // apply deposition to state
-        state.apply_deposition_requests(&args.deposition_requests)?;
+        state.apply_deposition_requests(&self.rollup_config, &args.deposition_requests)?;
 
         // handle transactions
         let block_info = get_block_info(&raw_block);
         let block_hash = raw_block.hash();
         let mut receipts = Vec::with_capacity(args.l2block.transactions().len());
+        let raw_txs = args
+            .l2block
+            .transactions()
+            .into_iter()
+            .map(|tx| tx.raw())
+            .collect();
+        let mut executor = ParallelExecutor::new(self, chain, state, block_info, raw_txs);
         for (tx_index, tx) in args.l2block.transactions().into_iter().enumerate() {
             let raw_tx = tx.raw();
             // check nonce
@@ -283,7 +458,7 @@ impl Generator {
             }
             // build call context
             // NOTICE users only allowed to send HandleMessage CallType txs
-            let run_result = match self.execute_transaction(chain, state, &block_info, &raw_tx) {
+            let run_result = match executor.execute(state, tx_index) {
                 Ok(run_result) => run_result,
                 Err(err) => {
                     return Err(TransactionErrorWithContext::new(
@@ -297,7 +472,7 @@ impl Generator {
                     .into());
                 }
             };
-            state.apply_run_result(&run_result)?;
+            executor.apply(state, &run_result)?;
 
             let compacted_post_account_root = state.calculate_compacted_account_root()?;
             let tx_receipt = TxReceipt::new_builder()
@@ -319,6 +494,7 @@ impl Generator {
                         .collect::<Vec<_>>()
                         .pack(),
                 )
+                .used_cycles(run_result.used_cycles.pack())
                 .build();
             receipts.push(tx_receipt);
         }
@@ -335,16 +511,20 @@ impl Generator {
     ) -> Result<Option<Backend>, StateError> {
         Ok(state
             .get_script(&script_hash)
-            .and_then(|script| {
-                // only accept data script hash type for now
-                if script.hash_type() == ScriptHashType::Data.into() {
-                    let code_hash: [u8; 32] = script.code_hash().unpack();
-                    self.backend_manage.get_backend(&code_hash.into())
-                } else {
-                    None
-                }
-            })
-            .cloned())
+            .and_then(|script| self.get_backend_by_script(&script)))
+    }
+
+    /// Resolve the backend of an account script,
+    /// the code hash refers to the validator code hash or the validator script type hash
+    /// according to the hash type, the same as the rule on layer1
+    pub(crate) fn get_backend_by_script(&self, script: &Script) -> Option<Backend> {
+        let code_hash: [u8; 32] = script.code_hash().unpack();
+        match ScriptHashType::try_from(script.hash_type()).ok()? {
+            ScriptHashType::Data => self.backend_manage.get_backend(&code_hash.into()),
+            ScriptHashType::Type => self
+                .backend_manage
+                .get_backend_by_type_hash(&code_hash.into()),
+        }
     }
 
     /// execute a layer2 tx
@@ -355,30 +535,117 @@ impl Generator {
         block_info: &BlockInfo,
         raw_tx: &RawL2Transaction,
     ) -> Result<RunResult, TransactionError> {
-        let mut run_result = RunResult::default();
-        {
-            let core_machine = Box::<AsmCoreMachine>::default();
-            let machine_builder =
-                DefaultMachineBuilder::new(core_machine).syscall(Box::new(L2Syscalls {
-                    chain,
-                    state,
-                    block_info: block_info,
-                    raw_tx,
-                    result: &mut run_result,
-                    code_store: state,
-                }));
-            let mut machine = AsmMachine::new(machine_builder.build(), None);
-            let account_id = raw_tx.to_id().unpack();
-            let script_hash = state.get_script_hash(account_id)?;
-            let backend = self
-                .load_backend(state, &script_hash)?
-                .ok_or(TransactionError::BackendNotFound { script_hash })?;
-            machine.load_program(&backend.generator, &[])?;
-            let code = machine.run()?;
-            if code != 0 {
-                return Err(TransactionError::InvalidExitCode(code).into());
+        self.run_transaction(chain, state, block_info, raw_tx, None)
+    }
+
+    /// execute a layer2 tx in the trace mode,
+    /// the trace is returned even if the execution failed
+    pub fn execute_transaction_with_trace<S: State + CodeStore, C: ChainStore>(
+        &self,
+        chain: &C,
+        state: &S,
+        block_info: &BlockInfo,
+        raw_tx: &RawL2Transaction,
+    ) -> (Result<RunResult, TransactionError>, ExecutionTrace) {
+        let mut trace = ExecutionTrace::default();
+        let result = self.run_transaction(chain, state, block_info, raw_tx, Some(&mut trace));
+        (result, trace)
+    }
+
+    /// Execute a layer2 tx in the read-only mode, the tx is not required to be signed.
+    ///
+    /// The tx is executed on the given state, the writes are discarded and the nonce of the sender
+    /// is not increased
+    pub fn call<S: State + CodeStore, C: ChainStore>(
+        &self,
+        chain: &C,
+        state: &S,
+        block_info: &BlockInfo,
+        raw_tx: &RawL2Transaction,
+    ) -> Result<CallResult, TransactionError> {
+        let run_result =
+            self.run_backend(chain, state, block_info, raw_tx, self.max_cycles, None)?;
+        Ok(CallResult {
+            return_data: run_result.return_data,
+            logs: run_result.logs,
+            used_cycles: run_result.used_cycles,
+        })
+    }
+
+    /// Estimate the cost of a layer2 tx, the tx is not required to be signed.
+    ///
+    /// The cycles are the least max cycles that the tx can be executed with,
+    /// which are searched by bisection if the tx fails with its used cycles as the max cycles
+    pub fn estimate_transaction<S: State + CodeStore, C: ChainStore>(
+        &self,
+        chain: &C,
+        state: &S,
+        block_info: &BlockInfo,
+        raw_tx: &RawL2Transaction,
+    ) -> Result<TransactionEstimation, TransactionError> {
+        let run = |max_cycles| {
+            self.run_transaction_with_max_cycles(chain, state, block_info, raw_tx, max_cycles, None)
+        };
+        let mut run_result = run(self.max_cycles)?;
+        let mut cycles = self.max_cycles;
+        if let Ok(result) = run(run_result.used_cycles) {
+            cycles = run_result.used_cycles;
+            run_result = result;
+        } else {
+            // the tx fails with `low` cycles and succeeds with `cycles` cycles
+            let mut low = run_result.used_cycles;
+            while cycles - low > 1 {
+                let mid = low + (cycles - low) / 2;
+                match run(mid) {
+                    Ok(result) => {
+                        cycles = mid;
+                        run_result = result;
+                    }
+                    Err(_) => low = mid,
+                }
             }
         }
+        Ok(TransactionEstimation {
+            cycles,
+            used_cycles: run_result.used_cycles,
+            read_count: run_result.read_values.len(),
+            write_count: run_result.write_values.len(),
+            write_data_bytes: run_result.write_data.values().map(|data| data.len()).sum(),
+            write_bytes: run_result.write_bytes,
+            fee: self.calculate_fee(run_result.used_cycles, run_result.write_bytes),
+        })
+    }
+
+    /// Run the tx with the max cycles of the generator
+    pub(crate) fn run_transaction<S: State + CodeStore, C: ChainStore>(
+        &self,
+        chain: &C,
+        state: &S,
+        block_info: &BlockInfo,
+        raw_tx: &RawL2Transaction,
+        trace: Option<&mut ExecutionTrace>,
+    ) -> Result<RunResult, TransactionError> {
+        self.run_transaction_with_max_cycles(
+            chain,
+            state,
+            block_info,
+            raw_tx,
+            self.max_cycles,
+            trace,
+        )
+    }
+
+    fn run_transaction_with_max_cycles<S: State + CodeStore, C: ChainStore>(
+        &self,
+        chain: &C,
+        state: &S,
+        block_info: &BlockInfo,
+        raw_tx: &RawL2Transaction,
+        max_cycles: u64,
+        trace: Option<&mut ExecutionTrace>,
+    ) -> Result<RunResult, TransactionError> {
+        let mut run_result =
+            self.run_backend(chain, state, block_info, raw_tx, max_cycles, trace)?;
         // set nonce
         let sender_id: u32 = raw_tx.from_id().unpack();
         let nonce = state.get_nonce(sender_id)?;
@@ -412,6 +679,55 @@ impl Generator {
 
         Ok(run_result)
     }
+
+    // run the backend of the receiver, the nonce is not increased
+    fn run_backend<S: State + CodeStore, C: ChainStore>(
+        &self,
+        chain: &C,
+        state: &S,
+        block_info: &BlockInfo,
+        raw_tx: &RawL2Transaction,
+        max_cycles: u64,
+        mut trace: Option<&mut ExecutionTrace>,
+    ) -> Result<RunResult, TransactionError> {
+        let mut run_result = RunResult::default();
+        let account_id = raw_tx.to_id().unpack();
+        let script_hash = state.get_script_hash(account_id)?;
+        let backend = self
+            .load_backend(state, &script_hash)?
+            .ok_or(TransactionError::BackendNotFound { script_hash })?;
+        let mut coverage = trace.as_ref().map(|_| Default::default());
+        let (ret, used_cycles) = run_program(
+            self.vm_engine,
+            L2Syscalls {
+                chain,
+                state,
+                block_info: block_info,
+                raw_tx,
+                result: &mut run_result,
+                code_store: state,
+                generator: self,
+                tracer: trace.as_deref_mut().map(Tracer::new),
+            },
+            &backend.generator,
+            max_cycles,
+            coverage.as_mut(),
+        )?;
+        if let Some(trace) = trace {
+            trace.coverage = coverage.unwrap_or_default();
+            trace.used_cycles = used_cycles;
+            trace.exit_code = ret.as_ref().ok().copied();
+        }
+        let code = ret.map_err(|err| match err {
+            VMError::InvalidCycles => TransactionError::ExceededMaxCycles { max_cycles },
+            err => err.into(),
+        })?;
+        if code != 0 {
+            return Err(TransactionError::InvalidExitCode(code).into());
+        }
+        run_result.used_cycles = used_cycles;
+        Ok(run_result)
+    }
 }
 
 fn get_block_info(l2block: &RawL2Block) -> BlockInfo {
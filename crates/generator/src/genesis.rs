@@ -75,6 +75,15 @@ pub fn build_genesis_from_store(
         "ckb simple UDT account id"
     );
 
+    // index the short script hashes of the builtin accounts
+    let short_script_hash_index: u8 = rollup_config.short_script_hash_index().into();
+    if short_script_hash_index == 1 {
+        for id in &[reserved_id, ckb_sudt_id] {
+            let script_hash = tree.get_script_hash(*id)?;
+            tree.register_short_script_hash(&script_hash)?;
+        }
+    }
+
     // calculate post state
     let post_account = {
         let root = tree.calculate_root()?;
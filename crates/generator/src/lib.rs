@@ -2,16 +2,21 @@
 //! and generate new status that can be committed to layer1
 
 pub mod account_lock_manage;
+pub mod allowed_scripts;
 pub mod backend_manage;
 pub mod builtin_scripts;
 pub mod dummy_state;
 pub mod error;
 pub mod generator;
 pub mod genesis;
+pub mod logs;
+pub mod parallel;
 pub mod sudt;
 pub mod syscalls;
+pub mod trace;
 pub mod traits;
 mod types;
+mod vm;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,109 @@
+//! Typed logs
+//!
+//! Besides the logs emitted by contracts, sUDT transfers and account creations are
+//! recorded as typed logs, so they can be decoded and filtered from the tx receipts.
+
+use crate::error::LogError;
+use gw_common::{h256_ext::H256Ext, H256};
+use gw_types::{
+    bytes::Bytes,
+    core::LogType,
+    packed::{Byte32, LogItem},
+    prelude::*,
+};
+use std::convert::TryFrom;
+
+/// MAX topics of a log
+pub const MAX_LOG_TOPICS: usize = 4;
+
+/// Decoded log
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GwLog {
+    Contract {
+        account_id: u32,
+        topics: Vec<H256>,
+        data: Bytes,
+    },
+    SudtTransfer {
+        sudt_id: u32,
+        from_id: u32,
+        to_id: u32,
+        amount: u128,
+    },
+    AccountCreated {
+        account_id: u32,
+        script_hash: H256,
+    },
+}
+
+pub fn build_log(account_id: u32, log_type: LogType, topics: &[H256], data: Bytes) -> LogItem {
+    let topics: Vec<Byte32> = topics.iter().map(|topic| topic.pack()).collect();
+    LogItem::new_builder()
+        .account_id(account_id.pack())
+        .log_type(log_type.into())
+        .topics(topics.pack())
+        .data(data.pack())
+        .build()
+}
+
+pub fn build_sudt_transfer_log(sudt_id: u32, from_id: u32, to_id: u32, amount: u128) -> LogItem {
+    build_log(
+        sudt_id,
+        LogType::SudtTransfer,
+        &[H256::from_u32(from_id), H256::from_u32(to_id)],
+        Bytes::from(amount.to_le_bytes().to_vec()),
+    )
+}
+
+pub fn build_account_created_log(account_id: u32, script_hash: H256) -> LogItem {
+    build_log(
+        account_id,
+        LogType::AccountCreated,
+        &[script_hash],
+        Bytes::default(),
+    )
+}
+
+/// Decode a log according to its type
+pub fn parse_log(item: &LogItem) -> Result<GwLog, LogError> {
+    let log_type = LogType::try_from(item.log_type()).map_err(LogError::UnknownLogType)?;
+    let account_id: u32 = item.account_id().unpack();
+    let topics: Vec<H256> = item
+        .topics()
+        .into_iter()
+        .map(|topic| topic.unpack())
+        .collect();
+    let data: Bytes = item.data().unpack();
+    match log_type {
+        LogType::Contract => Ok(GwLog::Contract {
+            account_id,
+            topics,
+            data,
+        }),
+        LogType::SudtTransfer => {
+            if topics.len() != 2 {
+                return Err(LogError::InvalidTopics { log_type });
+            }
+            if data.len() != 16 {
+                return Err(LogError::InvalidData { log_type });
+            }
+            let mut amount = [0u8; 16];
+            amount.copy_from_slice(&data);
+            Ok(GwLog::SudtTransfer {
+                sudt_id: account_id,
+                from_id: topics[0].to_u32(),
+                to_id: topics[1].to_u32(),
+                amount: u128::from_le_bytes(amount),
+            })
+        }
+        LogType::AccountCreated => {
+            if topics.len() != 1 {
+                return Err(LogError::InvalidTopics { log_type });
+            }
+            Ok(GwLog::AccountCreated {
+                account_id,
+                script_hash: topics[0],
+            })
+        }
+    }
+}
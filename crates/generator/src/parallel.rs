@@ -0,0 +1,304 @@
+//! Optimistic parallel execution
+//!
+//! The txs of a block are executed speculatively in parallel against an untracked snapshot of
+//! the state before the txs, then the results are committed in order. A speculative result is reused only if the tx read
+//! nothing written by the previously committed txs, otherwise the tx is re-executed serially
+//! against the latest state, so the results are the same as the sequential execution.
+//!
+//! The VMs run on worker threads, but the state is only accessed by the calling thread,
+//! the workers send state reads to it via channels. The speculative reads are not recorded
+//! by the state tracker, the reads of a reused result are recorded when it's committed,
+//! so the touched keys are the same as the sequential execution.
+
+use crate::{
+    error::{Error, TransactionError},
+    traits::StateExt,
+    types::RunResult,
+    Generator,
+};
+use gw_common::{
+    error::Error as StateError,
+    state::{build_account_field_key, State, GW_ACCOUNT_SCRIPT_HASH},
+    H256,
+};
+use gw_db::error::Error as DBError;
+use gw_traits::{ChainStore, CodeStore};
+use gw_types::{
+    bytes::Bytes,
+    packed::{BlockInfo, RawL2Transaction, Script},
+    prelude::*,
+};
+use std::{
+    collections::HashSet,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, Receiver, Sender},
+    },
+};
+
+type TxResult = Result<RunResult, TransactionError>;
+
+/// Execute the txs of a block, the caller drives the execution in order:
+///
+/// 1. `execute` the tx against the latest state
+/// 2. `apply` the result if the tx is accepted
+///
+/// NOTICE the state must not be modified by other means during the execution,
+/// otherwise the conflicts can't be detected.
+pub struct ParallelExecutor<'a, C> {
+    generator: &'a Generator,
+    chain: &'a C,
+    block_info: BlockInfo,
+    raw_txs: Vec<RawL2Transaction>,
+    speculative_results: Vec<Option<TxResult>>,
+    // writes of the applied txs
+    written_keys: HashSet<H256>,
+    written_data: HashSet<H256>,
+    account_count_changed: bool,
+}
+
+impl<'a, C: ChainStore> ParallelExecutor<'a, C> {
+    /// Execute the txs speculatively, the reads of the speculative execution are untracked,
+    /// nothing is executed if the generator has less than 2 execution threads
+    pub fn new<S: State + CodeStore>(
+        generator: &'a Generator,
+        chain: &'a C,
+        state: &S,
+        block_info: BlockInfo,
+        raw_txs: Vec<RawL2Transaction>,
+    ) -> Self {
+        let threads = generator.execution_threads();
+        let speculative_results = if threads > 1 && raw_txs.len() > 1 {
+            speculate(generator, chain, state, &block_info, &raw_txs, threads)
+        } else {
+            vec![None; raw_txs.len()]
+        };
+        ParallelExecutor {
+            generator,
+            chain,
+            block_info,
+            raw_txs,
+            speculative_results,
+            written_keys: Default::default(),
+            written_data: Default::default(),
+            account_count_changed: false,
+        }
+    }
+
+    /// Execute the tx at `index` against the latest state
+    pub fn execute<S: State + CodeStore>(&mut self, state: &S, index: usize) -> TxResult {
+        let raw_tx = &self.raw_txs[index];
+        match self.speculative_results[index].take() {
+            Some(Ok(run_result)) if !self.is_conflicted(raw_tx, &run_result) => {
+                // record the reads in the state tracker
+                for key in run_result.read_values.keys() {
+                    state.get_raw(key)?;
+                }
+                Ok(run_result)
+            }
+            // re-execute the conflicted or failed txs
+            _ => self
+                .generator
+                .run_transaction(self.chain, state, &self.block_info, raw_tx, None),
+        }
+    }
+
+    /// Apply the result of an executed tx to the state
+    pub fn apply<S: State + CodeStore>(
+        &mut self,
+        state: &mut S,
+        run_result: &RunResult,
+    ) -> Result<(), Error> {
+        state.apply_run_result(run_result)?;
+        self.written_keys
+            .extend(run_result.write_values.keys().copied());
+        self.written_data
+            .extend(run_result.write_data.keys().copied());
+        if run_result.account_count.is_some() {
+            self.account_count_changed = true;
+        }
+        Ok(())
+    }
+
+    fn is_conflicted(&self, raw_tx: &RawL2Transaction, run_result: &RunResult) -> bool {
+        // the backend is loaded from the script of the receiver
+        let to_id: u32 = raw_tx.to_id().unpack();
+        let receiver_script_hash_key: H256 =
+            build_account_field_key(to_id, GW_ACCOUNT_SCRIPT_HASH).into();
+        self.written_keys.contains(&receiver_script_hash_key)
+            || run_result
+                .read_values
+                .keys()
+                .any(|key| self.written_keys.contains(key))
+            || run_result
+                .read_data
+                .keys()
+                .any(|data_hash| self.written_data.contains(data_hash))
+            || (run_result.read_account_count && self.account_count_changed)
+    }
+}
+
+enum Request {
+    GetRaw(H256),
+    GetAccountCount,
+    GetScript(H256),
+    GetData(H256),
+    GetBlockHash(u64),
+}
+
+enum Response {
+    Raw(Result<H256, StateError>),
+    AccountCount(Result<u32, StateError>),
+    Script(Option<Script>),
+    Data(Option<Bytes>),
+    BlockHash(Result<Option<H256>, DBError>),
+}
+
+enum Message {
+    Request { worker: usize, request: Request },
+    Executed { index: usize, result: TxResult },
+}
+
+fn speculate<S: State + CodeStore, C: ChainStore>(
+    generator: &Generator,
+    chain: &C,
+    state: &S,
+    block_info: &BlockInfo,
+    raw_txs: &[RawL2Transaction],
+    threads: usize,
+) -> Vec<Option<TxResult>> {
+    let mut results = vec![None; raw_txs.len()];
+    let next_index = AtomicUsize::new(0);
+    crossbeam_utils::thread::scope(|scope| {
+        let (messages, messages_receiver) = mpsc::channel();
+        let mut responses = Vec::with_capacity(threads);
+        for worker in 0..threads {
+            let (response_sender, response_receiver) = mpsc::channel();
+            responses.push(response_sender);
+            let remote_state = RemoteState {
+                worker,
+                messages: messages.clone(),
+                responses: response_receiver,
+            };
+            let next_index = &next_index;
+            scope.spawn(move |_| loop {
+                let index = next_index.fetch_add(1, Ordering::SeqCst);
+                if index >= raw_txs.len() {
+                    break;
+                }
+                let result = generator.run_transaction(
+                    &remote_state,
+                    &remote_state,
+                    block_info,
+                    &raw_txs[index],
+                    None,
+                );
+                remote_state
+                    .messages
+                    .send(Message::Executed { index, result })
+                    .expect("send result");
+            });
+        }
+        // the channel is closed once all the workers exit
+        drop(messages);
+        while let Ok(message) = messages_receiver.recv() {
+            match message {
+                Message::Request { worker, request } => {
+                    let response = serve(state, chain, request);
+                    responses[worker].send(response).expect("send response");
+                }
+                Message::Executed { index, result } => {
+                    results[index] = Some(result);
+                }
+            }
+        }
+    })
+    .expect("speculative execution");
+    results
+}
+
+fn serve<S: State + CodeStore, C: ChainStore>(state: &S, chain: &C, request: Request) -> Response {
+    match request {
+        Request::GetRaw(key) => Response::Raw(state.get_raw_untracked(&key)),
+        Request::GetAccountCount => Response::AccountCount(state.get_account_count()),
+        Request::GetScript(script_hash) => Response::Script(state.get_script(&script_hash)),
+        Request::GetData(data_hash) => Response::Data(state.get_data(&data_hash)),
+        Request::GetBlockHash(number) => {
+            Response::BlockHash(chain.get_block_hash_by_number(number))
+        }
+    }
+}
+
+// read-only view of the state in a worker thread
+struct RemoteState {
+    worker: usize,
+    messages: Sender<Message>,
+    responses: Receiver<Response>,
+}
+
+impl RemoteState {
+    fn request(&self, request: Request) -> Response {
+        self.messages
+            .send(Message::Request {
+                worker: self.worker,
+                request,
+            })
+            .expect("send request");
+        self.responses.recv().expect("receive response")
+    }
+}
+
+impl State for RemoteState {
+    fn get_raw(&self, key: &H256) -> Result<H256, StateError> {
+        match self.request(Request::GetRaw(*key)) {
+            Response::Raw(value) => value,
+            _ => unreachable!("unexpected response"),
+        }
+    }
+    fn update_raw(&mut self, _key: H256, _value: H256) -> Result<(), StateError> {
+        // the writes are recorded in the run result
+        Err(StateError::Store)
+    }
+    fn get_account_count(&self) -> Result<u32, StateError> {
+        match self.request(Request::GetAccountCount) {
+            Response::AccountCount(count) => count,
+            _ => unreachable!("unexpected response"),
+        }
+    }
+    fn set_account_count(&mut self, _count: u32) -> Result<(), StateError> {
+        Err(StateError::Store)
+    }
+    fn calculate_root(&self) -> Result<H256, StateError> {
+        Err(StateError::Store)
+    }
+}
+
+impl CodeStore for RemoteState {
+    fn insert_script(&mut self, _script_hash: H256, _script: Script) {
+        unreachable!("insert script in a worker");
+    }
+    fn get_script(&self, script_hash: &H256) -> Option<Script> {
+        match self.request(Request::GetScript(*script_hash)) {
+            Response::Script(script) => script,
+            _ => unreachable!("unexpected response"),
+        }
+    }
+    fn insert_data(&mut self, _data_hash: H256, _code: Bytes) {
+        unreachable!("insert data in a worker");
+    }
+    fn get_data(&self, data_hash: &H256) -> Option<Bytes> {
+        match self.request(Request::GetData(*data_hash)) {
+            Response::Data(data) => data,
+            _ => unreachable!("unexpected response"),
+        }
+    }
+}
+
+impl ChainStore for RemoteState {
+    fn get_block_hash_by_number(&self, number: u64) -> Result<Option<H256>, DBError> {
+        match self.request(Request::GetBlockHash(number)) {
+            Response::BlockHash(block_hash) => block_hash,
+            _ => unreachable!("unexpected response"),
+        }
+    }
+}
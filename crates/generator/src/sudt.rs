@@ -1,5 +1,10 @@
 use crate::builtin_scripts::SUDT_VALIDATOR_CODE_HASH;
-use gw_types::{bytes::Bytes, core::ScriptHashType, packed::Script, prelude::*};
+use gw_types::{
+    bytes::Bytes,
+    core::ScriptHashType,
+    packed::{RollupConfig, Script},
+    prelude::*,
+};
 
 pub fn build_l2_sudt_script(l1_sudt_script_hash: [u8; 32]) -> Script {
     let args = Bytes::from(l1_sudt_script_hash.to_vec());
@@ -12,3 +17,17 @@ pub fn build_l2_sudt_script(l1_sudt_script_hash: [u8; 32]) -> Script {
         .hash_type(ScriptHashType::Data.into())
         .build()
 }
+
+/// Check whether the script is a layer2 sUDT script, which refers to the sUDT validator
+/// by the code hash or by the `l2_sudt_validator_script_type_hash` of the rollup config
+pub fn is_l2_sudt_script(rollup_config: &RollupConfig, script: &Script) -> bool {
+    let code_hash = script.code_hash();
+    if script.hash_type() == ScriptHashType::Type.into() {
+        code_hash.as_slice()
+            == rollup_config
+                .l2_sudt_validator_script_type_hash()
+                .as_slice()
+    } else {
+        code_hash.as_slice() == SUDT_VALIDATOR_CODE_HASH.as_slice()
+    }
+}
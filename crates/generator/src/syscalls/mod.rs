@@ -1,51 +1,82 @@
-use crate::types::RunResult;
+use crate::{
+    allowed_scripts::check_account_script,
+    generator::Generator,
+    logs::{build_account_created_log, build_log, build_sudt_transfer_log, MAX_LOG_TOPICS},
+    sudt::is_l2_sudt_script,
+    trace::Tracer,
+    types::RunResult,
+};
 use ckb_vm::{
     memory::Memory,
-    registers::{A0, A1, A2, A3, A7},
+    registers::{A0, A1, A2, A3, A4, A5, A7},
     Error as VMError, Register, SupportMachine, Syscalls,
 };
 use gw_common::{
     blake2b::new_blake2b,
     h256_ext::H256Ext,
     state::{
-        build_account_field_key, build_script_hash_to_account_id_key, State, GW_ACCOUNT_NONCE,
-        GW_ACCOUNT_SCRIPT_HASH,
+        build_account_field_key, build_account_key, build_data_hash_key,
+        build_script_hash_to_account_id_key, build_short_script_hash_to_script_hash_key,
+        build_sudt_allowance_key, State, GW_ACCOUNT_NONCE, GW_ACCOUNT_SCRIPT_HASH,
+        SHORT_SCRIPT_HASH_LEN,
     },
     H256,
 };
 use gw_traits::{ChainStore, CodeStore};
 use gw_types::{
     bytes::Bytes,
-    packed::{BlockInfo, LogItem, RawL2Transaction, Script},
+    core::LogType,
+    packed::{BlockInfo, RawL2Transaction, Script},
     prelude::*,
 };
-use std::cmp;
+use std::{cmp, convert::TryFrom};
 
 /* Constants */
 // 24KB is max ethereum contract code size
 const MAX_SET_RETURN_DATA_SIZE: u64 = 1024 * 24;
 
 /* Syscall numbers */
-const SYS_STORE: u64 = 3051;
-const SYS_LOAD: u64 = 3052;
-const SYS_SET_RETURN_DATA: u64 = 3061;
-const SYS_CREATE: u64 = 3071;
+pub(crate) const SYS_STORE: u64 = 3051;
+pub(crate) const SYS_LOAD: u64 = 3052;
+pub(crate) const SYS_SET_RETURN_DATA: u64 = 3061;
+pub(crate) const SYS_CREATE: u64 = 3071;
+pub(crate) const SYS_CALL: u64 = 3081;
 /* internal syscall numbers */
-const SYS_LOAD_TRANSACTION: u64 = 4051;
-const SYS_LOAD_BLOCKINFO: u64 = 4052;
-const SYS_LOAD_SCRIPT_HASH_BY_ACCOUNT_ID: u64 = 4053;
-const SYS_LOAD_ACCOUNT_ID_BY_SCRIPT_HASH: u64 = 4054;
-const SYS_LOAD_ACCOUNT_SCRIPT: u64 = 4055;
-const SYS_STORE_DATA: u64 = 4056;
-const SYS_LOAD_DATA: u64 = 4057;
-const SYS_GET_BLOCK_HASH: u64 = 4058;
-const SYS_LOG: u64 = 4061;
+pub(crate) const SYS_LOAD_TRANSACTION: u64 = 4051;
+pub(crate) const SYS_LOAD_BLOCKINFO: u64 = 4052;
+pub(crate) const SYS_LOAD_SCRIPT_HASH_BY_ACCOUNT_ID: u64 = 4053;
+pub(crate) const SYS_LOAD_ACCOUNT_ID_BY_SCRIPT_HASH: u64 = 4054;
+pub(crate) const SYS_LOAD_ACCOUNT_SCRIPT: u64 = 4055;
+pub(crate) const SYS_STORE_DATA: u64 = 4056;
+pub(crate) const SYS_LOAD_DATA: u64 = 4057;
+pub(crate) const SYS_GET_BLOCK_HASH: u64 = 4058;
+pub(crate) const SYS_LOG: u64 = 4061;
+pub(crate) const SYS_LOAD_NONCE: u64 = 4062;
+pub(crate) const SYS_LOAD_SUDT_BALANCE: u64 = 4063;
+pub(crate) const SYS_LOAD_SCRIPT_HASH_BY_SHORT_SCRIPT_HASH: u64 = 4064;
+pub(crate) const SYS_LOAD_ACCOUNT_COUNT: u64 = 4065;
+pub(crate) const SYS_TRANSFER_SUDT: u64 = 4066;
+pub(crate) const SYS_EMIT_LOG: u64 = 4067;
 /* CKB compatible syscalls */
-const DEBUG_PRINT_SYSCALL_NUMBER: u64 = 2177;
+pub(crate) const DEBUG_PRINT_SYSCALL_NUMBER: u64 = 2177;
 
 /* Syscall errors */
 pub const SUCCESS: u8 = 0;
 pub const ERROR_DUPLICATED_SCRIPT_HASH: u8 = std::i8::MAX as u8;
+// same as GW_ERROR_NOT_FOUND in gw_errors.h
+pub const ERROR_NOT_FOUND: u8 = 42;
+// same as GW_ERROR_UNKNOWN_SCRIPT_CODE_HASH in gw_errors.h
+pub const ERROR_UNKNOWN_SCRIPT_CODE_HASH: u8 = 50;
+// same as GW_ERROR_NOT_SUPPORTED in gw_errors.h
+pub const ERROR_NOT_SUPPORTED: u8 = 51;
+// same as GW_ERROR_INVALID_SUDT & GW_ERROR_PERMISSION_DENIED in gw_errors.h
+pub const ERROR_INVALID_SUDT: u8 = 52;
+pub const ERROR_PERMISSION_DENIED: u8 = 53;
+// same as the errors in sudt_utils.h
+pub const ERROR_INSUFFICIENT_BALANCE: u8 = 12;
+pub const ERROR_AMOUNT_OVERFLOW: u8 = 13;
+pub const ERROR_TO_ID: u8 = 14;
+pub const ERROR_ACCOUNT_NOT_EXISTS: u8 = 15;
 
 pub(crate) struct L2Syscalls<'a, S, C> {
     pub(crate) chain: &'a C,
@@ -54,6 +85,8 @@ pub(crate) struct L2Syscalls<'a, S, C> {
     pub(crate) raw_tx: &'a RawL2Transaction,
     pub(crate) code_store: &'a dyn CodeStore,
     pub(crate) result: &'a mut RunResult,
+    pub(crate) generator: &'a Generator,
+    pub(crate) tracer: Option<Tracer<'a>>,
 }
 
 fn load_data_u32<Mac: SupportMachine>(machine: &mut Mac, addr: u64) -> Result<u32, VMError> {
@@ -118,6 +151,30 @@ impl<'a, S: State, C: ChainStore, Mac: SupportMachine> Syscalls<Mac> for L2Sysca
     }
 
     fn ecall(&mut self, machine: &mut Mac) -> Result<bool, VMError> {
+        if let Some(tracer) = self.tracer.as_mut() {
+            let code = machine.registers()[A7].to_u64();
+            let args = [
+                machine.registers()[A0].to_u64(),
+                machine.registers()[A1].to_u64(),
+                machine.registers()[A2].to_u64(),
+                machine.registers()[A3].to_u64(),
+            ];
+            tracer.begin_syscall(code, args, machine.cycles());
+        }
+        let ret = self.handle_ecall(machine);
+        if let Some(tracer) = self.tracer.as_mut() {
+            let return_code = match ret {
+                Ok(true) => Some(machine.registers()[A0].to_u64()),
+                _ => None,
+            };
+            tracer.end_syscall(return_code);
+        }
+        ret
+    }
+}
+
+impl<'a, S: State, C: ChainStore> L2Syscalls<'a, S, C> {
+    fn handle_ecall<Mac: SupportMachine>(&mut self, machine: &mut Mac) -> Result<bool, VMError> {
         let code = machine.registers()[A7].to_u64();
         match code {
             SYS_STORE => {
@@ -125,7 +182,21 @@ impl<'a, S: State, C: ChainStore, Mac: SupportMachine> Syscalls<Mac> for L2Sysca
                 let key = load_data_h256(machine, key_addr)?;
                 let value_addr = machine.registers()[A1].to_u64();
                 let value = load_data_h256(machine, value_addr)?;
+                // the account id and the key are optional,
+                // they are the preimage of the raw key
+                let preimage_addr = machine.registers()[A3].to_u64();
+                if preimage_addr != 0 {
+                    let account_id = machine.registers()[A2].to_u32();
+                    let account_key = load_data_h256(machine, preimage_addr)?;
+                    if build_account_key(account_id, account_key.as_slice()) == key {
+                        self.result
+                            .write_preimages
+                            .insert(key, (account_id, account_key));
+                    }
+                }
                 self.result.write_values.insert(key, value);
+                self.result.write_bytes += value.as_slice().len() as u64;
+                self.trace_write(key, value);
                 machine.set_register(A0, Mac::REG::from_u8(SUCCESS));
                 Ok(true)
             }
@@ -137,6 +208,7 @@ impl<'a, S: State, C: ChainStore, Mac: SupportMachine> Syscalls<Mac> for L2Sysca
                 machine
                     .memory_mut()
                     .store_bytes(value_addr, &value.as_slice())?;
+                self.trace_output(value.as_slice());
                 machine.set_register(A0, Mac::REG::from_u8(SUCCESS));
                 Ok(true)
             }
@@ -147,6 +219,7 @@ impl<'a, S: State, C: ChainStore, Mac: SupportMachine> Syscalls<Mac> for L2Sysca
                     return Err(VMError::Unexpected);
                 }
                 let data = load_bytes(machine, data_addr, len as usize)?;
+                self.trace_output(&data);
                 self.result.return_data = data;
                 machine.set_register(A0, Mac::REG::from_u8(SUCCESS));
                 Ok(true)
@@ -163,6 +236,12 @@ impl<'a, S: State, C: ChainStore, Mac: SupportMachine> Syscalls<Mac> for L2Sysca
                 })?;
                 let script_hash = script.hash();
 
+                // Return error if the script is not allowed
+                if check_account_script(self.generator.rollup_config(), &script).is_err() {
+                    machine.set_register(A0, Mac::REG::from_u8(ERROR_UNKNOWN_SCRIPT_CODE_HASH));
+                    return Ok(true);
+                }
+
                 // Return error if script_hash is exists
                 if self
                     .get_account_id_by_script_hash(&script_hash.into())?
@@ -174,38 +253,72 @@ impl<'a, S: State, C: ChainStore, Mac: SupportMachine> Syscalls<Mac> for L2Sysca
 
                 // Same logic from State::create_account()
                 let id = self.get_account_count()?;
-                self.result.write_values.insert(
-                    build_account_field_key(id, GW_ACCOUNT_NONCE).into(),
-                    H256::zero(),
-                );
-                self.result.write_values.insert(
-                    build_account_field_key(id, GW_ACCOUNT_SCRIPT_HASH).into(),
-                    script_hash.into(),
-                );
-                // script hash to id
-                self.result.write_values.insert(
-                    build_script_hash_to_account_id_key(&script_hash[..]).into(),
-                    H256::from_u32(id),
+                let writes = [
+                    (
+                        build_account_field_key(id, GW_ACCOUNT_NONCE).into(),
+                        H256::zero(),
+                    ),
+                    (
+                        build_account_field_key(id, GW_ACCOUNT_SCRIPT_HASH).into(),
+                        script_hash.into(),
+                    ),
+                    // script hash to id
+                    (
+                        build_script_hash_to_account_id_key(&script_hash[..]).into(),
+                        H256::from_u32(id),
+                    ),
+                ];
+                for (key, value) in writes.iter() {
+                    self.result.write_values.insert(*key, *value);
+                    self.trace_write(*key, *value);
+                }
+                // short script hash to script hash, if the rollup config enables the index
+                let short_script_hash_index: u8 = self
+                    .generator
+                    .rollup_config()
+                    .short_script_hash_index()
+                    .into();
+                let short_script_hash_key = build_short_script_hash_to_script_hash_key(
+                    &script_hash[..SHORT_SCRIPT_HASH_LEN],
                 );
+                if short_script_hash_index == 1 && self.get_raw(&short_script_hash_key)?.is_zero() {
+                    let value = script_hash.into();
+                    self.result
+                        .write_values
+                        .insert(short_script_hash_key, value);
+                    self.trace_write(short_script_hash_key, value);
+                }
                 self.result
                     .new_scripts
                     .insert(script_hash.into(), script.as_slice().to_vec());
                 self.set_account_count(id + 1)?;
+                self.result
+                    .logs
+                    .push(build_account_created_log(id, script_hash.into()));
                 machine
                     .memory_mut()
                     .store32(&account_id_addr, &Mac::REG::from_u32(id))?;
+                self.trace_output(&id.to_le_bytes());
                 machine.set_register(A0, Mac::REG::from_u8(SUCCESS));
                 Ok(true)
             }
+            SYS_CALL => {
+                // the on-chain validator can't execute the program of another account,
+                // so nested calls are rejected as sys_call of validator_utils.h does
+                machine.set_register(A0, Mac::REG::from_u8(ERROR_NOT_SUPPORTED));
+                Ok(true)
+            }
             SYS_LOAD_BLOCKINFO => {
                 let data = self.block_info.as_slice();
                 store_data(machine, data)?;
+                self.trace_output(data);
                 machine.set_register(A0, Mac::REG::from_u8(SUCCESS));
                 Ok(true)
             }
             SYS_LOAD_TRANSACTION => {
                 let data = self.raw_tx.as_slice();
                 store_data(machine, data)?;
+                self.trace_output(data);
                 machine.set_register(A0, Mac::REG::from_u8(SUCCESS));
                 Ok(true)
             }
@@ -223,6 +336,7 @@ impl<'a, S: State, C: ChainStore, Mac: SupportMachine> Syscalls<Mac> for L2Sysca
                 machine
                     .memory_mut()
                     .store_bytes(account_id_addr, &account_id.to_le_bytes()[..])?;
+                self.trace_output(&account_id.to_le_bytes());
                 machine.set_register(A0, Mac::REG::from_u8(SUCCESS));
                 Ok(true)
             }
@@ -236,6 +350,7 @@ impl<'a, S: State, C: ChainStore, Mac: SupportMachine> Syscalls<Mac> for L2Sysca
                 machine
                     .memory_mut()
                     .store_bytes(script_hash_addr, script_hash.as_slice())?;
+                self.trace_output(script_hash.as_slice());
                 machine.set_register(A0, Mac::REG::from_u8(SUCCESS));
                 Ok(true)
             }
@@ -269,6 +384,7 @@ impl<'a, S: State, C: ChainStore, Mac: SupportMachine> Syscalls<Mac> for L2Sysca
                     machine
                         .memory_mut()
                         .store_bytes(script_addr, &data[offset..offset + new_len])?;
+                    self.trace_output(&data[offset..offset + new_len]);
                 }
                 machine
                     .memory_mut()
@@ -285,6 +401,8 @@ impl<'a, S: State, C: ChainStore, Mac: SupportMachine> Syscalls<Mac> for L2Sysca
                 let mut hasher = new_blake2b();
                 hasher.update(data.as_ref());
                 hasher.finalize(&mut data_hash);
+                self.result.write_bytes += data.len() as u64;
+                self.trace_write(build_data_hash_key(&data_hash), H256::one());
                 self.result
                     .write_data
                     .insert(data_hash.into(), data.as_slice().to_vec());
@@ -318,6 +436,7 @@ impl<'a, S: State, C: ChainStore, Mac: SupportMachine> Syscalls<Mac> for L2Sysca
                     machine
                         .memory_mut()
                         .store_bytes(data_addr, &data_ref[offset..offset + new_len])?;
+                    self.trace_output(&data_ref[offset..offset + new_len]);
                 }
                 machine
                     .memory_mut()
@@ -342,6 +461,7 @@ impl<'a, S: State, C: ChainStore, Mac: SupportMachine> Syscalls<Mac> for L2Sysca
                     machine
                         .memory_mut()
                         .store_bytes(block_hash_addr, hash.as_slice())?;
+                    self.trace_output(hash.as_slice());
                     machine.set_register(A0, Mac::REG::from_u8(SUCCESS));
                 } else {
                     // Can not get block hash by number
@@ -349,18 +469,132 @@ impl<'a, S: State, C: ChainStore, Mac: SupportMachine> Syscalls<Mac> for L2Sysca
                 }
                 Ok(true)
             }
+            SYS_LOAD_NONCE => {
+                let account_id = machine.registers()[A0].to_u32();
+                let nonce_addr = machine.registers()[A1].to_u64();
+                let nonce = self
+                    .get_raw(&build_account_field_key(account_id, GW_ACCOUNT_NONCE))?
+                    .to_u32();
+                machine
+                    .memory_mut()
+                    .store_bytes(nonce_addr, &nonce.to_le_bytes())?;
+                self.trace_output(&nonce.to_le_bytes());
+                machine.set_register(A0, Mac::REG::from_u8(SUCCESS));
+                Ok(true)
+            }
+            SYS_LOAD_SUDT_BALANCE => {
+                let sudt_id = machine.registers()[A0].to_u32();
+                let account_id = machine.registers()[A1].to_u32();
+                let balance_addr = machine.registers()[A2].to_u64();
+                // same key as the sUDT contract
+                let key = build_account_key(sudt_id, H256::from_u32(account_id).as_slice());
+                let balance = self.get_raw(&key)?.to_u128();
+                machine
+                    .memory_mut()
+                    .store_bytes(balance_addr, &balance.to_le_bytes())?;
+                self.trace_output(&balance.to_le_bytes());
+                machine.set_register(A0, Mac::REG::from_u8(SUCCESS));
+                Ok(true)
+            }
+            SYS_LOAD_SCRIPT_HASH_BY_SHORT_SCRIPT_HASH => {
+                let short_script_hash_addr = machine.registers()[A0].to_u64();
+                let short_script_hash_len = machine.registers()[A1].to_u64();
+                let script_hash_addr = machine.registers()[A2].to_u64();
+                if short_script_hash_len != SHORT_SCRIPT_HASH_LEN as u64 {
+                    eprintln!(
+                        "syscall error: invalid short script hash length: {}",
+                        short_script_hash_len
+                    );
+                    return Err(VMError::Unexpected);
+                }
+                let short_script_hash =
+                    load_bytes(machine, short_script_hash_addr, SHORT_SCRIPT_HASH_LEN)?;
+                let script_hash = self.get_raw(&build_short_script_hash_to_script_hash_key(
+                    &short_script_hash,
+                ))?;
+                if script_hash.is_zero() {
+                    machine.set_register(A0, Mac::REG::from_u8(ERROR_NOT_FOUND));
+                    return Ok(true);
+                }
+                machine
+                    .memory_mut()
+                    .store_bytes(script_hash_addr, script_hash.as_slice())?;
+                self.trace_output(script_hash.as_slice());
+                machine.set_register(A0, Mac::REG::from_u8(SUCCESS));
+                Ok(true)
+            }
+            SYS_LOAD_ACCOUNT_COUNT => {
+                let count_addr = machine.registers()[A0].to_u64();
+                let count = self.get_account_count()?;
+                machine
+                    .memory_mut()
+                    .store_bytes(count_addr, &count.to_le_bytes())?;
+                self.trace_output(&count.to_le_bytes());
+                machine.set_register(A0, Mac::REG::from_u8(SUCCESS));
+                Ok(true)
+            }
+            SYS_TRANSFER_SUDT => {
+                let sudt_id = machine.registers()[A0].to_u32();
+                let from_id = machine.registers()[A1].to_u32();
+                let to_id = machine.registers()[A2].to_u32();
+                let amount_addr = machine.registers()[A3].to_u64();
+                let amount = {
+                    let mut buf = [0u8; 16];
+                    buf.copy_from_slice(&load_bytes(machine, amount_addr, buf.len())?);
+                    u128::from_le_bytes(buf)
+                };
+                let code = self.transfer_sudt(sudt_id, from_id, to_id, amount)?;
+                machine.set_register(A0, Mac::REG::from_u8(code));
+                Ok(true)
+            }
             SYS_LOG => {
                 let account_id = machine.registers()[A0].to_u32();
                 let data_len = machine.registers()[A1].to_u32();
                 let data_addr = machine.registers()[A2].to_u64();
 
                 let data = load_bytes(machine, data_addr, data_len as usize)?;
-                self.result.logs.push(
-                    LogItem::new_builder()
-                        .account_id(account_id.pack())
-                        .data(Bytes::from(data).pack())
-                        .build(),
-                );
+                self.trace_output(&data);
+                self.result.logs.push(build_log(
+                    account_id,
+                    LogType::Contract,
+                    &[],
+                    Bytes::from(data),
+                ));
+                machine.set_register(A0, Mac::REG::from_u8(SUCCESS));
+                Ok(true)
+            }
+            SYS_EMIT_LOG => {
+                let account_id = machine.registers()[A0].to_u32();
+                let log_type = machine.registers()[A1].to_u8();
+                let topics_addr = machine.registers()[A2].to_u64();
+                let topics_count = machine.registers()[A3].to_u64() as usize;
+                let data_len = machine.registers()[A4].to_u32();
+                let data_addr = machine.registers()[A5].to_u64();
+
+                let contract_id: u32 = self.raw_tx.to_id().unpack();
+                let log_type = match LogType::try_from(log_type) {
+                    Ok(log_type @ LogType::Contract) => log_type,
+                    // a contract can only emit transfer logs of itself
+                    Ok(log_type @ LogType::SudtTransfer) if account_id == contract_id => log_type,
+                    // account creation logs are emitted by the generator
+                    _ => {
+                        eprintln!("syscall error: invalid log type: {}", log_type);
+                        return Err(VMError::Unexpected);
+                    }
+                };
+                if topics_count > MAX_LOG_TOPICS {
+                    eprintln!("syscall error: too many log topics: {}", topics_count);
+                    return Err(VMError::Unexpected);
+                }
+                let mut topics = Vec::with_capacity(topics_count);
+                for i in 0..topics_count {
+                    topics.push(load_data_h256(machine, topics_addr + i as u64 * 32)?);
+                }
+                let data = load_bytes(machine, data_addr, data_len as usize)?;
+                self.trace_output(&data);
+                self.result
+                    .logs
+                    .push(build_log(account_id, log_type, &topics, Bytes::from(data)));
                 machine.set_register(A0, Mac::REG::from_u8(SUCCESS));
                 Ok(true)
             }
@@ -371,9 +605,7 @@ impl<'a, S: State, C: ChainStore, Mac: SupportMachine> Syscalls<Mac> for L2Sysca
             _ => Ok(false),
         }
     }
-}
 
-impl<'a, S: State, C: ChainStore> L2Syscalls<'a, S, C> {
     fn get_raw(&mut self, key: &H256) -> Result<H256, VMError> {
         let value = match self.result.write_values.get(&key) {
             Some(value) => *value,
@@ -383,9 +615,26 @@ impl<'a, S: State, C: ChainStore> L2Syscalls<'a, S, C> {
                 tree_value
             }
         };
+        self.trace_read(*key, value);
         Ok(value)
     }
-    fn get_account_count(&self) -> Result<u32, VMError> {
+    fn trace_read(&mut self, key: H256, value: H256) {
+        if let Some(tracer) = self.tracer.as_mut() {
+            tracer.record_read(key, value);
+        }
+    }
+    fn trace_write(&mut self, key: H256, value: H256) {
+        if let Some(tracer) = self.tracer.as_mut() {
+            tracer.record_write(key, value);
+        }
+    }
+    fn trace_output(&mut self, data: &[u8]) {
+        if let Some(tracer) = self.tracer.as_mut() {
+            tracer.record_output(data);
+        }
+    }
+    fn get_account_count(&mut self) -> Result<u32, VMError> {
+        self.result.read_account_count = true;
         if let Some(id) = self.result.account_count {
             Ok(id)
         } else {
@@ -439,7 +688,79 @@ impl<'a, S: State, C: ChainStore> L2Syscalls<'a, S, C> {
         Ok(Some(id))
     }
 
-    fn output_debug<Mac: SupportMachine>(&self, machine: &mut Mac) -> Result<(), VMError> {
+    // transfer sUDT, returns the syscall error code,
+    // the contract can spend its own balance and the allowances approved to it
+    fn transfer_sudt(
+        &mut self,
+        sudt_id: u32,
+        from_id: u32,
+        to_id: u32,
+        amount: u128,
+    ) -> Result<u8, VMError> {
+        let contract_id: u32 = self.raw_tx.to_id().unpack();
+        if from_id == to_id {
+            return Ok(ERROR_TO_ID);
+        }
+        let sudt_script_hash = self.get_script_hash(sudt_id)?;
+        let is_sudt = !sudt_script_hash.is_zero()
+            && self
+                .get_script(&sudt_script_hash)
+                .map(|script| is_l2_sudt_script(self.generator.rollup_config(), &script))
+                .unwrap_or(false);
+        if !is_sudt {
+            return Ok(ERROR_INVALID_SUDT);
+        }
+        for id in &[from_id, to_id] {
+            if self.get_script_hash(*id)?.is_zero() {
+                return Ok(ERROR_ACCOUNT_NOT_EXISTS);
+            }
+        }
+
+        // spending the balance of another account consumes the allowance the owner approved
+        // to the contract, the sender of the tx is never trusted
+        let allowance_key = build_sudt_allowance_key(from_id, contract_id);
+        let new_allowance = if from_id == contract_id {
+            None
+        } else {
+            let raw_key = build_account_key(sudt_id, allowance_key.as_slice());
+            match self.get_raw(&raw_key)?.to_u128().checked_sub(amount) {
+                Some(allowance) => Some((raw_key, allowance)),
+                None => return Ok(ERROR_PERMISSION_DENIED),
+            }
+        };
+
+        // same keys as the sUDT contract
+        let from_key = build_account_key(sudt_id, H256::from_u32(from_id).as_slice());
+        let to_key = build_account_key(sudt_id, H256::from_u32(to_id).as_slice());
+        let new_from_balance = match self.get_raw(&from_key)?.to_u128().checked_sub(amount) {
+            Some(balance) => balance,
+            None => return Ok(ERROR_INSUFFICIENT_BALANCE),
+        };
+        let new_to_balance = match self.get_raw(&to_key)?.to_u128().checked_add(amount) {
+            Some(balance) => balance,
+            None => return Ok(ERROR_AMOUNT_OVERFLOW),
+        };
+        let mut writes = vec![
+            (from_key, H256::from_u32(from_id), new_from_balance),
+            (to_key, H256::from_u32(to_id), new_to_balance),
+        ];
+        if let Some((raw_key, allowance)) = new_allowance {
+            writes.push((raw_key, allowance_key, allowance));
+        }
+        for (raw_key, key, balance) in writes {
+            let value = H256::from_u128(balance);
+            self.result.write_values.insert(raw_key, value);
+            self.result.write_preimages.insert(raw_key, (sudt_id, key));
+            self.result.write_bytes += value.as_slice().len() as u64;
+            self.trace_write(raw_key, value);
+        }
+        self.result
+            .logs
+            .push(build_sudt_transfer_log(sudt_id, from_id, to_id, amount));
+        Ok(SUCCESS)
+    }
+
+    fn output_debug<Mac: SupportMachine>(&mut self, machine: &mut Mac) -> Result<(), VMError> {
         let mut addr = machine.registers()[A0].to_u64();
         let mut buffer = Vec::new();
 
@@ -457,6 +778,9 @@ impl<'a, S: State, C: ChainStore> L2Syscalls<'a, S, C> {
 
         let s = String::from_utf8(buffer).map_err(|_| VMError::ParseError)?;
         println!("[contract debug]: {}", s);
+        if let Some(tracer) = self.tracer.as_mut() {
+            tracer.record_debug_print(s);
+        }
         Ok(())
     }
 }
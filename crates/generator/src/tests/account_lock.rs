@@ -0,0 +1,326 @@
+use crate::account_lock_manage::{
+    always_success::AlwaysSuccess,
+    ed25519::Ed25519,
+    webauthn::{webauthn_challenge, Secp256r1WebAuthn, WebAuthnSignature},
+    AccountLockManage, LockAlgorithm, SignatureRequest,
+};
+use crate::error::{LockAlgorithmError, TransactionValidateError};
+use crate::{dummy_state::DummyState, traits::StateExt, Generator};
+use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+use gw_common::{blake2b::new_blake2b, H256};
+use gw_types::{
+    bytes::Bytes,
+    core::ScriptHashType,
+    packed::{L2Transaction, RawL2Transaction, RollupConfig, Script},
+    prelude::*,
+    MAX_SIGNATURE_SIZE,
+};
+use p256::ecdsa::{signature::Signer as _, Signature, SigningKey, VerifyingKey};
+use sha2::{Digest, Sha256};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+const ED25519_CODE_HASH: [u8; 32] = [1u8; 32];
+const WEBAUTHN_CODE_HASH: [u8; 32] = [2u8; 32];
+const COUNTING_CODE_HASH: [u8; 32] = [3u8; 32];
+const ALWAYS_SUCCESS_CODE_HASH: [u8; 32] = [4u8; 32];
+const CONTRACT_CODE_HASH: [u8; 32] = [5u8; 32];
+const RP_ID: &str = "wallet.example";
+
+// the signature is valid if it equals to the message, the verified signatures are counted
+struct CountingLock(Arc<AtomicUsize>);
+
+impl LockAlgorithm for CountingLock {
+    fn verify_signature(
+        &self,
+        _lock_args: Bytes,
+        signature: Bytes,
+        message: H256,
+    ) -> Result<bool, LockAlgorithmError> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        Ok(signature.as_ref() == message.as_slice())
+    }
+}
+
+fn blake160(data: &[u8]) -> Bytes {
+    let mut buf = [0u8; 32];
+    let mut hasher = new_blake2b();
+    hasher.update(data);
+    hasher.finalize(&mut buf);
+    Bytes::from(buf[..20].to_vec())
+}
+
+fn build_account_lock_manage() -> AccountLockManage {
+    let mut manage = AccountLockManage::default();
+    manage.register_lock_algorithm(ED25519_CODE_HASH.into(), Box::new(Ed25519::default()));
+    manage.register_lock_algorithm(
+        WEBAUTHN_CODE_HASH.into(),
+        Box::new(Secp256r1WebAuthn::new(RP_ID)),
+    );
+    manage
+}
+
+fn sign_webauthn(key: &SigningKey, client_data_json: String) -> WebAuthnSignature {
+    let pubkey = VerifyingKey::from(key).to_encoded_point(true);
+    let mut assertion = WebAuthnSignature {
+        pubkey: [0u8; 33],
+        signature: [0u8; 64],
+        // rp_id_hash | flags(user present and verified) | sign_count
+        authenticator_data: {
+            let mut data = Sha256::digest(RP_ID.as_bytes()).to_vec();
+            data.push(0x05);
+            data.extend_from_slice(&1u32.to_be_bytes());
+            data.into()
+        },
+        client_data_json: client_data_json.into_bytes().into(),
+    };
+    assertion.pubkey.copy_from_slice(pubkey.as_bytes());
+    let signature: Signature = key.sign(&assertion.signed_data());
+    assertion.signature.copy_from_slice(signature.as_ref());
+    assertion
+}
+
+#[test]
+fn test_ed25519_lock() {
+    let manage = build_account_lock_manage();
+    let lock_algo = manage
+        .get_lock_algorithm(&ED25519_CODE_HASH.into())
+        .expect("ed25519");
+    let secret = SecretKey::from_bytes(&[7u8; 32]).unwrap();
+    let public = PublicKey::from(&secret);
+    let keypair = Keypair { secret, public };
+    let lock_args = blake160(public.as_bytes());
+    let message = H256::from([3u8; 32]);
+    let signature = {
+        let mut buf = public.as_bytes().to_vec();
+        buf.extend_from_slice(&keypair.sign(message.as_slice()).to_bytes());
+        Bytes::from(buf)
+    };
+
+    let valid = lock_algo
+        .verify_signature(lock_args.clone(), signature.clone(), message)
+        .unwrap();
+    assert!(valid);
+    // wrong message
+    let valid = lock_algo
+        .verify_signature(lock_args.clone(), signature.clone(), [4u8; 32].into())
+        .unwrap();
+    assert!(!valid);
+    // wrong pubkey
+    let valid = lock_algo
+        .verify_signature(blake160(b"other"), signature.clone(), message)
+        .unwrap();
+    assert!(!valid);
+    // malformed signature
+    let err = lock_algo
+        .verify_signature(lock_args, signature.slice(1..), message)
+        .unwrap_err();
+    assert_eq!(err, LockAlgorithmError::InvalidSignature);
+}
+
+#[test]
+fn test_webauthn_lock() {
+    let manage = build_account_lock_manage();
+    let lock_algo = manage
+        .get_lock_algorithm(&WEBAUTHN_CODE_HASH.into())
+        .expect("webauthn");
+    let key = SigningKey::from_bytes(&[7u8; 32]).unwrap();
+    let message = H256::from([3u8; 32]);
+    let client_data_json = |ty: &str, message: &H256| {
+        format!(
+            r#"{{"type":"{}","challenge":"{}","origin":"https://wallet.example","crossOrigin":false}}"#,
+            ty,
+            webauthn_challenge(message)
+        )
+    };
+    let assertion = sign_webauthn(&key, client_data_json("webauthn.get", &message));
+    let lock_args = blake160(&assertion.pubkey);
+    assert_eq!(
+        WebAuthnSignature::from_slice(&assertion.to_bytes()).unwrap(),
+        assertion
+    );
+
+    let valid = lock_algo
+        .verify_signature(lock_args.clone(), assertion.to_bytes(), message)
+        .unwrap();
+    assert!(valid);
+    // the challenge is another message
+    let valid = lock_algo
+        .verify_signature(lock_args.clone(), assertion.to_bytes(), [4u8; 32].into())
+        .unwrap();
+    assert!(!valid);
+    // not an assertion
+    let created = sign_webauthn(&key, client_data_json("webauthn.create", &message));
+    let valid = lock_algo
+        .verify_signature(lock_args.clone(), created.to_bytes(), message)
+        .unwrap();
+    assert!(!valid);
+    // the user is not present
+    let mut absent = assertion.clone();
+    absent.authenticator_data = {
+        let mut data = absent.authenticator_data.to_vec();
+        data[32] = 0;
+        data.into()
+    };
+    let valid = lock_algo
+        .verify_signature(lock_args.clone(), absent.to_bytes(), message)
+        .unwrap();
+    assert!(!valid);
+    // another relying party
+    let mut other_rp = assertion.clone();
+    other_rp.authenticator_data = {
+        let mut data = Sha256::digest(b"evil.example").to_vec();
+        data.extend_from_slice(&assertion.authenticator_data[32..]);
+        data.into()
+    };
+    let signature: Signature = key.sign(&other_rp.signed_data());
+    other_rp.signature.copy_from_slice(signature.as_ref());
+    let valid = lock_algo
+        .verify_signature(lock_args.clone(), other_rp.to_bytes(), message)
+        .unwrap();
+    assert!(!valid);
+    // tampered client data
+    let mut tampered = assertion.clone();
+    tampered.client_data_json = client_data_json("webauthn.get", &message)
+        .replace("wallet", "evil")
+        .into_bytes()
+        .into();
+    let valid = lock_algo
+        .verify_signature(lock_args.clone(), tampered.to_bytes(), message)
+        .unwrap();
+    assert!(!valid);
+    // truncated authenticator data
+    let err = lock_algo
+        .verify_signature(lock_args, assertion.to_bytes().slice(..120), message)
+        .unwrap_err();
+    assert_eq!(err, LockAlgorithmError::InvalidSignature);
+}
+
+#[test]
+fn test_batch_verify_signatures() {
+    let verified_count = Arc::new(AtomicUsize::new(0));
+    let mut manage = build_account_lock_manage();
+    manage.register_lock_algorithm(
+        COUNTING_CODE_HASH.into(),
+        Box::new(CountingLock(Arc::clone(&verified_count))),
+    );
+    let build_request = |i: u8, valid: bool| {
+        let message = H256::from([i; 32]);
+        let signature = if valid { [i; 32] } else { [!i; 32] };
+        SignatureRequest {
+            witness_hash: [i; 32].into(),
+            lock_code_hash: COUNTING_CODE_HASH.into(),
+            lock_args: Bytes::new(),
+            signature: Bytes::from(signature.to_vec()),
+            message,
+        }
+    };
+    let mut requests: Vec<_> = (0..20u8).map(|i| build_request(i, i % 3 != 0)).collect();
+    requests.push(SignatureRequest {
+        lock_code_hash: [42u8; 32].into(),
+        ..build_request(20, true)
+    });
+
+    let results = manage.verify_signatures(&requests, 4);
+    assert_eq!(verified_count.load(Ordering::SeqCst), 20);
+    for (i, result) in results.iter().enumerate().take(20) {
+        if i % 3 != 0 {
+            assert_eq!(result, &Ok(()));
+        } else {
+            assert_eq!(result, &Err(LockAlgorithmError::InvalidSignature));
+        }
+    }
+    assert_eq!(results[20], Err(LockAlgorithmError::UnknownAccountLock));
+
+    // the valid signatures are cached, only the invalid ones are verified again
+    assert_eq!(manage.verify_signatures(&requests, 4), results);
+    assert_eq!(verified_count.load(Ordering::SeqCst), 27);
+    manage.verify_signature(&requests[1]).expect("cached");
+    assert_eq!(verified_count.load(Ordering::SeqCst), 27);
+    // the cache is hit only if the message is the same
+    let request = SignatureRequest {
+        message: [42u8; 32].into(),
+        ..requests[1].clone()
+    };
+    assert_eq!(
+        manage.verify_signature(&request),
+        Err(LockAlgorithmError::InvalidSignature)
+    );
+    assert_eq!(verified_count.load(Ordering::SeqCst), 28);
+}
+
+#[test]
+fn test_reject_eoa_type_without_onchain_lock() {
+    // the ed25519 lock can't be an allowed EOA type until the layer1 lock script exists
+    let rollup_config = RollupConfig::new_builder()
+        .allowed_eoa_type_hashes(vec![ED25519_CODE_HASH.pack()].pack())
+        .build();
+    let result = Generator::new(
+        Default::default(),
+        build_account_lock_manage(),
+        Default::default(),
+        &rollup_config,
+        &Default::default(),
+    );
+    assert_eq!(
+        result.err(),
+        Some(LockAlgorithmError::NoOnchainLock(ED25519_CODE_HASH.into()))
+    );
+}
+
+#[test]
+fn test_transaction_signature_size() {
+    let rollup_config = RollupConfig::new_builder()
+        .allowed_eoa_type_hashes(vec![ALWAYS_SUCCESS_CODE_HASH.pack()].pack())
+        .allowed_contract_type_hashes(vec![CONTRACT_CODE_HASH.pack()].pack())
+        .build();
+    let mut account_lock_manage = AccountLockManage::default();
+    account_lock_manage
+        .register_lock_algorithm(ALWAYS_SUCCESS_CODE_HASH.into(), Box::new(AlwaysSuccess));
+    let generator = Generator::new(
+        Default::default(),
+        account_lock_manage,
+        Default::default(),
+        &rollup_config,
+        &Default::default(),
+    )
+    .expect("create generator");
+    let mut tree = DummyState::default();
+    let build_script = |code_hash: [u8; 32], args: &[u8]| {
+        Script::new_builder()
+            .code_hash(code_hash.pack())
+            .hash_type(ScriptHashType::Type.into())
+            .args(Bytes::from(args.to_vec()).pack())
+            .build()
+    };
+    let eoa_id = tree
+        .create_account_from_script(build_script(ALWAYS_SUCCESS_CODE_HASH, b"alice"))
+        .expect("create account");
+    let contract_id = tree
+        .create_account_from_script(build_script(CONTRACT_CODE_HASH, b"contract"))
+        .expect("create account");
+    let build_tx = |signature_size: usize| {
+        L2Transaction::new_builder()
+            .raw(
+                RawL2Transaction::new_builder()
+                    .from_id(eoa_id.pack())
+                    .to_id(contract_id.pack())
+                    .build(),
+            )
+            .signature(Bytes::from(vec![42u8; signature_size]).pack())
+            .build()
+    };
+
+    generator
+        .check_transaction_signature(&tree, &build_tx(MAX_SIGNATURE_SIZE))
+        .expect("max size signature");
+    let err = generator
+        .check_transaction_signature(&tree, &build_tx(MAX_SIGNATURE_SIZE + 1))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionValidateError::Unlock(LockAlgorithmError::InvalidSignature)
+    );
+}
@@ -1,5 +1,8 @@
 use crate::genesis::{build_genesis, init_genesis};
-use gw_common::{sparse_merkle_tree::H256, state::State};
+use gw_common::{
+    sparse_merkle_tree::H256,
+    state::{State, SHORT_SCRIPT_HASH_LEN},
+};
 use gw_config::GenesisConfig;
 use gw_store::{
     state_db::{StateDBTransaction, StateDBVersion},
@@ -44,3 +47,40 @@ fn test_init_genesis() {
     let code_hash: [u8; 32] = script.code_hash().unpack();
     assert_ne!(code_hash, [0u8; 32]);
 }
+
+#[test]
+fn test_genesis_short_script_hash_index() {
+    let config = GenesisConfig { timestamp: 42 };
+    // the index is disabled by default, the genesis state is unchanged
+    let genesis = build_genesis(&config, &RollupConfig::default()).unwrap();
+    let genesis_block_hash: [u8; 32] = genesis.genesis.hash();
+    assert_eq!(genesis_block_hash, GENESIS_BLOCK_HASH);
+
+    let rollup_config = RollupConfig::new_builder()
+        .short_script_hash_index(1u8.into())
+        .build();
+    let indexed_genesis = build_genesis(&config, &rollup_config).unwrap();
+    assert_ne!(
+        indexed_genesis.genesis.raw().post_account().merkle_root(),
+        genesis.genesis.raw().post_account().merkle_root()
+    );
+    let store: Store = Store::open_tmp().unwrap();
+    init_genesis(
+        &store,
+        &config,
+        &rollup_config,
+        HeaderInfo::default(),
+        H256::zero(),
+    )
+    .unwrap();
+    let db = store.begin_transaction();
+    let state_db = StateDBTransaction::from_version(db, StateDBVersion::from_genesis());
+    let tree = state_db.account_state_tree().unwrap();
+    let script_hash = tree.get_script_hash(0).expect("script hash");
+    let short_script_hash = &script_hash.as_slice()[..SHORT_SCRIPT_HASH_LEN];
+    assert_eq!(
+        tree.get_script_hash_by_short_script_hash(short_script_hash)
+            .unwrap(),
+        Some(script_hash)
+    );
+}
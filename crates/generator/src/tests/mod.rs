@@ -1 +1,2 @@
+mod account_lock;
 mod genesis;
@@ -0,0 +1,135 @@
+//! Execution trace
+//!
+//! In the trace mode the generator records every syscall invoked by a contract,
+//! so contract developers can diagnose a failing tx offline.
+
+use gw_common::H256;
+use std::collections::BTreeMap;
+
+/// A syscall invoked by the contract
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyscallTrace {
+    /// syscall number
+    pub number: u64,
+    /// syscall name, `unknown` for syscalls that not handled by Godwoken
+    pub name: &'static str,
+    /// arguments in registers A0 ~ A3
+    pub args: [u64; 4],
+    /// keys & values read from the state
+    pub reads: Vec<(H256, H256)>,
+    /// keys & values written to the state
+    pub writes: Vec<(H256, H256)>,
+    /// data returned to the contract, set as return data, or logged
+    pub output: Vec<u8>,
+    /// value of register A0 after the syscall, `None` if the syscall failed
+    pub return_code: Option<u64>,
+    /// cycles consumed before the syscall
+    pub cycles: u64,
+    /// cycles consumed between the previous syscall (or the start of the program) and this one
+    pub cycles_delta: u64,
+}
+
+/// Structured trace of a tx execution
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExecutionTrace {
+    /// syscalls in the invoking order
+    pub syscalls: Vec<SyscallTrace>,
+    /// messages printed by the contract via the debug syscall
+    pub debug_prints: Vec<String>,
+    /// exit code of the contract, `None` if the VM failed
+    pub exit_code: Option<i8>,
+    /// total cycles consumed
+    pub used_cycles: u64,
+    /// executed times of the instructions by the program counter,
+    /// only recorded by the `Instrumented` VM engine
+    pub coverage: BTreeMap<u64, u64>,
+}
+
+pub(crate) struct Tracer<'a> {
+    trace: &'a mut ExecutionTrace,
+    current: Option<SyscallTrace>,
+}
+
+impl<'a> Tracer<'a> {
+    pub(crate) fn new(trace: &'a mut ExecutionTrace) -> Self {
+        Tracer {
+            trace,
+            current: None,
+        }
+    }
+
+    pub(crate) fn begin_syscall(&mut self, number: u64, args: [u64; 4], cycles: u64) {
+        let last_cycles = self
+            .trace
+            .syscalls
+            .last()
+            .map(|syscall| syscall.cycles)
+            .unwrap_or(0);
+        self.current = Some(SyscallTrace {
+            number,
+            name: syscall_name(number),
+            args,
+            cycles,
+            cycles_delta: cycles.saturating_sub(last_cycles),
+            ..Default::default()
+        });
+    }
+
+    pub(crate) fn end_syscall(&mut self, return_code: Option<u64>) {
+        if let Some(mut syscall) = self.current.take() {
+            syscall.return_code = return_code;
+            self.trace.syscalls.push(syscall);
+        }
+    }
+
+    pub(crate) fn record_read(&mut self, key: H256, value: H256) {
+        if let Some(syscall) = self.current.as_mut() {
+            syscall.reads.push((key, value));
+        }
+    }
+
+    pub(crate) fn record_write(&mut self, key: H256, value: H256) {
+        if let Some(syscall) = self.current.as_mut() {
+            syscall.writes.push((key, value));
+        }
+    }
+
+    pub(crate) fn record_output(&mut self, data: &[u8]) {
+        if let Some(syscall) = self.current.as_mut() {
+            syscall.output.extend_from_slice(data);
+        }
+    }
+
+    pub(crate) fn record_debug_print(&mut self, message: String) {
+        self.trace.debug_prints.push(message);
+    }
+}
+
+fn syscall_name(number: u64) -> &'static str {
+    use crate::syscalls::*;
+
+    match number {
+        SYS_STORE => "store",
+        SYS_LOAD => "load",
+        SYS_SET_RETURN_DATA => "set_return_data",
+        SYS_CREATE => "create",
+        SYS_CALL => "call",
+        SYS_LOAD_TRANSACTION => "load_transaction",
+        SYS_LOAD_BLOCKINFO => "load_blockinfo",
+        SYS_LOAD_SCRIPT_HASH_BY_ACCOUNT_ID => "load_script_hash_by_account_id",
+        SYS_LOAD_ACCOUNT_ID_BY_SCRIPT_HASH => "load_account_id_by_script_hash",
+        SYS_LOAD_ACCOUNT_SCRIPT => "load_account_script",
+        SYS_STORE_DATA => "store_data",
+        SYS_LOAD_DATA => "load_data",
+        SYS_GET_BLOCK_HASH => "get_block_hash",
+        SYS_LOG => "log",
+        SYS_LOAD_NONCE => "load_nonce",
+        SYS_LOAD_SUDT_BALANCE => "load_sudt_balance",
+        SYS_LOAD_SCRIPT_HASH_BY_SHORT_SCRIPT_HASH => "load_script_hash_by_short_script_hash",
+        SYS_LOAD_ACCOUNT_COUNT => "load_account_count",
+        SYS_TRANSFER_SUDT => "transfer_sudt",
+        SYS_EMIT_LOG => "emit_log",
+        DEBUG_PRINT_SYSCALL_NUMBER => "debug_print",
+        _ => "unknown",
+    }
+}
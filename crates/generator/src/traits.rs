@@ -1,3 +1,4 @@
+use crate::allowed_scripts::check_eoa_script;
 use crate::error::{AccountError, DepositionError, Error, WithdrawalError};
 use crate::sudt::build_l2_sudt_script;
 use crate::types::RunResult;
@@ -5,7 +6,7 @@ use gw_common::{builtins::CKB_SUDT_ACCOUNT_ID, state::State, CKB_SUDT_SCRIPT_ARG
 use gw_traits::CodeStore;
 use gw_types::{
     bytes::Bytes,
-    packed::{DepositionRequest, Script, WithdrawalRequest},
+    packed::{DepositionRequest, RollupConfig, Script, WithdrawalRequest},
     prelude::*,
 };
 
@@ -14,6 +15,7 @@ pub trait StateExt {
     fn apply_run_result(&mut self, run_result: &RunResult) -> Result<(), Error>;
     fn apply_deposition_request(
         &mut self,
+        rollup_config: &RollupConfig,
         deposition_request: &DepositionRequest,
     ) -> Result<(), Error>;
 
@@ -24,10 +26,11 @@ pub trait StateExt {
 
     fn apply_deposition_requests(
         &mut self,
+        rollup_config: &RollupConfig,
         deposition_requests: &[DepositionRequest],
     ) -> Result<(), Error> {
         for request in deposition_requests {
-            self.apply_deposition_request(request)?;
+            self.apply_deposition_request(rollup_config, request)?;
         }
         Ok(())
     }
@@ -56,6 +59,9 @@ impl<S: State + CodeStore> StateExt for S {
         for (k, v) in &run_result.write_values {
             self.update_raw((*k).into(), (*v).into())?;
         }
+        for (id, key) in run_result.write_preimages.values() {
+            self.record_account_key(*id, key)?;
+        }
         if let Some(id) = run_result.account_count {
             self.set_account_count(id)?;
         }
@@ -70,14 +76,25 @@ impl<S: State + CodeStore> StateExt for S {
         Ok(())
     }
 
-    fn apply_deposition_request(&mut self, request: &DepositionRequest) -> Result<(), Error> {
+    fn apply_deposition_request(
+        &mut self,
+        rollup_config: &RollupConfig,
+        request: &DepositionRequest,
+    ) -> Result<(), Error> {
+        // the account must be a valid EOA
+        check_eoa_script(rollup_config, &request.script())?;
+        let short_script_hash_index: u8 = rollup_config.short_script_hash_index().into();
         // find or create user account
         let account_script_hash = request.script().hash();
         let id = match self.get_account_id_by_script_hash(&account_script_hash.into())? {
             Some(id) => id,
             None => {
                 self.insert_script(account_script_hash.into(), request.script().clone());
-                self.create_account(account_script_hash.into())?
+                let id = self.create_account(account_script_hash.into())?;
+                if short_script_hash_index == 1 {
+                    self.register_short_script_hash(&account_script_hash.into())?;
+                }
+                id
             }
         };
         // mint CKB
@@ -93,7 +110,11 @@ impl<S: State + CodeStore> StateExt for S {
                 Some(id) => id,
                 None => {
                     self.insert_script(l2_sudt_script_hash.into(), l2_sudt_script);
-                    self.create_account(l2_sudt_script_hash.into())?
+                    let id = self.create_account(l2_sudt_script_hash.into())?;
+                    if short_script_hash_index == 1 {
+                        self.register_short_script_hash(&l2_sudt_script_hash.into())?;
+                    }
+                    id
                 }
             };
             // prevent fake CKB SUDT, the caller should filter these invalid depositions
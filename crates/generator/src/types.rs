@@ -5,18 +5,53 @@ use std::{
     fmt::{self, Display},
 };
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct RunResult {
     pub read_values: HashMap<H256, H256>,
     pub write_values: HashMap<H256, H256>,
+    // raw key -> (account id, key), preimages of the written account keys
+    pub write_preimages: HashMap<H256, (u32, H256)>,
     pub return_data: Vec<u8>,
     pub account_count: Option<u32>,
+    // the account count is read by SYS_CREATE or SYS_LOAD_ACCOUNT_COUNT
+    pub read_account_count: bool,
     pub new_scripts: HashMap<H256, Vec<u8>>,
     pub write_data: HashMap<H256, Vec<u8>>,
     // data hash -> data full size
     pub read_data: HashMap<H256, usize>,
     // log data
     pub logs: Vec<packed::LogItem>,
+    // cycles consumed by the VM
+    pub used_cycles: u64,
+    // bytes written via SYS_STORE & SYS_STORE_DATA
+    pub write_bytes: u64,
+}
+
+/// Result of a read-only call
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CallResult {
+    pub return_data: Vec<u8>,
+    pub logs: Vec<packed::LogItem>,
+    // cycles consumed by the VM
+    pub used_cycles: u64,
+}
+
+/// Estimated cost of a tx
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransactionEstimation {
+    // the least max cycles to execute the tx
+    pub cycles: u64,
+    // cycles consumed by the VM with the least max cycles
+    pub used_cycles: u64,
+    // count of the state keys read & written, including the nonce of the sender
+    pub read_count: usize,
+    pub write_count: usize,
+    // bytes of the data written via SYS_STORE_DATA
+    pub write_data_bytes: usize,
+    // bytes written via SYS_STORE & SYS_STORE_DATA
+    pub write_bytes: u64,
+    // fee of the consumed resources, denominated in CKB
+    pub fee: u128,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
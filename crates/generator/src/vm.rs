@@ -0,0 +1,91 @@
+//! VM engines
+//!
+//! The engines differ only in speed, they must produce the same results and consume
+//! the same cycles, which is checked by the differential tests.
+
+use crate::syscalls::L2Syscalls;
+#[cfg(feature = "detect-asm")]
+use ckb_vm::machine::asm::{AsmCoreMachine, AsmMachine};
+use ckb_vm::{
+    decoder::build_decoder, instructions::cost_model::instruction_cycles, DefaultCoreMachine,
+    DefaultMachine, DefaultMachineBuilder, Error as VMError, Register, SparseMemory,
+    SupportMachine, TraceMachine, WXorXMemory,
+};
+use gw_common::state::State;
+use gw_config::VMEngine;
+use gw_traits::ChainStore;
+use gw_types::bytes::Bytes;
+use std::collections::BTreeMap;
+
+type InterpreterCoreMachine = DefaultCoreMachine<u64, WXorXMemory<u64, SparseMemory<u64>>>;
+
+/// Run a generator program in a new VM,
+/// returns the exit code (or the VM error) and the consumed cycles.
+///
+/// The `Instrumented` engine counts the executed instructions by the program counter
+/// into `coverage`, which is ignored by the other engines
+pub(crate) fn run_program<S: State, C: ChainStore>(
+    engine: VMEngine,
+    syscalls: L2Syscalls<S, C>,
+    program: &Bytes,
+    max_cycles: u64,
+    coverage: Option<&mut BTreeMap<u64, u64>>,
+) -> Result<(Result<i8, VMError>, u64), VMError> {
+    match engine {
+        #[cfg(feature = "detect-asm")]
+        VMEngine::Asm => {
+            let core_machine = AsmCoreMachine::new_with_max_cycles(max_cycles);
+            let machine_builder = DefaultMachineBuilder::new(core_machine)
+                .instruction_cycle_func(Box::new(instruction_cycles))
+                .syscall(Box::new(syscalls));
+            let mut machine = AsmMachine::new(machine_builder.build(), None);
+            machine.load_program(program, &[])?;
+            let ret = machine.run();
+            Ok((ret, machine.machine.cycles()))
+        }
+        #[cfg(not(feature = "detect-asm"))]
+        VMEngine::Asm => run_program(
+            VMEngine::Interpreter,
+            syscalls,
+            program,
+            max_cycles,
+            coverage,
+        ),
+        VMEngine::Interpreter => {
+            let core_machine = InterpreterCoreMachine::new_with_max_cycles(max_cycles);
+            let machine_builder = DefaultMachineBuilder::new(core_machine)
+                .instruction_cycle_func(Box::new(instruction_cycles))
+                .syscall(Box::new(syscalls));
+            let mut machine = TraceMachine::new(machine_builder.build());
+            machine.load_program(program, &[])?;
+            let ret = machine.run();
+            Ok((ret, machine.machine.cycles()))
+        }
+        VMEngine::Instrumented => {
+            let core_machine = InterpreterCoreMachine::new_with_max_cycles(max_cycles);
+            let machine_builder = DefaultMachineBuilder::new(core_machine)
+                .instruction_cycle_func(Box::new(instruction_cycles))
+                .syscall(Box::new(syscalls));
+            let mut machine = machine_builder.build();
+            machine.load_program(program, &[])?;
+            let mut local_coverage = BTreeMap::new();
+            let ret = run_instrumented(&mut machine, coverage.unwrap_or(&mut local_coverage));
+            Ok((ret, machine.cycles()))
+        }
+    }
+}
+
+// execute the instructions one by one
+fn run_instrumented(
+    machine: &mut DefaultMachine<InterpreterCoreMachine>,
+    coverage: &mut BTreeMap<u64, u64>,
+) -> Result<i8, VMError> {
+    let mut decoder = build_decoder::<u64>();
+    machine.set_running(true);
+    while machine.running() {
+        let pc = machine.pc().to_u64();
+        machine.step(&mut decoder)?;
+        *coverage.entry(pc).or_default() += 1;
+    }
+    Ok(machine.exit_code())
+}
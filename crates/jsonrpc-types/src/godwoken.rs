@@ -1,5 +1,4 @@
 use crate::blockchain::Script;
-use crate::fixed_bytes::Byte65;
 use ckb_fixed_hash::H256;
 use ckb_jsonrpc_types::{JsonBytes, Uint128, Uint32, Uint64};
 use failure::{err_msg, Error as FailureError};
@@ -52,7 +51,7 @@ impl From<packed::RawL2Transaction> for RawL2Transaction {
 #[serde(rename_all = "snake_case")]
 pub struct L2Transaction {
     pub raw: RawL2Transaction,
-    pub signature: Byte65,
+    pub signature: JsonBytes,
 }
 
 impl From<L2Transaction> for packed::L2Transaction {
@@ -61,7 +60,7 @@ impl From<L2Transaction> for packed::L2Transaction {
 
         packed::L2Transaction::new_builder()
             .raw(raw.into())
-            .signature(signature.into())
+            .signature(signature.into_bytes().pack())
             .build()
     }
 }
@@ -70,7 +69,7 @@ impl From<packed::L2Transaction> for L2Transaction {
     fn from(l2_transaction: packed::L2Transaction) -> L2Transaction {
         Self {
             raw: l2_transaction.raw().into(),
-            signature: l2_transaction.signature().into(),
+            signature: JsonBytes::from_bytes(l2_transaction.signature().unpack()),
         }
     }
 }
@@ -95,14 +94,31 @@ impl From<packed::L2Transaction> for L2TransactionView {
 #[serde(rename_all = "snake_case")]
 pub struct LogItem {
     pub account_id: Uint32,
+    // gw_types::core::LogType
+    pub log_type: Uint32,
+    pub topics: Vec<H256>,
     pub data: JsonBytes,
 }
 
 impl From<LogItem> for packed::LogItem {
     fn from(json: LogItem) -> packed::LogItem {
-        let LogItem { account_id, data } = json;
+        let LogItem {
+            account_id,
+            log_type,
+            topics,
+            data,
+        } = json;
+        let topics: Vec<_> = topics
+            .into_iter()
+            .map(|topic| {
+                let topic: [u8; 32] = topic.into();
+                topic.pack()
+            })
+            .collect();
         packed::LogItem::new_builder()
             .account_id(account_id.value().pack())
+            .log_type(packed::Byte::new(log_type.value() as u8))
+            .topics(topics.pack())
             .data(data.into_bytes().pack())
             .build()
     }
@@ -111,9 +127,20 @@ impl From<LogItem> for packed::LogItem {
 impl From<packed::LogItem> for LogItem {
     fn from(data: packed::LogItem) -> LogItem {
         let account_id: u32 = data.account_id().unpack();
+        let log_type: u8 = data.log_type().into();
+        let topics: Vec<_> = data
+            .topics()
+            .into_iter()
+            .map(|topic| {
+                let topic: [u8; 32] = topic.unpack();
+                topic.into()
+            })
+            .collect();
         let data = JsonBytes::from_bytes(data.data().unpack());
         LogItem {
             account_id: Uint32::from(account_id),
+            log_type: Uint32::from(log_type as u32),
+            topics,
             data,
         }
     }
@@ -126,6 +153,7 @@ pub struct TxReceipt {
     pub compacted_post_account_root: H256,
     pub read_data_hashes: Vec<H256>,
     pub logs: Vec<LogItem>,
+    pub used_cycles: Uint64,
 }
 
 impl From<TxReceipt> for packed::TxReceipt {
@@ -135,6 +163,7 @@ impl From<TxReceipt> for packed::TxReceipt {
             compacted_post_account_root,
             read_data_hashes,
             logs,
+            used_cycles,
         } = json;
         let tx_witness_hash: [u8; 32] = tx_witness_hash.into();
         let compacted_post_account_root: [u8; 32] = compacted_post_account_root.into();
@@ -146,11 +175,13 @@ impl From<TxReceipt> for packed::TxReceipt {
             })
             .collect();
         let logs: Vec<packed::LogItem> = logs.into_iter().map(|item| item.into()).collect();
+        let used_cycles: u64 = used_cycles.into();
         packed::TxReceipt::new_builder()
             .tx_witness_hash(tx_witness_hash.pack())
             .compacted_post_account_root(compacted_post_account_root.pack())
             .read_data_hashes(read_data_hashes.pack())
             .logs(logs.pack())
+            .used_cycles(used_cycles.pack())
             .build()
     }
 }
@@ -168,11 +199,13 @@ impl From<packed::TxReceipt> for TxReceipt {
             })
             .collect();
         let logs: Vec<LogItem> = data.logs().into_iter().map(|item| item.into()).collect();
+        let used_cycles: u64 = data.used_cycles().unpack();
         TxReceipt {
             tx_witness_hash: tx_witness_hash.into(),
             compacted_post_account_root: compacted_post_account_root.into(),
             read_data_hashes,
             logs,
+            used_cycles: used_cycles.into(),
         }
     }
 }
@@ -765,7 +798,7 @@ impl From<packed::DepositionRequest> for DepositionRequest {
 #[serde(rename_all = "snake_case")]
 pub struct WithdrawalRequest {
     pub raw: RawWithdrawalRequest,
-    pub signature: Byte65,
+    pub signature: JsonBytes,
 }
 
 impl From<WithdrawalRequest> for packed::WithdrawalRequest {
@@ -773,7 +806,7 @@ impl From<WithdrawalRequest> for packed::WithdrawalRequest {
         let WithdrawalRequest { raw, signature } = json;
         packed::WithdrawalRequest::new_builder()
             .raw(raw.into())
-            .signature(signature.into())
+            .signature(signature.into_bytes().pack())
             .build()
     }
 }
@@ -782,7 +815,7 @@ impl From<packed::WithdrawalRequest> for WithdrawalRequest {
     fn from(withdrawal_request: packed::WithdrawalRequest) -> WithdrawalRequest {
         Self {
             raw: withdrawal_request.raw().into(),
-            signature: withdrawal_request.signature().into(),
+            signature: JsonBytes::from_bytes(withdrawal_request.signature().unpack()),
         }
     }
 }
@@ -1,5 +1,4 @@
 pub mod blockchain;
-pub mod fixed_bytes;
 pub mod godwoken;
 // re-exports
 pub use ckb_jsonrpc_types;
@@ -0,0 +1,112 @@
+use crate::policy::PolicyError;
+use gw_common::{error::Error as StateError, H256};
+use gw_db::error::Error as DBError;
+use gw_generator::error::{
+    AccountError, Error as GeneratorError, LockAlgorithmError, TransactionError,
+    TransactionValidateError, WithdrawalError,
+};
+use thiserror::Error;
+
+/// MemPool error
+#[derive(Error, Debug, PartialEq, Eq, Clone)]
+pub enum Error {
+    #[error("duplicated {hash:?}")]
+    Duplicated { hash: H256 },
+    #[error("over size, max bytes {max_bytes}, actual bytes {actual_bytes}")]
+    Oversize {
+        max_bytes: usize,
+        actual_bytes: usize,
+    },
+    #[error("signature over size, max bytes {max_bytes}, actual bytes {actual_bytes}")]
+    SignatureOversize {
+        max_bytes: usize,
+        actual_bytes: usize,
+    },
+    #[error("nonce too low, expected {expected}, actual {actual}")]
+    NonceTooLow { expected: u32, actual: u32 },
+    #[error("nonce too high, expected {expected}, actual {actual}")]
+    NonceTooHigh { expected: u32, actual: u32 },
+    #[error("nonce {nonce} is used by another entry")]
+    NonceConflict { nonce: u32 },
+    #[error("pool is full, max {max}")]
+    PoolFull { max: usize },
+    #[error("invalid signature")]
+    InvalidSignature,
+    #[error("insufficient balance")]
+    InsufficientBalance,
+    #[error("unknown account")]
+    UnknownAccount,
+    #[error("Policy error {0}")]
+    Policy(PolicyError),
+    #[error("Generator error {0}")]
+    Generator(GeneratorError),
+    #[error("Transaction error {0}")]
+    Transaction(TransactionValidateError),
+    #[error("State error {0:?}")]
+    State(StateError),
+    #[error("{0}")]
+    DB(DBError),
+}
+
+impl Error {
+    pub fn nonce(expected: u32, actual: u32) -> Self {
+        if actual < expected {
+            Error::NonceTooLow { expected, actual }
+        } else {
+            Error::NonceTooHigh { expected, actual }
+        }
+    }
+}
+
+impl From<PolicyError> for Error {
+    fn from(err: PolicyError) -> Self {
+        Error::Policy(err)
+    }
+}
+
+impl From<StateError> for Error {
+    fn from(err: StateError) -> Self {
+        Error::State(err)
+    }
+}
+
+impl From<DBError> for Error {
+    fn from(err: DBError) -> Self {
+        Error::DB(err)
+    }
+}
+
+impl From<GeneratorError> for Error {
+    fn from(err: GeneratorError) -> Self {
+        match err {
+            GeneratorError::Withdrawal(WithdrawalError::Overdraft) => Error::InsufficientBalance,
+            GeneratorError::Withdrawal(WithdrawalError::Nonce { expected, actual }) => {
+                Error::nonce(expected, actual)
+            }
+            GeneratorError::Unlock(LockAlgorithmError::InvalidSignature) => Error::InvalidSignature,
+            GeneratorError::Account(AccountError::UnknownAccount)
+            | GeneratorError::Account(AccountError::ScriptNotFound { .. }) => Error::UnknownAccount,
+            GeneratorError::State(err) => Error::State(err),
+            err => Error::Generator(err),
+        }
+    }
+}
+
+impl From<TransactionValidateError> for Error {
+    fn from(err: TransactionValidateError) -> Self {
+        match err {
+            TransactionValidateError::Transaction(TransactionError::Nonce { expected, actual }) => {
+                Error::nonce(expected, actual)
+            }
+            TransactionValidateError::Unlock(LockAlgorithmError::InvalidSignature) => {
+                Error::InvalidSignature
+            }
+            TransactionValidateError::Account(AccountError::UnknownAccount)
+            | TransactionValidateError::Account(AccountError::ScriptNotFound { .. }) => {
+                Error::UnknownAccount
+            }
+            TransactionValidateError::State(err) => Error::State(err),
+            err => Error::Transaction(err),
+        }
+    }
+}
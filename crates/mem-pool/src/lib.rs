@@ -3,4 +3,9 @@
 //! MemPool only do basic verification on l2transactions & withdrawal requests,
 //! the block producer need to verify the fully verification itself.
 
+pub mod error;
+pub mod policy;
 pub mod pool;
+pub mod status;
+
+pub use error::Error;
@@ -0,0 +1,272 @@
+//! Admission policies
+//!
+//! Policies are checked before the verification of txs & withdrawals,
+//! they prevent a single account from filling the whole pool.
+
+use gw_common::H256;
+use gw_config::MemPoolConfig;
+use gw_types::{packed::RollupConfig, prelude::Unpack};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    time::{Duration, Instant},
+};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq, Clone)]
+pub enum PolicyError {
+    #[error("account script code hash {code_hash:?} is not allowed")]
+    NotAllowed { code_hash: H256 },
+    #[error("account script code hash {code_hash:?} is denied")]
+    Denied { code_hash: H256 },
+    #[error("too many pending objects of account {account_id}, max: {max}")]
+    TooManyPending { account_id: u32, max: usize },
+    #[error("fee too low, min: {min} actual: {actual}")]
+    FeeTooLow { min: u128, actual: u128 },
+    #[error("account {account_id} exceeded the rate limit: {limit} per {window:?}")]
+    RateLimited {
+        account_id: u32,
+        limit: usize,
+        window: Duration,
+    },
+}
+
+pub struct AdmissionPolicy {
+    max_pending_per_account: usize,
+    min_fee: u128,
+    allowed_code_hashes: HashSet<H256>,
+    denied_code_hashes: HashSet<H256>,
+    rate_limit_per_account: usize,
+    rate_limit_window: Duration,
+    // account_id -> admission time of recently pushed objects
+    recent_admissions: HashMap<u32, VecDeque<Instant>>,
+}
+
+impl AdmissionPolicy {
+    pub fn new(config: &MemPoolConfig, rollup_config: &RollupConfig) -> Self {
+        let allowed_code_hashes: HashSet<H256> = if config.allowed_code_hashes.is_empty() {
+            rollup_config
+                .allowed_eoa_type_hashes()
+                .into_iter()
+                .map(|code_hash| code_hash.unpack())
+                .collect()
+        } else {
+            config
+                .allowed_code_hashes
+                .iter()
+                .map(|code_hash| (*code_hash).into())
+                .collect()
+        };
+        let denied_code_hashes = config
+            .denied_code_hashes
+            .iter()
+            .map(|code_hash| (*code_hash).into())
+            .collect();
+        AdmissionPolicy {
+            max_pending_per_account: config.max_pending_per_account,
+            min_fee: config.min_fee,
+            allowed_code_hashes,
+            denied_code_hashes,
+            rate_limit_per_account: config.rate_limit_per_account,
+            rate_limit_window: Duration::from_secs(config.rate_limit_window_secs),
+            recent_admissions: Default::default(),
+        }
+    }
+
+    /// Check the account script code hash against the allow & deny lists
+    pub fn check_code_hash(&self, code_hash: &H256) -> Result<(), PolicyError> {
+        if self.denied_code_hashes.contains(code_hash) {
+            return Err(PolicyError::Denied {
+                code_hash: *code_hash,
+            });
+        }
+        if !self.allowed_code_hashes.is_empty() && !self.allowed_code_hashes.contains(code_hash) {
+            return Err(PolicyError::NotAllowed {
+                code_hash: *code_hash,
+            });
+        }
+        Ok(())
+    }
+
+    /// Check the number of in pool objects of an account
+    pub fn check_pending_count(&self, account_id: u32, count: usize) -> Result<(), PolicyError> {
+        if self.max_pending_per_account > 0 && count >= self.max_pending_per_account {
+            return Err(PolicyError::TooManyPending {
+                account_id,
+                max: self.max_pending_per_account,
+            });
+        }
+        Ok(())
+    }
+
+    /// Check the fee paid to the block producer by a tx, denominated in CKB
+    pub fn check_fee(&self, fee: u128) -> Result<(), PolicyError> {
+        if fee < self.min_fee {
+            return Err(PolicyError::FeeTooLow {
+                min: self.min_fee,
+                actual: fee,
+            });
+        }
+        Ok(())
+    }
+
+    /// Check the account doesn't exceed the rate limit
+    pub fn check_rate_limit(&mut self, account_id: u32, now: Instant) -> Result<(), PolicyError> {
+        if self.rate_limit_per_account == 0 {
+            return Ok(());
+        }
+        let window = self.rate_limit_window;
+        let exceeded = match self.recent_admissions.get_mut(&account_id) {
+            Some(admissions) => {
+                // remove expired admissions
+                while let Some(admitted_at) = admissions.front() {
+                    if now.saturating_duration_since(*admitted_at) < window {
+                        break;
+                    }
+                    admissions.pop_front();
+                }
+                if admissions.is_empty() {
+                    self.recent_admissions.remove(&account_id);
+                    false
+                } else {
+                    admissions.len() >= self.rate_limit_per_account
+                }
+            }
+            None => false,
+        };
+        if exceeded {
+            return Err(PolicyError::RateLimited {
+                account_id,
+                limit: self.rate_limit_per_account,
+                window,
+            });
+        }
+        Ok(())
+    }
+
+    /// Record an admitted object of the account
+    pub fn record_admission(&mut self, account_id: u32, now: Instant) {
+        if self.rate_limit_per_account == 0 {
+            return;
+        }
+        self.recent_admissions
+            .entry(account_id)
+            .or_default()
+            .push_back(now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gw_types::{packed::Byte32, prelude::*};
+
+    fn rollup_config(allowed_eoa_type_hashes: &[[u8; 32]]) -> RollupConfig {
+        let allowed_eoa_type_hashes: Vec<Byte32> = allowed_eoa_type_hashes
+            .iter()
+            .map(|code_hash| code_hash.pack())
+            .collect();
+        RollupConfig::new_builder()
+            .allowed_eoa_type_hashes(allowed_eoa_type_hashes.pack())
+            .build()
+    }
+
+    #[test]
+    fn test_deny_list() {
+        let config = MemPoolConfig {
+            allowed_code_hashes: vec![[1u8; 32], [2u8; 32]],
+            denied_code_hashes: vec![[2u8; 32]],
+            ..Default::default()
+        };
+        let policy = AdmissionPolicy::new(&config, &RollupConfig::default());
+        policy.check_code_hash(&[1u8; 32].into()).expect("allowed");
+        // the deny list takes precedence over the allow list
+        assert_eq!(
+            policy.check_code_hash(&[2u8; 32].into()),
+            Err(PolicyError::Denied {
+                code_hash: [2u8; 32].into()
+            })
+        );
+
+        // all the other accounts are allowed without allow lists
+        let config = MemPoolConfig {
+            denied_code_hashes: vec![[2u8; 32]],
+            ..Default::default()
+        };
+        let policy = AdmissionPolicy::new(&config, &RollupConfig::default());
+        policy.check_code_hash(&[3u8; 32].into()).expect("allowed");
+        assert!(policy.check_code_hash(&[2u8; 32].into()).is_err());
+    }
+
+    #[test]
+    fn test_allow_list() {
+        // the allowed EOA types of the rollup are used if the allow list is empty
+        let rollup_config = rollup_config(&[[1u8; 32]]);
+        let policy = AdmissionPolicy::new(&MemPoolConfig::default(), &rollup_config);
+        policy.check_code_hash(&[1u8; 32].into()).expect("allowed");
+        assert_eq!(
+            policy.check_code_hash(&[2u8; 32].into()),
+            Err(PolicyError::NotAllowed {
+                code_hash: [2u8; 32].into()
+            })
+        );
+
+        // the allow list overrides the allowed EOA types
+        let config = MemPoolConfig {
+            allowed_code_hashes: vec![[2u8; 32]],
+            ..Default::default()
+        };
+        let policy = AdmissionPolicy::new(&config, &rollup_config);
+        policy.check_code_hash(&[2u8; 32].into()).expect("allowed");
+        assert!(policy.check_code_hash(&[1u8; 32].into()).is_err());
+    }
+
+    #[test]
+    fn test_max_pending() {
+        let config = MemPoolConfig {
+            max_pending_per_account: 2,
+            ..Default::default()
+        };
+        let policy = AdmissionPolicy::new(&config, &RollupConfig::default());
+        policy.check_pending_count(1, 1).expect("under limit");
+        assert_eq!(
+            policy.check_pending_count(1, 2),
+            Err(PolicyError::TooManyPending {
+                account_id: 1,
+                max: 2
+            })
+        );
+
+        // 0 means unlimited
+        let policy = AdmissionPolicy::new(&MemPoolConfig::default(), &RollupConfig::default());
+        policy.check_pending_count(1, 10_000).expect("unlimited");
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let config = MemPoolConfig {
+            rate_limit_per_account: 2,
+            rate_limit_window_secs: 10,
+            ..Default::default()
+        };
+        let mut policy = AdmissionPolicy::new(&config, &RollupConfig::default());
+        let now = Instant::now();
+        for _ in 0..2 {
+            policy.check_rate_limit(1, now).expect("under rate limit");
+            policy.record_admission(1, now);
+        }
+        let err = policy.check_rate_limit(1, now).unwrap_err();
+        assert_eq!(
+            err,
+            PolicyError::RateLimited {
+                account_id: 1,
+                limit: 2,
+                window: Duration::from_secs(10),
+            }
+        );
+        // other accounts are not affected
+        policy.check_rate_limit(2, now).expect("under rate limit");
+        // admissions expire after the window
+        let later = now + Duration::from_secs(10);
+        policy.check_rate_limit(1, later).expect("under rate limit");
+    }
+}
@@ -10,22 +10,37 @@
 //! We maintain a pending list which contains executable txs & withdrawals (executable means can be packaged into the next block),
 //! we also maintain a queue list which contains non-executable txs & withdrawals (these objects may become executable in the future).
 
+use crate::{
+    error::Error,
+    policy::AdmissionPolicy,
+    status::{DropReason, EntryStatus, StatusTracker},
+};
 use anyhow::{anyhow, Result};
-use gw_common::{builtins::CKB_SUDT_ACCOUNT_ID, state::State, H256};
-use gw_generator::{Generator, RunResult};
+use gw_common::{builtins::CKB_SUDT_ACCOUNT_ID, error::Error as StateError, state::State, H256};
+use gw_config::MemPoolConfig;
+use gw_generator::{
+    builtin_scripts::SUDT_VALIDATOR_CODE_HASH, sudt::build_l2_sudt_script, CallResult, Generator,
+    RunResult, TransactionEstimation,
+};
 use gw_store::{
     chain_view::ChainView,
     state_db::{StateDBTransaction, StateDBVersion},
     Store,
 };
+use gw_traits::CodeStore;
 use gw_types::{
-    packed::{BlockInfo, L2Transaction, WithdrawalRequest},
+    bytes::Bytes,
+    packed::{
+        self, BlockInfo, L2Block, L2Transaction, RawL2Transaction, RollupConfig, SUDTArgs,
+        SUDTArgsUnion, WithdrawalRequest,
+    },
     prelude::{Entity, Unpack},
+    MAX_SIGNATURE_SIZE,
 };
 use std::{
-    cmp::{max, min},
     collections::{HashMap, HashSet},
     sync::Arc,
+    time::Instant,
 };
 
 /// MAX mem pool txs
@@ -36,6 +51,20 @@ const MAX_IN_POOL_WITHDRAWAL: usize = 3000;
 const MAX_TX_SIZE: usize = 50_000;
 /// MAX withdrawal size
 const MAX_WITHDRAWAL_SIZE: usize = 50_000;
+/// MAX discarded blocks loaded at once to re-inject their contents
+const MAX_REINJECT_BLOCKS: usize = 100;
+
+/// The signature is variable-length bytes, reject the oversized one before verifying it
+fn check_signature_size(signature: &packed::Bytes) -> Result<(), Error> {
+    let signature_size = signature.len();
+    if signature_size > MAX_SIGNATURE_SIZE {
+        return Err(Error::SignatureOversize {
+            max_bytes: MAX_SIGNATURE_SIZE,
+            actual_bytes: signature_size,
+        });
+    }
+    Ok(())
+}
 
 #[derive(Default)]
 pub struct EntryList {
@@ -50,6 +79,79 @@ impl EntryList {
         self.txs.is_empty() && self.withdrawals.is_empty()
     }
 
+    fn len(&self) -> usize {
+        self.txs.len() + self.withdrawals.len()
+    }
+
+    fn contains_nonce(&self, nonce: u32) -> bool {
+        let is_tx_nonce = self.txs.iter().any(|tx| {
+            let tx_nonce: u32 = tx.raw().nonce().unpack();
+            tx_nonce == nonce
+        });
+        let is_withdrawal_nonce = self.withdrawals.iter().any(|withdrawal| {
+            let withdrawal_nonce: u32 = withdrawal.raw().nonce().unpack();
+            withdrawal_nonce == nonce
+        });
+        is_tx_nonce || is_withdrawal_nonce
+    }
+
+    // insert the tx by the nonce order
+    fn insert_tx(&mut self, tx: L2Transaction) {
+        let nonce: u32 = tx.raw().nonce().unpack();
+        let index = self
+            .txs
+            .iter()
+            .position(|tx| {
+                let tx_nonce: u32 = tx.raw().nonce().unpack();
+                tx_nonce > nonce
+            })
+            .unwrap_or_else(|| self.txs.len());
+        self.txs.insert(index, tx);
+    }
+
+    // insert the withdrawal by the nonce order
+    fn insert_withdrawal(&mut self, withdrawal: WithdrawalRequest) {
+        let nonce: u32 = withdrawal.raw().nonce().unpack();
+        let index = self
+            .withdrawals
+            .iter()
+            .position(|withdrawal| {
+                let withdrawal_nonce: u32 = withdrawal.raw().nonce().unpack();
+                withdrawal_nonce > nonce
+            })
+            .unwrap_or_else(|| self.withdrawals.len());
+        self.withdrawals.insert(index, withdrawal);
+    }
+
+    // return the entries which can be packaged in order from the account's nonce,
+    // the withdrawals are packaged before the txs, so the entries stop at
+    // a nonce gap or a withdrawal following a tx
+    fn packageable(&self, nonce: u32) -> (&[L2Transaction], &[WithdrawalRequest]) {
+        let mut next_nonce = nonce;
+        let mut withdrawals_count = 0;
+        for withdrawal in &self.withdrawals {
+            let withdrawal_nonce: u32 = withdrawal.raw().nonce().unpack();
+            if withdrawal_nonce != next_nonce {
+                break;
+            }
+            withdrawals_count += 1;
+            next_nonce += 1;
+        }
+        let mut txs_count = 0;
+        for tx in &self.txs {
+            let tx_nonce: u32 = tx.raw().nonce().unpack();
+            if tx_nonce != next_nonce {
+                break;
+            }
+            txs_count += 1;
+            next_nonce += 1;
+        }
+        (
+            &self.txs[..txs_count],
+            &self.withdrawals[..withdrawals_count],
+        )
+    }
+
     // remove and return txs which tx.nonce is lower than nonce
     fn remove_lower_nonce_txs(&mut self, nonce: u32) -> Vec<L2Transaction> {
         let mut removed = Vec::default();
@@ -63,15 +165,9 @@ impl EntryList {
         removed
     }
 
-    // remove and return withdrawals which withdrawal.nonce is lower than nonce & have not enough balance
-    fn remove_lower_nonce_balance_withdrawals(
-        &mut self,
-        nonce: u32,
-        capacity: u128,
-    ) -> Vec<WithdrawalRequest> {
+    // remove and return withdrawals which withdrawal.nonce is lower than nonce
+    fn remove_lower_nonce_withdrawals(&mut self, nonce: u32) -> Vec<WithdrawalRequest> {
         let mut removed = Vec::default();
-
-        // remove lower nonce withdrawals
         while !self.withdrawals.is_empty() {
             let withdrawal_nonce: u32 = self.withdrawals[0].raw().nonce().unpack();
             if withdrawal_nonce >= nonce {
@@ -79,19 +175,221 @@ impl EntryList {
             }
             removed.push(self.withdrawals.remove(0));
         }
+        removed
+    }
 
-        // remove lower balance withdrawals
-        if let Some(withdrawal) = self.withdrawals.get(0).clone() {
-            let withdrawal_capacity: u64 = withdrawal.raw().capacity().unpack();
-            if (withdrawal_capacity as u128) > capacity {
-                // TODO instead of remove all withdrawals, put them into future queue
-                removed.extend_from_slice(&self.withdrawals);
-                self.withdrawals.clear();
+    // remove and return the tx which hash is tx_hash
+    fn remove_tx(&mut self, tx_hash: &H256) -> Option<L2Transaction> {
+        let index = self
+            .txs
+            .iter()
+            .position(|tx| &H256::from(tx.hash()) == tx_hash)?;
+        Some(self.txs.remove(index))
+    }
+
+    // remove and return the withdrawal which hash is withdrawal_hash
+    fn remove_withdrawal(&mut self, withdrawal_hash: &H256) -> Option<WithdrawalRequest> {
+        let index = self
+            .withdrawals
+            .iter()
+            .position(|withdrawal| &H256::from(withdrawal.hash()) == withdrawal_hash)?;
+        Some(self.withdrawals.remove(index))
+    }
+}
+
+/// Balances reserved by the in pool txs & withdrawals of an account
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct ReservedBalance {
+    // sudt_id -> reserved amount
+    amounts: HashMap<u32, u128>,
+}
+
+impl ReservedBalance {
+    /// Return the reserved amount of a sUDT, CKB is reserved under `CKB_SUDT_ACCOUNT_ID`
+    pub fn get(&self, sudt_id: u32) -> u128 {
+        self.amounts.get(&sudt_id).cloned().unwrap_or(0)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.amounts.values().all(|amount| *amount == 0)
+    }
+
+    fn reserve(&mut self, costs: &[(u32, u128)]) {
+        for (sudt_id, amount) in costs {
+            let reserved = self.amounts.entry(*sudt_id).or_default();
+            *reserved = reserved.saturating_add(*amount);
+        }
+    }
+
+    // check the account can pay the costs besides the reserved balances
+    fn is_affordable<S: State>(
+        &self,
+        state: &S,
+        account_id: u32,
+        costs: &[(u32, u128)],
+    ) -> Result<bool, StateError> {
+        for (sudt_id, amount) in costs {
+            let balance = state.get_sudt_balance(*sudt_id, account_id)?;
+            let required = match self.get(*sudt_id).checked_add(*amount) {
+                Some(required) => required,
+                None => return Ok(false),
+            };
+            if required > balance {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+// return (sudt_id, amount) pairs that the withdrawal burns from the account
+fn withdrawal_costs<S: State>(
+    state: &S,
+    withdrawal: &WithdrawalRequest,
+) -> Result<Vec<(u32, u128)>, StateError> {
+    let raw = withdrawal.raw();
+    let capacity: u64 = raw.capacity().unpack();
+    let amount: u128 = raw.amount().unpack();
+    let mut costs = vec![(CKB_SUDT_ACCOUNT_ID, capacity as u128)];
+    if amount > 0 {
+        let l2_sudt_script_hash = build_l2_sudt_script(raw.sudt_script_hash().unpack()).hash();
+        if let Some(sudt_id) = state.get_account_id_by_script_hash(&l2_sudt_script_hash.into())? {
+            if sudt_id != CKB_SUDT_ACCOUNT_ID {
+                costs.push((sudt_id, amount));
             }
         }
+    }
+    Ok(costs)
+}
 
-        removed
+// parse the transfer of the builtin sUDT contract, return (sudt_id, amount, fee)
+fn parse_sudt_transfer<S: State + CodeStore>(
+    state: &S,
+    tx: &L2Transaction,
+) -> Result<Option<(u32, u128, u128)>, StateError> {
+    let raw_tx = tx.raw();
+    let to_id: u32 = raw_tx.to_id().unpack();
+    let script_hash = state.get_script_hash(to_id)?;
+    let is_sudt = state
+        .get_script(&script_hash)
+        .map(|script| {
+            let code_hash: [u8; 32] = script.code_hash().unpack();
+            H256::from(code_hash) == *SUDT_VALIDATOR_CODE_HASH
+        })
+        .unwrap_or(false);
+    if !is_sudt {
+        return Ok(None);
     }
+    let args: Bytes = raw_tx.args().unpack();
+    let transfer = match SUDTArgs::from_slice(&args).map(|args| args.to_enum()) {
+        Ok(SUDTArgsUnion::SUDTTransfer(transfer)) => {
+            let amount: u128 = transfer.amount().unpack();
+            let fee: u128 = transfer.fee().unpack();
+            Some((to_id, amount, fee))
+        }
+        // queries and invalid args transfer nothing, the execution of the latter will fail anyway
+        _ => None,
+    };
+    Ok(transfer)
+}
+
+// return (sudt_id, amount) pairs that the tx transfers out from the sender,
+// only the transfers of the builtin sUDT contract can be recognized
+fn tx_costs<S: State + CodeStore>(
+    state: &S,
+    tx: &L2Transaction,
+) -> Result<Vec<(u32, u128)>, StateError> {
+    let costs = parse_sudt_transfer(state, tx)?
+        .map(|(sudt_id, amount, fee)| vec![(sudt_id, amount.saturating_add(fee))])
+        .unwrap_or_default();
+    Ok(costs)
+}
+
+// reserve balances for the entries of an account in the packaging order,
+// withdrawals are packaged before txs so they take precedence.
+// return the unaffordable withdrawals, which are removed from the entry list
+fn reserve_entries<S: State + CodeStore>(
+    state: &S,
+    account_id: u32,
+    list: &mut EntryList,
+) -> Result<(ReservedBalance, Vec<WithdrawalRequest>), StateError> {
+    let mut reserved = ReservedBalance::default();
+    let mut unaffordable = Vec::default();
+    let mut affordable = Vec::with_capacity(list.withdrawals.len());
+    for withdrawal in list.withdrawals.drain(..) {
+        // keep the nonce order, once a withdrawal is unaffordable all the following ones are moved out
+        if !unaffordable.is_empty() {
+            unaffordable.push(withdrawal);
+            continue;
+        }
+        let costs = withdrawal_costs(state, &withdrawal)?;
+        if reserved.is_affordable(state, account_id, &costs)? {
+            reserved.reserve(&costs);
+            affordable.push(withdrawal);
+        } else {
+            unaffordable.push(withdrawal);
+        }
+    }
+    list.withdrawals = affordable;
+    for tx in &list.txs {
+        reserved.reserve(&tx_costs(state, tx)?);
+    }
+    Ok((reserved, unaffordable))
+}
+
+/// Blocks reverted & attached by a reorg
+#[derive(Default)]
+struct Reorg {
+    // hashes of the discarded blocks, in the chain order
+    discarded_blocks: Vec<H256>,
+    // hashes of contents of the included blocks
+    included_txs: HashSet<H256>,
+    included_withdrawals: HashSet<H256>,
+}
+
+impl Reorg {
+    fn include_block(&mut self, block: &L2Block) {
+        self.included_txs.extend(
+            block
+                .transactions()
+                .into_iter()
+                .map(|tx| H256::from(tx.hash())),
+        );
+        self.included_withdrawals.extend(
+            block
+                .withdrawals()
+                .into_iter()
+                .map(|withdrawal| H256::from(withdrawal.hash())),
+        );
+    }
+
+    /// Return the withdrawals & txs of a discarded block which are not included by the new branch
+    fn reinjects(&self, block: &L2Block) -> (Vec<WithdrawalRequest>, Vec<L2Transaction>) {
+        let withdrawals = block
+            .withdrawals()
+            .into_iter()
+            .filter(|withdrawal| {
+                !self
+                    .included_withdrawals
+                    .contains(&H256::from(withdrawal.hash()))
+            })
+            .collect();
+        let txs = block
+            .transactions()
+            .into_iter()
+            .filter(|tx| !self.included_txs.contains(&H256::from(tx.hash())))
+            .collect();
+        (withdrawals, txs)
+    }
+}
+
+/// Txs & withdrawals which are reinjected or dropped by a reset of the pool
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct ResetReport {
+    pub reinjected_txs: Vec<H256>,
+    pub reinjected_withdrawals: Vec<H256>,
+    pub dropped_txs: Vec<(H256, Error)>,
+    pub dropped_withdrawals: Vec<(H256, Error)>,
 }
 
 pub struct MemPool {
@@ -106,17 +404,34 @@ pub struct MemPool {
     generator: Arc<Generator>,
     /// pending queue, contains executable contents(can be pacakged into block)
     pending: HashMap<u32, EntryList>,
+    /// future queue, contains withdrawals which can't be afforded by the account's balance for now
+    queue: HashMap<u32, EntryList>,
+    /// balances reserved by the pending contents of each account
+    reserved: HashMap<u32, ReservedBalance>,
+    /// admission policies
+    policy: AdmissionPolicy,
     /// all transactions in the pool
     all_txs: HashMap<H256, L2Transaction>,
     /// all withdrawals in the pool
     all_withdrawals: HashMap<H256, WithdrawalRequest>,
+    /// lifecycle status of txs & withdrawals
+    status_tracker: StatusTracker,
 }
 
 impl MemPool {
-    pub fn create(db: Store, generator: Arc<Generator>) -> Result<Self> {
+    pub fn create(
+        db: Store,
+        generator: Arc<Generator>,
+        config: &MemPoolConfig,
+        rollup_config: &RollupConfig,
+    ) -> Result<Self> {
         let pending = Default::default();
+        let queue = Default::default();
+        let reserved = Default::default();
+        let policy = AdmissionPolicy::new(config, rollup_config);
         let all_txs = Default::default();
         let all_withdrawals = Default::default();
+        let status_tracker = Default::default();
 
         let tip = db.get_tip_block_hash()?;
 
@@ -128,8 +443,12 @@ impl MemPool {
             current_tip: None,
             generator,
             pending,
+            queue,
+            reserved,
+            policy,
             all_txs,
             all_withdrawals,
+            status_tracker,
         };
 
         // set tip
@@ -142,60 +461,159 @@ impl MemPool {
     }
 
     /// Push a layer2 tx into pool
-    pub fn push_transaction(&mut self, tx: L2Transaction) -> Result<()> {
+    pub fn push_transaction(&mut self, tx: L2Transaction) -> Result<(), Error> {
+        let account_id: u32 = tx.raw().from_id().unpack();
+        let now = Instant::now();
+        self.policy.check_rate_limit(account_id, now)?;
+        self.add_transaction(tx, false)?;
+        self.policy.record_admission(account_id, now);
+        Ok(())
+    }
+
+    /// Add a layer2 tx into pool
+    /// the rate limit is not checked here, since the tx may be a re-injected one.
+    /// `signature_verified` indicates the signature is already verified by the caller
+    fn add_transaction(
+        &mut self,
+        tx: L2Transaction,
+        signature_verified: bool,
+    ) -> Result<(), Error> {
         // check duplication
         let tx_hash: H256 = tx.raw().hash().into();
         if self.all_txs.contains_key(&tx_hash) {
-            return Err(anyhow!("duplicated tx"));
+            return Err(Error::Duplicated { hash: tx_hash });
         }
 
+        // check admission policies
+        self.check_tx_policy(&tx)?;
+
         // basic verification
-        self.basic_verify_tx(&tx)?;
+        self.basic_verify_tx(&tx, signature_verified)?;
 
         // remove under price tx if pool is full
         if self.all_txs.len() >= MAX_IN_POOL_TXS {
             //TODO
-            return Err(anyhow!(
-                "Too many txs in the pool! MAX_IN_POOL_TXS: {}",
-                MAX_IN_POOL_TXS
-            ));
+            return Err(Error::PoolFull {
+                max: MAX_IN_POOL_TXS,
+            });
         }
         // Check replace-by-fee
         // TODO
 
+        // Check balance, the sender must be able to afford all the in pool objects
+        let state = self.state_db.account_state_tree()?;
+        let account_id: u32 = tx.raw().from_id().unpack();
+        let costs = tx_costs(&state, &tx)?;
+        let reserved = self.reserved.get(&account_id).cloned().unwrap_or_default();
+        if !reserved.is_affordable(&state, account_id, &costs)? {
+            return Err(Error::InsufficientBalance);
+        }
+
         // Add to pool
-        // TODO check nonce conflict
+        self.reserved.entry(account_id).or_default().reserve(&costs);
         self.all_txs.insert(tx_hash, tx.clone());
-        let account_id: u32 = tx.raw().from_id().unpack();
+        self.status_tracker
+            .set_tx_status(tx_hash, EntryStatus::Pending);
         let entry_list = self.pending.entry(account_id).or_default();
-        entry_list.txs.push(tx);
+        entry_list.insert_tx(tx);
+        Ok(())
+    }
+
+    /// Check admission policies for tx
+    fn check_tx_policy(&self, tx: &L2Transaction) -> Result<(), Error> {
+        let state = self.state_db.account_state_tree()?;
+        let account_id: u32 = tx.raw().from_id().unpack();
+        // check sender's script, unknown sender is rejected by the basic verification
+        let script_hash = state.get_script_hash(account_id)?;
+        if let Some(script) = state.get_script(&script_hash) {
+            let code_hash: [u8; 32] = script.code_hash().unpack();
+            self.policy.check_code_hash(&code_hash.into())?;
+        }
+        self.policy
+            .check_pending_count(account_id, self.entries_count(account_id))?;
+        // the state transition charges no fee of the consumed resources,
+        // the block producer only receives the fees of the CKB transfers,
+        // other txs pay nothing
+        let fee = match parse_sudt_transfer(&state, tx)? {
+            Some((CKB_SUDT_ACCOUNT_ID, _amount, fee)) => fee,
+            _ => 0,
+        };
+        self.policy.check_fee(fee)?;
+        Ok(())
+    }
+
+    /// Check admission policies for withdrawal
+    fn check_withdrawal_policy(&self, withdrawal: &WithdrawalRequest) -> Result<(), Error> {
+        let state = self.state_db.account_state_tree()?;
+        let account_script_hash: H256 = withdrawal.raw().account_script_hash().unpack();
+        // unknown account is rejected by the basic verification
+        if let Some(script) = state.get_script(&account_script_hash) {
+            let code_hash: [u8; 32] = script.code_hash().unpack();
+            self.policy.check_code_hash(&code_hash.into())?;
+        }
+        if let Some(account_id) = state.get_account_id_by_script_hash(&account_script_hash)? {
+            self.policy
+                .check_pending_count(account_id, self.entries_count(account_id))?;
+        }
+        Ok(())
+    }
+
+    /// Return the count of in pool txs & withdrawals of an account
+    fn entries_count(&self, account_id: u32) -> usize {
+        let pending_count = self.pending.get(&account_id).map(EntryList::len);
+        let queued_count = self.queue.get(&account_id).map(EntryList::len);
+        pending_count.unwrap_or(0) + queued_count.unwrap_or(0)
+    }
+
+    /// Check the nonce of a new tx or withdrawal,
+    /// the nonce must be unused by the in pool entries of the account
+    /// and follow them from the account's nonce, so the entries can be packaged in order.
+    /// The nonces of the removed entries can be used again
+    fn check_nonce<S: State>(&self, state: &S, account_id: u32, nonce: u32) -> Result<(), Error> {
+        let account_nonce = state.get_nonce(account_id)?;
+        if nonce < account_nonce {
+            return Err(Error::NonceTooLow {
+                expected: account_nonce,
+                actual: nonce,
+            });
+        }
+        let is_used = [&self.pending, &self.queue]
+            .iter()
+            .filter_map(|lists| lists.get(&account_id))
+            .any(|list| list.contains_nonce(nonce));
+        if is_used {
+            return Err(Error::NonceConflict { nonce });
+        }
+        let max_nonce = account_nonce.saturating_add(self.entries_count(account_id) as u32);
+        if nonce > max_nonce {
+            return Err(Error::NonceTooHigh {
+                expected: max_nonce,
+                actual: nonce,
+            });
+        }
         Ok(())
     }
 
     /// Basic verification for tx
-    fn basic_verify_tx(&self, tx: &L2Transaction) -> Result<()> {
+    fn basic_verify_tx(&self, tx: &L2Transaction, signature_verified: bool) -> Result<(), Error> {
         // check tx size
-        if tx.as_slice().len() > MAX_TX_SIZE {
-            return Err(anyhow!("tx over size"));
+        let tx_size = tx.as_slice().len();
+        if tx_size > MAX_TX_SIZE {
+            return Err(Error::Oversize {
+                max_bytes: MAX_TX_SIZE,
+                actual_bytes: tx_size,
+            });
         }
+        check_signature_size(&tx.signature())?;
 
-        // TODO
-        // we should introduce queue manchanism and only remove tx when tx.nonce is lower
-        // reject tx if nonce is not equals account.nonce
         let state = self.state_db.account_state_tree()?;
         let account_id: u32 = tx.raw().from_id().unpack();
-        let nonce = state.get_nonce(account_id)?;
-        let tx_nonce: u32 = tx.raw().nonce().unpack();
-        if nonce != tx_nonce {
-            return Err(anyhow!(
-                "tx's nonce is incorrect, expected: {} got: {}",
-                nonce,
-                tx_nonce,
-            ));
-        }
+        self.check_nonce(&state, account_id, tx.raw().nonce().unpack())?;
 
         // verify signature
-        self.generator.check_transaction_signature(&state, &tx)?;
+        if !signature_verified {
+            self.generator.check_transaction_signature(&state, &tx)?;
+        }
 
         Ok(())
     }
@@ -221,49 +639,125 @@ impl MemPool {
         Ok(run_result)
     }
 
+    /// Execute a raw tx in the read-only mode on the state of the pool,
+    /// the tx is neither signed nor pushed into pool
+    pub fn call(&self, raw_tx: &RawL2Transaction, block_info: &BlockInfo) -> Result<CallResult> {
+        let state = self.state_db.account_state_tree()?;
+        let tip_block_hash = self.db.get_tip_block_hash()?;
+        let chain_view = ChainView::new(self.db.begin_transaction(), tip_block_hash);
+        let call_result = self
+            .generator
+            .call(&chain_view, &state, block_info, raw_tx)?;
+        Ok(call_result)
+    }
+
+    /// Estimate the cost of a raw tx on the state of the pool
+    pub fn estimate_transaction(
+        &self,
+        raw_tx: &RawL2Transaction,
+        block_info: &BlockInfo,
+    ) -> Result<TransactionEstimation> {
+        let state = self.state_db.account_state_tree()?;
+        let tip_block_hash = self.db.get_tip_block_hash()?;
+        let chain_view = ChainView::new(self.db.begin_transaction(), tip_block_hash);
+        let estimation =
+            self.generator
+                .estimate_transaction(&chain_view, &state, block_info, raw_tx)?;
+        Ok(estimation)
+    }
+
     /// Push a withdrawal request into pool
-    pub fn push_withdrawal_request(&mut self, withdrawal: WithdrawalRequest) -> Result<()> {
+    pub fn push_withdrawal_request(&mut self, withdrawal: WithdrawalRequest) -> Result<(), Error> {
+        let account_id = {
+            let state = self.state_db.account_state_tree()?;
+            let account_script_hash: H256 = withdrawal.raw().account_script_hash().unpack();
+            state
+                .get_account_id_by_script_hash(&account_script_hash)?
+                .ok_or(Error::UnknownAccount)?
+        };
+        let now = Instant::now();
+        self.policy.check_rate_limit(account_id, now)?;
+        self.add_withdrawal_request(withdrawal, false)?;
+        self.policy.record_admission(account_id, now);
+        Ok(())
+    }
+
+    /// Add a withdrawal request into pool
+    /// the rate limit is not checked here, since the withdrawal may be a re-injected one.
+    /// `signature_verified` indicates the signature is already verified by the caller
+    fn add_withdrawal_request(
+        &mut self,
+        withdrawal: WithdrawalRequest,
+        signature_verified: bool,
+    ) -> Result<(), Error> {
         // check withdrawal size
-        if withdrawal.as_slice().len() > MAX_WITHDRAWAL_SIZE {
-            return Err(anyhow!("withdrawal over size"));
+        let withdrawal_size = withdrawal.as_slice().len();
+        if withdrawal_size > MAX_WITHDRAWAL_SIZE {
+            return Err(Error::Oversize {
+                max_bytes: MAX_WITHDRAWAL_SIZE,
+                actual_bytes: withdrawal_size,
+            });
         }
+        check_signature_size(&withdrawal.signature())?;
 
         // check duplication
         let withdrawal_hash: H256 = withdrawal.raw().hash().into();
         if self.all_withdrawals.contains_key(&withdrawal_hash) {
-            return Err(anyhow!("duplicated withdrawal"));
+            return Err(Error::Duplicated {
+                hash: withdrawal_hash,
+            });
         }
 
+        // check admission policies
+        self.check_withdrawal_policy(&withdrawal)?;
+
         // basic verification
-        self.verify_withdrawal_request(&withdrawal)?;
+        let state = self.state_db.account_state_tree()?;
+        if !signature_verified {
+            self.generator
+                .check_withdrawal_request_signature(&state, &withdrawal)?;
+        }
+        self.generator
+            .verify_withdrawal_request_except_nonce(&state, &withdrawal)?;
+        let account_script_hash: H256 = withdrawal.raw().account_script_hash().unpack();
+        let account_id = state
+            .get_account_id_by_script_hash(&account_script_hash)?
+            .ok_or(Error::UnknownAccount)?;
+        self.check_nonce(&state, account_id, withdrawal.raw().nonce().unpack())?;
 
         // remove under price tx if pool is full
         if self.all_withdrawals.len() >= MAX_IN_POOL_WITHDRAWAL {
             //TODO
-            return Err(anyhow!(
-                "Too many withdrawals in the pool! MAX_IN_POOL_WITHDRAWALS: {}",
-                MAX_IN_POOL_WITHDRAWAL
-            ));
+            return Err(Error::PoolFull {
+                max: MAX_IN_POOL_WITHDRAWAL,
+            });
         }
         // Check replace-by-fee
         // TODO
 
+        // Check balance, the account must be able to afford all the in pool objects
+        let costs = withdrawal_costs(&state, &withdrawal)?;
+        let reserved = self.reserved.get(&account_id).cloned().unwrap_or_default();
+        if !reserved.is_affordable(&state, account_id, &costs)? {
+            return Err(Error::InsufficientBalance);
+        }
+
         // Add to pool
-        // TODO check nonce conflict
-        let state = self.state_db.account_state_tree()?;
+        self.reserved.entry(account_id).or_default().reserve(&costs);
         self.all_withdrawals
             .insert(withdrawal_hash, withdrawal.clone());
-        let account_script_hash: H256 = withdrawal.raw().account_script_hash().unpack();
-        let account_id = state
-            .get_account_id_by_script_hash(&account_script_hash)?
-            .expect("get account_id");
+        self.status_tracker
+            .set_withdrawal_status(withdrawal_hash, EntryStatus::Pending);
         let entry_list = self.pending.entry(account_id).or_default();
-        entry_list.withdrawals.push(withdrawal);
+        entry_list.insert_withdrawal(withdrawal);
         Ok(())
     }
 
     /// Verify withdrawal request without push it into pool
-    pub fn verify_withdrawal_request(&self, withdrawal_request: &WithdrawalRequest) -> Result<()> {
+    pub fn verify_withdrawal_request(
+        &self,
+        withdrawal_request: &WithdrawalRequest,
+    ) -> Result<(), Error> {
         let state = self.state_db.account_state_tree()?;
         // verify withdrawal signature
         self.generator
@@ -274,31 +768,155 @@ impl MemPool {
             .map_err(Into::into)
     }
 
+    /// Return the pending txs & withdrawals to package into the next block,
+    /// the ones which the block producer can't package should be reported by `discard_unused`
+    pub fn package(&self) -> Result<(Vec<L2Transaction>, Vec<WithdrawalRequest>)> {
+        let state = self.state_db.account_state_tree()?;
+        let mut txs = Vec::new();
+        let mut withdrawals = Vec::new();
+        for (&account_id, entry) in &self.pending {
+            // the entries of an account are packaged in the nonce order
+            let nonce = state.get_nonce(account_id)?;
+            let (packageable_txs, packageable_withdrawals) = entry.packageable(nonce);
+            txs.extend(packageable_txs.iter().cloned());
+            withdrawals.extend(packageable_withdrawals.iter().cloned());
+        }
+        Ok((txs, withdrawals))
+    }
+
     /// Return pending contents
     pub fn pending(&self) -> &HashMap<u32, EntryList> {
         &self.pending
     }
 
+    /// Return future queue contents
+    pub fn queue(&self) -> &HashMap<u32, EntryList> {
+        &self.queue
+    }
+
+    /// Return balances reserved by the pending contents of an account
+    pub fn reserved_balance(&self, account_id: u32) -> Option<&ReservedBalance> {
+        self.reserved.get(&account_id)
+    }
+
+    /// Return the status tracker
+    pub fn status_tracker(&self) -> &StatusTracker {
+        &self.status_tracker
+    }
+
+    /// Return the mutable status tracker, the chain uses it to track committed blocks
+    pub fn status_tracker_mut(&mut self) -> &mut StatusTracker {
+        &mut self.status_tracker
+    }
+
+    /// Return the status of a tx
+    pub fn tx_status(&self, tx_hash: &H256) -> Option<&EntryStatus> {
+        self.status_tracker.tx_status(tx_hash)
+    }
+
+    /// Return the status of a withdrawal
+    pub fn withdrawal_status(&self, withdrawal_hash: &H256) -> Option<&EntryStatus> {
+        self.status_tracker.withdrawal_status(withdrawal_hash)
+    }
+
+    /// Discard the txs & withdrawals which the block producer can't package into a block
+    pub fn discard_unused(
+        &mut self,
+        unused_txs: &[L2Transaction],
+        unused_withdrawals: &[WithdrawalRequest],
+    ) -> Result<()> {
+        for tx in unused_txs {
+            let tx_hash: H256 = tx.hash().into();
+            if self.all_txs.remove(&tx_hash).is_none() {
+                continue;
+            }
+            let account_id: u32 = tx.raw().from_id().unpack();
+            for lists in &mut [&mut self.pending, &mut self.queue] {
+                if let Some(list) = lists.get_mut(&account_id) {
+                    list.remove_tx(&tx_hash);
+                }
+            }
+            self.status_tracker
+                .set_tx_status(tx_hash, EntryStatus::Dropped(DropReason::Unused));
+        }
+        for withdrawal in unused_withdrawals {
+            let withdrawal_hash: H256 = withdrawal.hash().into();
+            if self.all_withdrawals.remove(&withdrawal_hash).is_none() {
+                continue;
+            }
+            for lists in &mut [&mut self.pending, &mut self.queue] {
+                for list in lists.values_mut() {
+                    list.remove_withdrawal(&withdrawal_hash);
+                }
+            }
+            self.status_tracker
+                .set_withdrawal_status(withdrawal_hash, EntryStatus::Dropped(DropReason::Unused));
+        }
+        // re-calculate reserved balances and remove empty entries
+        self.demote_unexecutables()?;
+        self.promote_executables()?;
+        Ok(())
+    }
+
     /// Notify new tip
     /// this method update current state of mem pool
-    pub fn notify_new_tip(&mut self, new_tip: H256) -> Result<()> {
+    /// return the txs & withdrawals which are reinjected or dropped by the reset
+    pub fn notify_new_tip(&mut self, new_tip: H256) -> Result<ResetReport> {
         // reset pool state
-        self.reset(self.current_tip, Some(new_tip))?;
+        let report = self.reset(self.current_tip, Some(new_tip))?;
         self.current_tip = Some(new_tip);
-        // try promote executables
-        self.promote_executables(self.pending.iter())?;
         // try demote unexecutables, this function also discards objects that already in the chain
         self.demote_unexecutables()?;
-        Ok(())
+        // try promote executables, run after the demotion so the reserved balances are up to date
+        self.promote_executables()?;
+        Ok(report)
     }
 
-    /// Move executables into pending.
-    /// TODO
-    fn promote_executables<I: Iterator>(&self, _accounts: I) -> Result<()> {
+    /// Move executables from future queue into pending.
+    fn promote_executables(&mut self) -> Result<()> {
+        let state = self.state_db.account_state_tree()?;
+        let mut remove_list = Vec::default();
+        for (&account_id, queued_list) in &mut self.queue {
+            let nonce = state.get_nonce(account_id)?;
+            // drop withdrawals if withdrawal.nonce lower than nonce
+            let deprecated_withdrawals = queued_list.remove_lower_nonce_withdrawals(nonce);
+            for withdrawal in deprecated_withdrawals {
+                let withdrawal_hash: H256 = withdrawal.hash().into();
+                self.all_withdrawals.remove(&withdrawal_hash);
+                self.status_tracker.drop_stale_withdrawal(withdrawal_hash);
+            }
+            // move affordable withdrawals in order
+            let mut reserved = self.reserved.get(&account_id).cloned().unwrap_or_default();
+            while !queued_list.withdrawals.is_empty() {
+                let costs = withdrawal_costs(&state, &queued_list.withdrawals[0])?;
+                if !reserved.is_affordable(&state, account_id, &costs)? {
+                    break;
+                }
+                reserved.reserve(&costs);
+                let withdrawal = queued_list.withdrawals.remove(0);
+                self.status_tracker
+                    .set_withdrawal_status(withdrawal.hash().into(), EntryStatus::Pending);
+                self.pending
+                    .entry(account_id)
+                    .or_default()
+                    .insert_withdrawal(withdrawal);
+            }
+            if !reserved.is_empty() {
+                self.reserved.insert(account_id, reserved);
+            }
+            // Delete empty entry
+            if queued_list.is_empty() {
+                remove_list.push(account_id);
+            }
+        }
+        for account_id in remove_list {
+            self.queue.remove(&account_id);
+        }
         Ok(())
     }
 
     /// Discard unexecutables from pending.
+    /// Withdrawals that have no enough balance are moved into future queue.
     fn demote_unexecutables(&mut self) -> Result<()> {
         let state = self.state_db.account_state_tree()?;
         let mut remove_list = Vec::default();
@@ -311,14 +929,32 @@ impl MemPool {
             for tx in deprecated_txs {
                 let tx_hash = tx.hash().into();
                 self.all_txs.remove(&tx_hash);
+                self.status_tracker.drop_stale_tx(tx_hash);
             }
-            // Drop all withdrawals that are have no enough balance
-            let capacity = state.get_sudt_balance(CKB_SUDT_ACCOUNT_ID, account_id)?;
-            let deprecated_withdrawals =
-                list.remove_lower_nonce_balance_withdrawals(nonce, capacity);
+            // drop withdrawals if withdrawal.nonce lower than nonce
+            let deprecated_withdrawals = list.remove_lower_nonce_withdrawals(nonce);
             for withdrawal in deprecated_withdrawals {
                 let withdrawal_hash: H256 = withdrawal.hash().into();
                 self.all_withdrawals.remove(&withdrawal_hash);
+                self.status_tracker.drop_stale_withdrawal(withdrawal_hash);
+            }
+            // re-calculate reserved balances on the current state,
+            // move withdrawals that have no enough balance into future queue
+            let (reserved, unaffordable_withdrawals) = reserve_entries(&state, account_id, list)?;
+            for withdrawal in &unaffordable_withdrawals {
+                self.status_tracker
+                    .set_withdrawal_status(withdrawal.hash().into(), EntryStatus::Queued);
+            }
+            if !unaffordable_withdrawals.is_empty() {
+                let queued_list = self.queue.entry(account_id).or_default();
+                for withdrawal in unaffordable_withdrawals {
+                    queued_list.insert_withdrawal(withdrawal);
+                }
+            }
+            if reserved.is_empty() {
+                self.reserved.remove(&account_id);
+            } else {
+                self.reserved.insert(account_id, reserved);
             }
             // Delete empty entry
             if list.is_empty() {
@@ -334,9 +970,7 @@ impl MemPool {
     /// Reset
     /// this method reset the current state of the mem pool
     /// discarded txs & withdrawals will be reinject to pool
-    fn reset(&mut self, old_tip: Option<H256>, new_tip: Option<H256>) -> Result<()> {
-        let mut reinject_txs: Vec<L2Transaction> = Default::default();
-        let mut reinject_withdrawals: Vec<WithdrawalRequest> = Default::default();
+    fn reset(&mut self, old_tip: Option<H256>, new_tip: Option<H256>) -> Result<ResetReport> {
         // read block from db
         let new_tip = match new_tip {
             Some(block_hash) => block_hash,
@@ -344,63 +978,11 @@ impl MemPool {
         };
         let new_tip_block = self.db.get_block(&new_tip)?.expect("new tip block");
 
+        let mut reorg = Reorg::default();
         if old_tip.is_some() && old_tip != Some(new_tip_block.raw().parent_block_hash().unpack()) {
             let old_tip = old_tip.unwrap();
             let old_tip_block = self.db.get_block(&old_tip)?.expect("old tip block");
-
-            let new_number: u64 = new_tip_block.raw().number().unpack();
-            let old_number: u64 = old_tip_block.raw().number().unpack();
-            let depth = max(new_number, old_number) - min(new_number, old_number);
-            if depth > 64 {
-                eprintln!("skipping deep transaction reorg: depth {}", depth);
-            } else {
-                let mut rem = old_tip_block;
-                let mut add = new_tip_block.clone();
-                let mut discarded_txs: HashSet<L2Transaction> = Default::default();
-                let mut included_txs: HashSet<L2Transaction> = Default::default();
-                let mut discarded_withdrawals: HashSet<WithdrawalRequest> = Default::default();
-                let mut included_withdrawals: HashSet<WithdrawalRequest> = Default::default();
-                while rem.raw().number().unpack() > add.raw().number().unpack() {
-                    discarded_txs.extend(rem.transactions().into_iter());
-                    discarded_withdrawals.extend(rem.withdrawals().into_iter());
-                    rem = self
-                        .db
-                        .get_block(&rem.raw().parent_block_hash().unpack())?
-                        .expect("get block");
-                }
-                while add.raw().number().unpack() > rem.raw().number().unpack() {
-                    included_txs.extend(add.transactions().into_iter());
-                    included_withdrawals.extend(rem.withdrawals().into_iter());
-                    add = self
-                        .db
-                        .get_block(&add.raw().parent_block_hash().unpack())?
-                        .expect("get block");
-                }
-                while rem.hash() != add.hash() {
-                    discarded_txs.extend(rem.transactions().into_iter());
-                    discarded_withdrawals.extend(rem.withdrawals().into_iter());
-                    rem = self
-                        .db
-                        .get_block(&rem.raw().parent_block_hash().unpack())?
-                        .expect("get block");
-                    included_txs.extend(add.transactions().into_iter());
-                    included_withdrawals.extend(add.withdrawals().into_iter());
-                    add = self
-                        .db
-                        .get_block(&add.raw().parent_block_hash().unpack())?
-                        .expect("get block");
-                }
-                reinject_txs = discarded_txs
-                    .difference(&included_txs)
-                    .into_iter()
-                    .cloned()
-                    .collect();
-                reinject_withdrawals = discarded_withdrawals
-                    .difference(&included_withdrawals)
-                    .into_iter()
-                    .cloned()
-                    .collect();
-            }
+            reorg = self.collect_reorg(old_tip_block, new_tip_block.clone())?;
         }
 
         // update current state
@@ -409,18 +991,147 @@ impl MemPool {
             .db
             .state_at(StateDBVersion::from_block_hash(tip_block_hash))?;
 
-        // re-inject txs
-        for tx in reinject_txs {
-            if self.push_transaction(tx.clone()).is_err() {
-                eprintln!("MemPool: drop tx {:?}", tx.hash());
+        // re-inject the contents of the discarded blocks in the chain order,
+        // a range of blocks is loaded at a time
+        let mut report = ResetReport::default();
+        for block_hashes in reorg.discarded_blocks.chunks(MAX_REINJECT_BLOCKS) {
+            let mut withdrawals = Vec::new();
+            let mut txs = Vec::new();
+            // the count of withdrawals & txs of each block
+            let mut counts = Vec::with_capacity(block_hashes.len());
+            for block_hash in block_hashes {
+                let block = self
+                    .db
+                    .get_block(block_hash)?
+                    .ok_or_else(|| anyhow!("can't find discarded block {:?}", block_hash))?;
+                let (block_withdrawals, block_txs) = reorg.reinjects(&block);
+                counts.push((block_withdrawals.len(), block_txs.len()));
+                withdrawals.extend(block_withdrawals);
+                txs.extend(block_txs);
+            }
+
+            // verify the signatures of the re-injected objects in parallel
+            let (withdrawal_signatures, tx_signatures) = {
+                let state = self.state_db.account_state_tree()?;
+                (
+                    self.generator
+                        .check_withdrawal_request_signatures(&state, &withdrawals),
+                    self.generator.check_transaction_signatures(&state, &txs),
+                )
+            };
+
+            // the withdrawals of a block are re-injected before the txs as they are packaged,
+            // so the nonces of an account follow each other
+            let mut withdrawals = withdrawals.into_iter().zip(withdrawal_signatures);
+            let mut txs = txs.into_iter().zip(tx_signatures);
+            for (withdrawals_count, txs_count) in counts {
+                for (withdrawal, signature) in withdrawals.by_ref().take(withdrawals_count) {
+                    self.reinject_withdrawal(
+                        withdrawal,
+                        signature.map_err(Into::into),
+                        &mut report,
+                    );
+                }
+                for (tx, signature) in txs.by_ref().take(txs_count) {
+                    self.reinject_tx(tx, signature.map_err(Into::into), &mut report);
+                }
             }
         }
-        // re-inject withdrawals
-        for withdrawal in reinject_withdrawals {
-            if self.push_withdrawal_request(withdrawal.clone()).is_err() {
-                eprintln!("MemPool: drop withdrawal {:?}", withdrawal);
+        Ok(report)
+    }
+
+    // re-inject a tx whose signature is verified
+    fn reinject_tx(
+        &mut self,
+        tx: L2Transaction,
+        signature: Result<(), Error>,
+        report: &mut ResetReport,
+    ) {
+        let tx_hash: H256 = tx.hash().into();
+        match signature.and_then(|()| self.add_transaction(tx, true)) {
+            Ok(()) => report.reinjected_txs.push(tx_hash),
+            Err(err) => {
+                log::warn!("MemPool: drop tx {:?}, reason: {}", tx_hash, err);
+                self.drop_rejected_tx(tx_hash, &err);
+                report.dropped_txs.push((tx_hash, err));
             }
         }
-        Ok(())
+    }
+
+    // re-inject a withdrawal whose signature is verified
+    fn reinject_withdrawal(
+        &mut self,
+        withdrawal: WithdrawalRequest,
+        signature: Result<(), Error>,
+        report: &mut ResetReport,
+    ) {
+        let withdrawal_hash: H256 = withdrawal.hash().into();
+        match signature.and_then(|()| self.add_withdrawal_request(withdrawal, true)) {
+            Ok(()) => report.reinjected_withdrawals.push(withdrawal_hash),
+            Err(err) => {
+                log::warn!(
+                    "MemPool: drop withdrawal {:?}, reason: {}",
+                    withdrawal_hash,
+                    err
+                );
+                self.drop_rejected_withdrawal(withdrawal_hash, &err);
+                report.dropped_withdrawals.push((withdrawal_hash, err));
+            }
+        }
+    }
+
+    // mark a rejected tx as dropped, a duplicated one is still in the pool
+    fn drop_rejected_tx(&mut self, tx_hash: H256, err: &Error) {
+        if let Error::Duplicated { .. } = err {
+            return;
+        }
+        self.status_tracker.set_tx_status(
+            tx_hash,
+            EntryStatus::Dropped(DropReason::Rejected(err.clone())),
+        );
+    }
+
+    // mark a rejected withdrawal as dropped, a duplicated one is still in the pool
+    fn drop_rejected_withdrawal(&mut self, withdrawal_hash: H256, err: &Error) {
+        if let Error::Duplicated { .. } = err {
+            return;
+        }
+        self.status_tracker.set_withdrawal_status(
+            withdrawal_hash,
+            EntryStatus::Dropped(DropReason::Rejected(err.clone())),
+        );
+    }
+
+    /// Walk back the old branch and the new branch to the common ancestor,
+    /// collect the hashes of the discarded blocks and the contents of the included blocks.
+    /// The blocks are loaded one at a time, the discarded contents are loaded again on re-injection
+    fn collect_reorg(&self, old_tip_block: L2Block, new_tip_block: L2Block) -> Result<Reorg> {
+        let mut reorg = Reorg::default();
+        let mut rem = old_tip_block;
+        let mut add = new_tip_block;
+        while rem.raw().number().unpack() > add.raw().number().unpack() {
+            reorg.discarded_blocks.push(rem.hash().into());
+            rem = self.get_parent_block(&rem)?;
+        }
+        while add.raw().number().unpack() > rem.raw().number().unpack() {
+            reorg.include_block(&add);
+            add = self.get_parent_block(&add)?;
+        }
+        while rem.hash() != add.hash() {
+            reorg.discarded_blocks.push(rem.hash().into());
+            rem = self.get_parent_block(&rem)?;
+            reorg.include_block(&add);
+            add = self.get_parent_block(&add)?;
+        }
+        // blocks are walked from tip to ancestor
+        reorg.discarded_blocks.reverse();
+        Ok(reorg)
+    }
+
+    fn get_parent_block(&self, block: &L2Block) -> Result<L2Block> {
+        let parent_block_hash: H256 = block.raw().parent_block_hash().unpack();
+        self.db
+            .get_block(&parent_block_hash)?
+            .ok_or_else(|| anyhow!("can't find parent block {:?}", parent_block_hash))
     }
 }
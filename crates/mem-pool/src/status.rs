@@ -0,0 +1,334 @@
+//! Status tracker
+//!
+//! Track the lifecycle of txs & withdrawals, from entering the MemPool to being finalized on chain.
+//! The tracker is fed by the MemPool (pending / queued / dropped) and by the chain (committed / reverted / finalized).
+
+use crate::error::Error;
+use gw_common::H256;
+use gw_types::{packed::L2Block, prelude::*};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+/// MAX dropped & finalized entries to keep, older ones are forgotten
+const MAX_TRACKED_SETTLED_ENTRIES: usize = 100_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DropReason {
+    /// rejected when re-injecting into the pool after a reorg
+    Rejected(Error),
+    /// the nonce is consumed by another entry
+    Stale,
+    /// the block producer can't package it into a block
+    Unused,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntryStatus {
+    /// executable, waiting to be packaged
+    Pending,
+    /// not executable for now, waiting in the future queue
+    Queued,
+    /// removed from the pool without being committed
+    Dropped(DropReason),
+    /// included in a block
+    Committed { block_number: u64, block_hash: H256 },
+    /// included in a finalized block
+    Finalized { block_number: u64, block_hash: H256 },
+    /// the including block is reverted
+    Reverted { block_number: u64, block_hash: H256 },
+}
+
+impl EntryStatus {
+    fn is_settled(&self) -> bool {
+        match self {
+            EntryStatus::Dropped(_) | EntryStatus::Finalized { .. } => true,
+            _ => false,
+        }
+    }
+
+    fn is_on_chain(&self) -> bool {
+        match self {
+            EntryStatus::Committed { .. } | EntryStatus::Finalized { .. } => true,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EntryKind {
+    Tx,
+    Withdrawal,
+}
+
+// entries of a committed block which is not finalized yet
+struct CommittedBlock {
+    block_hash: H256,
+    txs: Vec<H256>,
+    withdrawals: Vec<H256>,
+}
+
+#[derive(Default)]
+pub struct StatusTracker {
+    txs: HashMap<H256, EntryStatus>,
+    withdrawals: HashMap<H256, EntryStatus>,
+    // block number -> committed block
+    committed_blocks: BTreeMap<u64, CommittedBlock>,
+    // settled entries in settling order, used to forget the oldest ones
+    settled: VecDeque<(EntryKind, H256)>,
+}
+
+impl StatusTracker {
+    /// Return the status of a tx
+    pub fn tx_status(&self, tx_hash: &H256) -> Option<&EntryStatus> {
+        self.txs.get(tx_hash)
+    }
+
+    /// Return the status of a withdrawal
+    pub fn withdrawal_status(&self, withdrawal_hash: &H256) -> Option<&EntryStatus> {
+        self.withdrawals.get(withdrawal_hash)
+    }
+
+    pub(crate) fn set_tx_status(&mut self, tx_hash: H256, status: EntryStatus) {
+        self.set_status(EntryKind::Tx, tx_hash, status);
+    }
+
+    pub(crate) fn set_withdrawal_status(&mut self, withdrawal_hash: H256, status: EntryStatus) {
+        self.set_status(EntryKind::Withdrawal, withdrawal_hash, status);
+    }
+
+    /// Mark a tx as stale dropped, unless it's already on chain
+    pub(crate) fn drop_stale_tx(&mut self, tx_hash: H256) {
+        if !self
+            .txs
+            .get(&tx_hash)
+            .map(EntryStatus::is_on_chain)
+            .unwrap_or(false)
+        {
+            self.set_tx_status(tx_hash, EntryStatus::Dropped(DropReason::Stale));
+        }
+    }
+
+    /// Mark a withdrawal as stale dropped, unless it's already on chain
+    pub(crate) fn drop_stale_withdrawal(&mut self, withdrawal_hash: H256) {
+        let is_on_chain = self
+            .withdrawals
+            .get(&withdrawal_hash)
+            .map(EntryStatus::is_on_chain)
+            .unwrap_or(false);
+        if !is_on_chain {
+            self.set_withdrawal_status(withdrawal_hash, EntryStatus::Dropped(DropReason::Stale));
+        }
+    }
+
+    /// Mark the txs & withdrawals of a block as committed
+    pub fn commit_block(&mut self, block: &L2Block) {
+        let block_number: u64 = block.raw().number().unpack();
+        let block_hash: H256 = block.hash().into();
+        let committed_block = CommittedBlock {
+            block_hash,
+            txs: block
+                .transactions()
+                .into_iter()
+                .map(|tx| tx.hash().into())
+                .collect(),
+            withdrawals: block
+                .withdrawals()
+                .into_iter()
+                .map(|withdrawal| withdrawal.hash().into())
+                .collect(),
+        };
+        let status = EntryStatus::Committed {
+            block_number,
+            block_hash,
+        };
+        for tx_hash in &committed_block.txs {
+            self.set_tx_status(*tx_hash, status.clone());
+        }
+        for withdrawal_hash in &committed_block.withdrawals {
+            self.set_withdrawal_status(*withdrawal_hash, status.clone());
+        }
+        self.committed_blocks.insert(block_number, committed_block);
+    }
+
+    /// Mark the txs & withdrawals of a block as reverted
+    pub fn revert_block(&mut self, block: &L2Block) {
+        let block_number: u64 = block.raw().number().unpack();
+        let block_hash: H256 = block.hash().into();
+        let status = EntryStatus::Reverted {
+            block_number,
+            block_hash,
+        };
+        for tx in block.transactions() {
+            self.set_tx_status(tx.hash().into(), status.clone());
+        }
+        for withdrawal in block.withdrawals() {
+            self.set_withdrawal_status(withdrawal.hash().into(), status.clone());
+        }
+        if let Some(committed_block) = self.committed_blocks.get(&block_number) {
+            if committed_block.block_hash == block_hash {
+                self.committed_blocks.remove(&block_number);
+            }
+        }
+    }
+
+    /// Mark the txs & withdrawals of blocks which number is lower than or equal to
+    /// `last_finalized_block_number` as finalized
+    pub fn finalize(&mut self, last_finalized_block_number: u64) {
+        let unfinalized = self
+            .committed_blocks
+            .split_off(&last_finalized_block_number.saturating_add(1));
+        let finalized = std::mem::replace(&mut self.committed_blocks, unfinalized);
+        for (block_number, committed_block) in finalized {
+            let CommittedBlock {
+                block_hash,
+                txs,
+                withdrawals,
+            } = committed_block;
+            let committed = EntryStatus::Committed {
+                block_number,
+                block_hash,
+            };
+            let status = EntryStatus::Finalized {
+                block_number,
+                block_hash,
+            };
+            // skip entries which are re-committed by other blocks
+            for tx_hash in txs {
+                if self.txs.get(&tx_hash) == Some(&committed) {
+                    self.set_tx_status(tx_hash, status.clone());
+                }
+            }
+            for withdrawal_hash in withdrawals {
+                if self.withdrawals.get(&withdrawal_hash) == Some(&committed) {
+                    self.set_withdrawal_status(withdrawal_hash, status.clone());
+                }
+            }
+        }
+    }
+
+    fn set_status(&mut self, kind: EntryKind, hash: H256, status: EntryStatus) {
+        let is_settled = status.is_settled();
+        let statuses = match kind {
+            EntryKind::Tx => &mut self.txs,
+            EntryKind::Withdrawal => &mut self.withdrawals,
+        };
+        statuses.insert(hash, status);
+        if is_settled {
+            self.settled.push_back((kind, hash));
+            self.forget_settled();
+        }
+    }
+
+    // forget the oldest settled entries
+    fn forget_settled(&mut self) {
+        while self.settled.len() > MAX_TRACKED_SETTLED_ENTRIES {
+            let (kind, hash) = self.settled.pop_front().expect("settled entry");
+            let statuses = match kind {
+                EntryKind::Tx => &mut self.txs,
+                EntryKind::Withdrawal => &mut self.withdrawals,
+            };
+            // the entry may be re-activated after it's settled
+            if statuses.get(&hash).map(EntryStatus::is_settled) == Some(true) {
+                statuses.remove(&hash);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gw_types::packed::{
+        L2Transaction, RawL2Block, RawL2Transaction, RawWithdrawalRequest, WithdrawalRequest,
+    };
+
+    fn build_block(number: u64, timestamp: u64, tx: &L2Transaction) -> L2Block {
+        let withdrawal = WithdrawalRequest::new_builder()
+            .raw(
+                RawWithdrawalRequest::new_builder()
+                    .nonce((number as u32).pack())
+                    .build(),
+            )
+            .build();
+        let raw = RawL2Block::new_builder()
+            .number(number.pack())
+            .timestamp(timestamp.pack())
+            .build();
+        L2Block::new_builder()
+            .raw(raw)
+            .transactions(vec![tx.clone()].pack())
+            .withdrawals(vec![withdrawal].pack())
+            .build()
+    }
+
+    fn build_tx(nonce: u32) -> L2Transaction {
+        let raw = RawL2Transaction::new_builder().nonce(nonce.pack()).build();
+        L2Transaction::new_builder().raw(raw).build()
+    }
+
+    #[test]
+    fn test_revert_block() {
+        let mut tracker = StatusTracker::default();
+        let tx = build_tx(0);
+        let tx_hash: H256 = tx.hash().into();
+        let block = build_block(1, 0, &tx);
+        let withdrawal_hash: H256 = block.withdrawals().get(0).unwrap().hash().into();
+        let block_hash: H256 = block.hash().into();
+        tracker.set_tx_status(tx_hash, EntryStatus::Pending);
+        tracker.commit_block(&block);
+        let committed = EntryStatus::Committed {
+            block_number: 1,
+            block_hash,
+        };
+        assert_eq!(tracker.tx_status(&tx_hash), Some(&committed));
+        assert_eq!(
+            tracker.withdrawal_status(&withdrawal_hash),
+            Some(&committed)
+        );
+
+        // a committed entry is not dropped by the pool
+        tracker.drop_stale_tx(tx_hash);
+        assert_eq!(tracker.tx_status(&tx_hash), Some(&committed));
+
+        tracker.revert_block(&block);
+        let reverted = EntryStatus::Reverted {
+            block_number: 1,
+            block_hash,
+        };
+        assert_eq!(tracker.tx_status(&tx_hash), Some(&reverted));
+        assert_eq!(tracker.withdrawal_status(&withdrawal_hash), Some(&reverted));
+        // the reverted block is never finalized
+        tracker.finalize(1);
+        assert_eq!(tracker.tx_status(&tx_hash), Some(&reverted));
+
+        // a reverted entry is dropped if the nonce is consumed by the new chain
+        tracker.drop_stale_tx(tx_hash);
+        assert_eq!(
+            tracker.tx_status(&tx_hash),
+            Some(&EntryStatus::Dropped(DropReason::Stale))
+        );
+    }
+
+    #[test]
+    fn test_recommit_reverted_entries() {
+        let mut tracker = StatusTracker::default();
+        let tx = build_tx(0);
+        let tx_hash: H256 = tx.hash().into();
+        let block = build_block(1, 0, &tx);
+        let fork_block = build_block(1, 1, &tx);
+        let fork_block_hash: H256 = fork_block.hash().into();
+        tracker.commit_block(&block);
+        tracker.revert_block(&block);
+        // the entry is committed again by the fork block
+        tracker.commit_block(&fork_block);
+        // revert an unknown block doesn't remove the committed fork block
+        tracker.revert_block(&build_block(1, 2, &build_tx(1)));
+        tracker.finalize(1);
+        assert_eq!(
+            tracker.tx_status(&tx_hash),
+            Some(&EntryStatus::Finalized {
+                block_number: 1,
+                block_hash: fork_block_hash,
+            })
+        );
+    }
+}
@@ -1,5 +1,7 @@
 pub mod chain_view;
 mod db_utils;
+pub mod log_index;
+pub mod preimage_index;
 pub mod smt_store_impl;
 pub mod state_db;
 mod store_impl;
@@ -0,0 +1,274 @@
+//! Log index
+//!
+//! Index the logs in the tx receipts of the main chain blocks by account id and by topic,
+//! the index is built in `attach_block` and removed in `detach_block`.
+
+use crate::{db_utils::build_transaction_key, traits::KVStore, transaction::StoreTransaction};
+use gw_common::H256;
+use gw_db::{
+    error::Error,
+    schema::{COLUMN_LOG_INDEX, COLUMN_TRANSACTION_RECEIPT},
+    Direction, IteratorMode,
+};
+use gw_types::{core::LogType, packed, prelude::*};
+use std::convert::TryFrom;
+
+const ACCOUNT_INDEX_PREFIX: u8 = 0;
+const TOPIC_INDEX_PREFIX: u8 = 1;
+// block_number | tx_index | log_index
+const LOG_POSITION_SIZE: usize = 8 + 4 + 4;
+
+/// Filter logs of the main chain, all the conditions must be satisfied
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogFilter {
+    /// lowest block number, inclusive
+    pub from_block: u64,
+    /// highest block number, inclusive
+    pub to_block: u64,
+    pub account_id: Option<u32>,
+    pub log_type: Option<LogType>,
+    /// the log must contain the topic
+    pub topic: Option<H256>,
+}
+
+impl LogFilter {
+    fn matches(&self, log: &packed::LogItem) -> bool {
+        if let Some(account_id) = self.account_id {
+            let log_account_id: u32 = log.account_id().unpack();
+            if log_account_id != account_id {
+                return false;
+            }
+        }
+        if let Some(log_type) = self.log_type {
+            if LogType::try_from(log.log_type()) != Ok(log_type) {
+                return false;
+            }
+        }
+        if let Some(topic) = self.topic {
+            let topic: packed::Byte32 = topic.pack();
+            if !log
+                .topics()
+                .into_iter()
+                .any(|t| t.as_slice() == topic.as_slice())
+            {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// A log with its position on chain
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub block_number: u64,
+    pub block_hash: H256,
+    pub tx_hash: H256,
+    pub tx_index: u32,
+    /// index of the log in the tx receipt
+    pub log_index: u32,
+    pub log: packed::LogItem,
+}
+
+fn build_log_position(block_number: u64, tx_index: u32, log_index: u32) -> Vec<u8> {
+    let mut position = Vec::with_capacity(LOG_POSITION_SIZE);
+    // use BE, so the logs are sorted by the position
+    position.extend_from_slice(&block_number.to_be_bytes());
+    position.extend_from_slice(&tx_index.to_be_bytes());
+    position.extend_from_slice(&log_index.to_be_bytes());
+    position
+}
+
+fn parse_log_position(position: &[u8]) -> (u64, u32, u32) {
+    let mut block_number = [0u8; 8];
+    let mut tx_index = [0u8; 4];
+    let mut log_index = [0u8; 4];
+    block_number.copy_from_slice(&position[..8]);
+    tx_index.copy_from_slice(&position[8..12]);
+    log_index.copy_from_slice(&position[12..16]);
+    (
+        u64::from_be_bytes(block_number),
+        u32::from_be_bytes(tx_index),
+        u32::from_be_bytes(log_index),
+    )
+}
+
+fn build_account_index_prefix(account_id: u32) -> Vec<u8> {
+    let mut prefix = vec![ACCOUNT_INDEX_PREFIX];
+    prefix.extend_from_slice(&account_id.to_be_bytes());
+    prefix
+}
+
+fn build_topic_index_prefix(topic: &H256) -> Vec<u8> {
+    let mut prefix = vec![TOPIC_INDEX_PREFIX];
+    prefix.extend_from_slice(topic.as_slice());
+    prefix
+}
+
+// index keys of a log
+fn build_log_index_keys(log: &packed::LogItem, position: &[u8]) -> Vec<Vec<u8>> {
+    let account_id: u32 = log.account_id().unpack();
+    let mut prefixes = vec![build_account_index_prefix(account_id)];
+    for topic in log.topics().into_iter() {
+        let topic: H256 = topic.unpack();
+        prefixes.push(build_topic_index_prefix(&topic));
+    }
+    prefixes
+        .into_iter()
+        .map(|mut key| {
+            key.extend_from_slice(position);
+            key
+        })
+        .collect()
+}
+
+impl StoreTransaction {
+    /// Index the logs of a main chain block
+    pub(crate) fn insert_log_index(&self, block: &packed::L2Block) -> Result<(), Error> {
+        self.update_log_index(block, |key, tx_hash| {
+            self.insert_raw(COLUMN_LOG_INDEX, key, tx_hash.as_slice())
+        })
+    }
+
+    /// Remove the log index of a detached block
+    pub(crate) fn remove_log_index(&self, block: &packed::L2Block) -> Result<(), Error> {
+        self.update_log_index(block, |key, _tx_hash| self.delete(COLUMN_LOG_INDEX, key))
+    }
+
+    fn update_log_index<F: FnMut(&[u8], &H256) -> Result<(), Error>>(
+        &self,
+        block: &packed::L2Block,
+        mut f: F,
+    ) -> Result<(), Error> {
+        let block_number: u64 = block.raw().number().unpack();
+        for (tx_index, tx) in block.transactions().into_iter().enumerate() {
+            let tx_hash: H256 = tx.hash().into();
+            let receipt = match self.get_transaction_receipt_by_index(&tx_hash, tx_index as u32) {
+                Some(receipt) => receipt,
+                None => continue,
+            };
+            for (log_index, log) in receipt.logs().into_iter().enumerate() {
+                let position = build_log_position(block_number, tx_index as u32, log_index as u32);
+                for key in build_log_index_keys(&log, &position) {
+                    f(&key, &tx_hash)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn get_transaction_receipt_by_index(
+        &self,
+        tx_hash: &H256,
+        tx_index: u32,
+    ) -> Option<packed::TxReceipt> {
+        let key = build_transaction_key(tx_hash.pack(), tx_index);
+        self.get(COLUMN_TRANSACTION_RECEIPT, &key).map(|slice| {
+            packed::TxReceiptReader::from_slice_should_be_ok(&slice.as_ref()).to_entity()
+        })
+    }
+
+    /// Query logs of the main chain, the logs are sorted by the position on chain
+    pub fn get_logs(&self, filter: &LogFilter) -> Result<Vec<LogEntry>, Error> {
+        let tip_number: u64 = self.get_tip_block()?.raw().number().unpack();
+        let to_block = std::cmp::min(filter.to_block, tip_number);
+        if filter.from_block > to_block {
+            return Ok(Vec::new());
+        }
+        // use the index if possible, otherwise scan the blocks
+        let index_prefix = match (filter.account_id, filter.topic) {
+            (Some(account_id), _) => Some(build_account_index_prefix(account_id)),
+            (None, Some(topic)) => Some(build_topic_index_prefix(&topic)),
+            (None, None) => None,
+        };
+        match index_prefix {
+            Some(prefix) => self.get_logs_by_index(filter, &prefix, to_block),
+            None => self.get_logs_by_scanning(filter, to_block),
+        }
+    }
+
+    fn get_logs_by_index(
+        &self,
+        filter: &LogFilter,
+        prefix: &[u8],
+        to_block: u64,
+    ) -> Result<Vec<LogEntry>, Error> {
+        let mut start_key = prefix.to_vec();
+        start_key.extend_from_slice(&build_log_position(filter.from_block, 0, 0));
+        let mut end_key = prefix.to_vec();
+        end_key.extend_from_slice(&build_log_position(to_block, u32::MAX, u32::MAX));
+
+        let mut entries = Vec::new();
+        let iter = self.get_iter(
+            COLUMN_LOG_INDEX,
+            IteratorMode::From(&start_key, Direction::Forward),
+        );
+        for (key, value) in iter {
+            if key.len() != prefix.len() + LOG_POSITION_SIZE || key[..] > end_key[..] {
+                break;
+            }
+            let (block_number, tx_index, log_index) = parse_log_position(&key[prefix.len()..]);
+            let mut tx_hash = [0u8; 32];
+            tx_hash.copy_from_slice(&value);
+            let tx_hash = H256::from(tx_hash);
+            let log = self
+                .get_transaction_receipt_by_index(&tx_hash, tx_index)
+                .and_then(|receipt| receipt.logs().get(log_index as usize))
+                .ok_or_else(|| Error::from("log index is inconsistent".to_string()))?;
+            if !filter.matches(&log) {
+                continue;
+            }
+            let block_hash = self
+                .get_block_hash_by_number(block_number)?
+                .ok_or_else(|| Error::from("log index is inconsistent".to_string()))?;
+            entries.push(LogEntry {
+                block_number,
+                block_hash,
+                tx_hash,
+                tx_index,
+                log_index,
+                log,
+            });
+        }
+        Ok(entries)
+    }
+
+    fn get_logs_by_scanning(
+        &self,
+        filter: &LogFilter,
+        to_block: u64,
+    ) -> Result<Vec<LogEntry>, Error> {
+        let mut entries = Vec::new();
+        for block_number in filter.from_block..=to_block {
+            let block_hash = match self.get_block_hash_by_number(block_number)? {
+                Some(block_hash) => block_hash,
+                None => continue,
+            };
+            let block = self
+                .get_block(&block_hash)?
+                .ok_or_else(|| Error::from(format!("block {} not found", block_number)))?;
+            for (tx_index, tx) in block.transactions().into_iter().enumerate() {
+                let tx_hash: H256 = tx.hash().into();
+                let receipt = match self.get_transaction_receipt_by_index(&tx_hash, tx_index as u32)
+                {
+                    Some(receipt) => receipt,
+                    None => continue,
+                };
+                for (log_index, log) in receipt.logs().into_iter().enumerate() {
+                    if !filter.matches(&log) {
+                        continue;
+                    }
+                    entries.push(LogEntry {
+                        block_number,
+                        block_hash,
+                        tx_hash,
+                        tx_index: tx_index as u32,
+                        log_index: log_index as u32,
+                        log,
+                    });
+                }
+            }
+        }
+        Ok(entries)
+    }
+}
@@ -0,0 +1,76 @@
+//! Account key preimage index
+//!
+//! The account keys in the state tree are hashes: `blake2b(id | type | key)`,
+//! the optional preimage index records the raw `(account id, key)` pairs to iterate the storage of an account.
+//! The index is written in the same DB transaction as the state. It isn't versioned by blocks,
+//! when the chain is reverted the index is cleared along with the account state tree,
+//! then it's rebuilt by replaying the remaining blocks.
+
+use crate::{traits::KVStore, transaction::StoreTransaction};
+use gw_common::H256;
+use gw_db::{
+    error::Error,
+    schema::{COLUMN_ACCOUNT_KEY_PREIMAGE, COLUMN_META, META_PREIMAGE_INDEX_ENABLED_KEY},
+    Direction, IteratorMode,
+};
+use std::mem::size_of;
+
+// account_id | key
+const PREIMAGE_INDEX_KEY_SIZE: usize = size_of::<u32>() + 32;
+
+fn build_preimage_index_key(account_id: u32, key: &H256) -> Vec<u8> {
+    let mut index_key = Vec::with_capacity(PREIMAGE_INDEX_KEY_SIZE);
+    index_key.extend_from_slice(&account_id.to_be_bytes());
+    index_key.extend_from_slice(key.as_slice());
+    index_key
+}
+
+impl StoreTransaction {
+    /// Enable the preimage index, only the keys written after enabling are indexed
+    pub fn enable_preimage_index(&self) -> Result<(), Error> {
+        self.insert_raw(COLUMN_META, META_PREIMAGE_INDEX_ENABLED_KEY, &[1])
+    }
+
+    pub fn is_preimage_index_enabled(&self) -> bool {
+        self.get(COLUMN_META, META_PREIMAGE_INDEX_ENABLED_KEY)
+            .map(|slice| slice.as_ref() == [1])
+            .unwrap_or(false)
+    }
+
+    pub(crate) fn insert_account_key_preimage(
+        &self,
+        account_id: u32,
+        key: &H256,
+    ) -> Result<(), Error> {
+        let index_key = build_preimage_index_key(account_id, key);
+        self.insert_raw(COLUMN_ACCOUNT_KEY_PREIMAGE, &index_key, &[])
+    }
+
+    /// Remove all the preimages
+    pub(crate) fn clear_preimage_index(&self) -> Result<(), Error> {
+        for (k, _v) in self.get_iter(COLUMN_ACCOUNT_KEY_PREIMAGE, IteratorMode::Start) {
+            self.delete(COLUMN_ACCOUNT_KEY_PREIMAGE, k.as_ref())?;
+        }
+        Ok(())
+    }
+
+    /// Return the indexed keys of an account in ascending order,
+    /// the keys may be deleted (set to zero) from the state later
+    pub fn get_account_storage_keys(&self, account_id: u32) -> Vec<H256> {
+        let prefix = account_id.to_be_bytes();
+        let iter = self.get_iter(
+            COLUMN_ACCOUNT_KEY_PREIMAGE,
+            IteratorMode::From(&prefix, Direction::Forward),
+        );
+        let mut keys = Vec::new();
+        for (index_key, _v) in iter {
+            if index_key.len() != PREIMAGE_INDEX_KEY_SIZE || index_key[..prefix.len()] != prefix {
+                break;
+            }
+            let mut key = [0u8; 32];
+            key.copy_from_slice(&index_key[prefix.len()..]);
+            keys.push(key.into());
+        }
+        keys
+    }
+}
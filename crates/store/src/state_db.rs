@@ -3,7 +3,12 @@
 use std::{cell::RefCell, collections::HashSet};
 
 use crate::{smt_store_impl::SMTStore, traits::KVStore, transaction::StoreTransaction};
-use gw_common::{error::Error as StateError, smt::SMT, state::State, H256};
+use gw_common::{
+    error::Error as StateError,
+    smt::SMT,
+    state::{build_account_key, State},
+    H256,
+};
 use gw_db::schema::{
     Col, COLUMN_ACCOUNT_SMT_BRANCH, COLUMN_ACCOUNT_SMT_LEAF, COLUMN_DATA, COLUMN_SCRIPT,
 };
@@ -71,7 +76,9 @@ impl StateDBTransaction {
 
     pub fn account_state_tree<'a>(&'a self) -> Result<StateTree<'a>, Error> {
         let account_count = self.inner.get_account_count()?;
-        Ok(StateTree::new(self, self.account_smt()?, account_count))
+        let mut tree = StateTree::new(self, self.account_smt()?, account_count);
+        tree.preimage_index = self.inner.is_preimage_index_enabled();
+        Ok(tree)
     }
 
     /// TODO refacotring with version based DB
@@ -84,6 +91,7 @@ impl StateDBTransaction {
                 self.delete(col, k.as_ref())?;
             }
         }
+        self.inner.clear_preimage_index()?;
         Ok(())
     }
 }
@@ -123,6 +131,8 @@ pub struct StateTree<'a> {
     account_count: u32,
     db: &'a StateDBTransaction,
     tracker: StateTracker,
+    // record the preimages of the account keys
+    preimage_index: bool,
 }
 
 impl<'a> StateTree<'a> {
@@ -136,6 +146,7 @@ impl<'a> StateTree<'a> {
             db,
             account_count,
             tracker: StateTracker::new(),
+            preimage_index: false,
         }
     }
 
@@ -156,6 +167,19 @@ impl<'a> StateTree<'a> {
             .expect("set smt root");
         Ok(())
     }
+
+    /// Return the non-zero storage values of an account, requires the preimage index
+    pub fn get_account_storage(&self, account_id: u32) -> Result<Vec<(H256, H256)>, StateError> {
+        let mut storage = Vec::new();
+        for key in self.db.inner.get_account_storage_keys(account_id) {
+            let raw_key = build_account_key(account_id, key.as_slice());
+            let value: H256 = self.tree.get(&raw_key.into())?.into();
+            if !value.is_zero() {
+                storage.push((key, value));
+            }
+        }
+        Ok(storage)
+    }
 }
 
 impl<'a> State for StateTree<'a> {
@@ -180,6 +204,19 @@ impl<'a> State for StateTree<'a> {
         let root = self.tree.root();
         Ok(*root)
     }
+    fn get_raw_untracked(&self, key: &H256) -> Result<H256, StateError> {
+        let v = self.tree.get(&(*key).into())?;
+        Ok(v.into())
+    }
+    fn record_account_key(&mut self, id: u32, key: &H256) -> Result<(), StateError> {
+        if self.preimage_index {
+            self.db
+                .inner
+                .insert_account_key_preimage(id, key)
+                .map_err(|_| StateError::Store)?;
+        }
+        Ok(())
+    }
 }
 
 impl<'a> CodeStore for StateTree<'a> {
@@ -380,6 +380,9 @@ impl StoreTransaction {
         });
         self.update_custodian_assets(deposit_assets, withdrawal_assets)?;
 
+        // build log index
+        self.insert_log_index(&block)?;
+
         // build main chain index
         self.insert_raw(COLUMN_INDEX, raw_number.as_slice(), &block_hash)?;
         self.insert_raw(COLUMN_INDEX, &block_hash, raw_number.as_slice())?;
@@ -423,6 +426,9 @@ impl StoreTransaction {
         });
         self.update_custodian_assets(withdrawal_assets, deposit_assets)?;
 
+        // remove log index
+        self.remove_log_index(block)?;
+
         let block_number = block.raw().number();
         self.delete(COLUMN_INDEX, block_number.as_slice())?;
         self.delete(COLUMN_INDEX, &block.hash())?;
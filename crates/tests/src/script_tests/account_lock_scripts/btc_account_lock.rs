@@ -0,0 +1,108 @@
+use super::*;
+use crate::script_tests::utils::layer1::*;
+use ckb_crypto::secp::{Generator, Privkey, Pubkey};
+use ckb_error::assert_error_eq;
+use ckb_script::{ScriptError, TransactionScriptsVerifier};
+use ckb_types::{bytes::Bytes, packed::WitnessArgs, prelude::*};
+use gw_generator::account_lock_manage::{secp256k1::Secp256k1Bitcoin, LockAlgorithm};
+use gw_generator::builtin_scripts::BTC_ACCOUNT_LOCK;
+use rand::{thread_rng, Rng};
+use ripemd160::Ripemd160;
+use sha2::{Digest, Sha256};
+
+const ERROR_PUBKEY_BLAKE160_HASH: i8 = -31;
+
+fn sign_message(key: &Privkey, message: [u8; 32]) -> Bytes {
+    // calculate bitcoin signing message of the hex encoded message
+    let message = {
+        let hex_message = hex::encode(&message);
+        let mut data = b"\x18Bitcoin Signed Message:\n".to_vec();
+        data.push(hex_message.len() as u8);
+        data.extend_from_slice(hex_message.as_bytes());
+        let buf = Sha256::digest(&Sha256::digest(&data));
+        let mut signing_message = [0u8; 32];
+        signing_message.copy_from_slice(&buf[..]);
+        ckb_types::H256::from(signing_message)
+    };
+    let sig = key.sign_recoverable(&message).expect("sign");
+    let sig = sig.serialize();
+    // header | r | s, the header of a compressed pubkey is 31 + recid
+    let mut signature = [0u8; 65];
+    signature[0] = 31 + sig[64];
+    signature[1..].copy_from_slice(&sig[..64]);
+    signature.to_vec().into()
+}
+
+pub fn hash160_pubkey_hash(pubkey: &Pubkey) -> Bytes {
+    // the compressed pubkey
+    let buf = Ripemd160::digest(&Sha256::digest(&pubkey.serialize()));
+    buf.to_vec().into()
+}
+
+fn verify_tx(
+    signature: &Bytes,
+    pubkey_hash: Bytes,
+    message: [u8; 32],
+) -> Result<u64, ckb_error::Error> {
+    let mut data_loader = DummyDataLoader::new();
+    let tx = gen_tx(
+        &mut data_loader,
+        &BTC_ACCOUNT_LOCK,
+        pubkey_hash,
+        Bytes::from(message.to_vec()),
+    );
+    let tx = tx
+        .as_advanced_builder()
+        .set_witnesses(vec![WitnessArgs::new_builder()
+            .lock(Some(signature.clone()).pack())
+            .build()
+            .as_bytes()
+            .pack()])
+        .build();
+    let resolved_tx = build_resolved_tx(&data_loader, &tx);
+    let mut verifier = TransactionScriptsVerifier::new(&resolved_tx, &data_loader);
+    verifier.set_debug_printer(|_script, msg| println!("[script debug] {}", msg));
+    verifier.verify(MAX_CYCLES)
+}
+
+#[test]
+fn test_sign_btc_message() {
+    let privkey = Generator::random_privkey();
+    let pubkey = privkey.pubkey().expect("pubkey");
+    let pubkey_hash = hash160_pubkey_hash(&pubkey);
+    let mut rng = thread_rng();
+    let mut message = [0u8; 32];
+    rng.fill(&mut message);
+    let signature = sign_message(&privkey, message);
+    verify_tx(&signature, pubkey_hash.clone(), message).expect("pass verification");
+    let valid = Secp256k1Bitcoin::default()
+        .verify_signature(pubkey_hash, signature, message.into())
+        .unwrap();
+    assert!(valid);
+}
+
+#[test]
+fn test_wrong_btc_signature() {
+    let privkey = Generator::random_privkey();
+    let pubkey = privkey.pubkey().expect("pubkey");
+    let pubkey_hash = hash160_pubkey_hash(&pubkey);
+    let mut rng = thread_rng();
+    let mut message = [0u8; 32];
+    rng.fill(&mut message);
+    let signature = {
+        let mut wrong_message = [0u8; 32];
+        rng.fill(&mut wrong_message);
+        sign_message(&privkey, wrong_message)
+    };
+    let verify_result = verify_tx(&signature, pubkey_hash.clone(), message);
+    let script_cell_index = 0;
+    assert_error_eq!(
+        verify_result.unwrap_err(),
+        ScriptError::ValidationFailure(ERROR_PUBKEY_BLAKE160_HASH)
+            .input_lock_script(script_cell_index)
+    );
+    let valid = Secp256k1Bitcoin::default()
+        .verify_signature(pubkey_hash, signature, message.into())
+        .unwrap();
+    assert!(!valid);
+}
@@ -1,10 +1,115 @@
+mod btc_account_lock;
 mod eth_account_lock;
+mod multisig_account_lock;
+mod tron_account_lock;
 
-use ckb_types::bytes::Bytes;
+use crate::script_tests::utils::layer1::DummyDataLoader;
+use ckb_types::{
+    bytes::Bytes,
+    core::{Capacity, DepType, ScriptHashType, TransactionBuilder, TransactionView},
+    packed::{CellDep, CellInput, CellOutput, OutPoint, Script},
+    prelude::*,
+};
 use lazy_static::lazy_static;
+use rand::{thread_rng, Rng};
 
 lazy_static! {
     pub static ref SECP256K1_DATA_BIN: Bytes = Bytes::from(
         &include_bytes!("../../../../../c/deps/ckb-miscellaneous-scripts/build/secp256k1_data")[..]
     );
 }
+
+/// Build a tx which unlocks a cell of the account lock, the input cell data is the message
+pub fn gen_tx(
+    dummy: &mut DummyDataLoader,
+    lock_code: &Bytes,
+    lock_args: Bytes,
+    input_data: Bytes,
+) -> TransactionView {
+    let mut rng = thread_rng();
+    // setup sighash_all dep
+    let script_out_point = {
+        let contract_tx_hash = {
+            let mut buf = [0u8; 32];
+            rng.fill(&mut buf);
+            buf.pack()
+        };
+        OutPoint::new(contract_tx_hash.clone(), 0)
+    };
+    // dep contract code
+    let script_cell = CellOutput::new_builder()
+        .capacity(
+            Capacity::bytes(lock_code.len())
+                .expect("script capacity")
+                .pack(),
+        )
+        .build();
+    let script_cell_data_hash = CellOutput::calc_data_hash(lock_code);
+    dummy
+        .cells
+        .insert(script_out_point.clone(), (script_cell, lock_code.clone()));
+    // setup secp256k1_data dep
+    let secp256k1_data_out_point = {
+        let tx_hash = {
+            let mut buf = [0u8; 32];
+            rng.fill(&mut buf);
+            buf.pack()
+        };
+        OutPoint::new(tx_hash, 0)
+    };
+    let secp256k1_data_cell = CellOutput::new_builder()
+        .capacity(
+            Capacity::bytes(SECP256K1_DATA_BIN.len())
+                .expect("data capacity")
+                .pack(),
+        )
+        .build();
+    dummy.cells.insert(
+        secp256k1_data_out_point.clone(),
+        (secp256k1_data_cell, SECP256K1_DATA_BIN.clone()),
+    );
+    // setup default tx builder
+    let dummy_capacity = Capacity::shannons(42);
+    let tx_builder = TransactionBuilder::default()
+        .cell_dep(
+            CellDep::new_builder()
+                .out_point(script_out_point)
+                .dep_type(DepType::Code.into())
+                .build(),
+        )
+        .cell_dep(
+            CellDep::new_builder()
+                .out_point(secp256k1_data_out_point)
+                .dep_type(DepType::Code.into())
+                .build(),
+        )
+        .output(
+            CellOutput::new_builder()
+                .capacity(dummy_capacity.pack())
+                .build(),
+        )
+        .output_data(Bytes::new().pack());
+
+    let previous_tx_hash = {
+        let mut buf = [0u8; 32];
+        rng.fill(&mut buf);
+        buf.pack()
+    };
+    let previous_out_point = OutPoint::new(previous_tx_hash, 0);
+    let script = Script::new_builder()
+        .args(lock_args.pack())
+        .code_hash(script_cell_data_hash.clone())
+        .hash_type(ScriptHashType::Data.into())
+        .build();
+    let previous_output_cell = CellOutput::new_builder()
+        .capacity(dummy_capacity.pack())
+        .lock(script)
+        .build();
+    dummy.cells.insert(
+        previous_out_point.clone(),
+        (previous_output_cell.clone(), input_data),
+    );
+    tx_builder
+        .input(CellInput::new(previous_out_point, 0))
+        .build()
+}
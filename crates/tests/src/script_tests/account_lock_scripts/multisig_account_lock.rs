@@ -0,0 +1,152 @@
+use super::*;
+use crate::script_tests::utils::layer1::*;
+use ckb_crypto::secp::{Generator, Privkey, Pubkey};
+use ckb_error::assert_error_eq;
+use ckb_hash::blake2b_256;
+use ckb_script::{ScriptError, TransactionScriptsVerifier};
+use ckb_types::{bytes::Bytes, packed::WitnessArgs, prelude::*};
+use gw_generator::account_lock_manage::{
+    multisig::{MultisigArgs, Secp256k1Multisig},
+    LockAlgorithm,
+};
+use gw_generator::builtin_scripts::MULTISIG_ACCOUNT_LOCK;
+use gw_generator::error::LockAlgorithmError;
+use rand::{thread_rng, Rng};
+
+const ERROR_ENCODING: i8 = -2;
+const ERROR_PUBKEY_BLAKE160_HASH: i8 = -31;
+
+fn blake160(pubkey: &Pubkey) -> [u8; 20] {
+    let mut pubkey_hash = [0u8; 20];
+    pubkey_hash.copy_from_slice(&blake2b_256(&pubkey.serialize())[..20]);
+    pubkey_hash
+}
+
+fn sign_message(keys: &[&Privkey], message: [u8; 32]) -> Bytes {
+    let message = ckb_types::H256::from(message);
+    let mut signatures = Vec::new();
+    for key in keys {
+        let sig = key.sign_recoverable(&message).expect("sign");
+        signatures.extend_from_slice(&sig.serialize());
+    }
+    signatures.into()
+}
+
+fn verify_tx(
+    signature: Bytes,
+    lock_args: Bytes,
+    message: [u8; 32],
+) -> Result<u64, ckb_error::Error> {
+    let mut data_loader = DummyDataLoader::new();
+    let tx = gen_tx(
+        &mut data_loader,
+        &MULTISIG_ACCOUNT_LOCK,
+        lock_args,
+        Bytes::from(message.to_vec()),
+    );
+    let tx = tx
+        .as_advanced_builder()
+        .set_witnesses(vec![WitnessArgs::new_builder()
+            .lock(Some(signature).pack())
+            .build()
+            .as_bytes()
+            .pack()])
+        .build();
+    let resolved_tx = build_resolved_tx(&data_loader, &tx);
+    let mut verifier = TransactionScriptsVerifier::new(&resolved_tx, &data_loader);
+    verifier.set_debug_printer(|_script, msg| println!("[script debug] {}", msg));
+    verifier.verify(MAX_CYCLES)
+}
+
+// 2 of 3 multisig
+fn gen_multisig() -> (Vec<Privkey>, Bytes) {
+    let keys: Vec<Privkey> = (0..3).map(|_| Generator::random_privkey()).collect();
+    let args = MultisigArgs {
+        threshold: 2,
+        pubkey_hashes: keys
+            .iter()
+            .map(|key| blake160(&key.pubkey().expect("pubkey")))
+            .collect(),
+    };
+    (keys, args.to_bytes())
+}
+
+#[test]
+fn test_multisig() {
+    let (keys, lock_args) = gen_multisig();
+    let mut rng = thread_rng();
+    let mut message = [0u8; 32];
+    rng.fill(&mut message);
+    for signers in &[[0, 1], [0, 2], [1, 2]] {
+        let signature = sign_message(&[&keys[signers[0]], &keys[signers[1]]], message);
+        verify_tx(signature.clone(), lock_args.clone(), message).expect("pass verification");
+        let valid = Secp256k1Multisig::default()
+            .verify_signature(lock_args.clone(), signature, message.into())
+            .unwrap();
+        assert!(valid);
+    }
+}
+
+#[test]
+fn test_multisig_wrong_signers() {
+    let (keys, lock_args) = gen_multisig();
+    let mut rng = thread_rng();
+    let mut message = [0u8; 32];
+    rng.fill(&mut message);
+    let outsider = Generator::random_privkey();
+    let script_cell_index = 0;
+    // an outsider, a duplicated signer and the wrong order of signers
+    let cases = vec![
+        sign_message(&[&keys[0], &outsider], message),
+        sign_message(&[&keys[1], &keys[1]], message),
+        sign_message(&[&keys[2], &keys[0]], message),
+    ];
+    for signature in cases {
+        assert_error_eq!(
+            verify_tx(signature.clone(), lock_args.clone(), message).unwrap_err(),
+            ScriptError::ValidationFailure(ERROR_PUBKEY_BLAKE160_HASH)
+                .input_lock_script(script_cell_index)
+        );
+        let valid = Secp256k1Multisig::default()
+            .verify_signature(lock_args.clone(), signature, message.into())
+            .unwrap();
+        assert!(!valid);
+    }
+
+    // not enough signatures
+    let signature = sign_message(&[&keys[0]], message);
+    assert_error_eq!(
+        verify_tx(signature.clone(), lock_args.clone(), message).unwrap_err(),
+        ScriptError::ValidationFailure(ERROR_ENCODING).input_lock_script(script_cell_index)
+    );
+    let err = Secp256k1Multisig::default()
+        .verify_signature(lock_args, signature, message.into())
+        .unwrap_err();
+    assert_eq!(err, LockAlgorithmError::InvalidSignature);
+}
+
+#[test]
+fn test_multisig_invalid_args() {
+    let pubkey_hash = [0u8; 20];
+    for (threshold, pubkey_hashes) in &[(0u8, 1usize), (2, 1)] {
+        let args = MultisigArgs {
+            threshold: *threshold,
+            pubkey_hashes: vec![pubkey_hash; *pubkey_hashes],
+        };
+        assert_eq!(
+            MultisigArgs::from_slice(&args.to_bytes()).unwrap_err(),
+            LockAlgorithmError::InvalidLockArgs
+        );
+    }
+    let mut args = MultisigArgs {
+        threshold: 1,
+        pubkey_hashes: vec![pubkey_hash; 2],
+    }
+    .to_bytes()
+    .to_vec();
+    args.pop();
+    assert_eq!(
+        MultisigArgs::from_slice(&args).unwrap_err(),
+        LockAlgorithmError::InvalidLockArgs
+    );
+}
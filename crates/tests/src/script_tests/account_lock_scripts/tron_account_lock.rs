@@ -0,0 +1,100 @@
+use super::eth_account_lock::sha3_pubkey_hash;
+use super::*;
+use crate::script_tests::utils::layer1::*;
+use ckb_crypto::secp::{Generator, Privkey};
+use ckb_error::assert_error_eq;
+use ckb_script::{ScriptError, TransactionScriptsVerifier};
+use ckb_types::{bytes::Bytes, packed::WitnessArgs, prelude::*};
+use gw_generator::account_lock_manage::{secp256k1::Secp256k1Tron, LockAlgorithm};
+use gw_generator::builtin_scripts::TRON_ACCOUNT_LOCK;
+use rand::{thread_rng, Rng};
+use sha3::{Digest, Keccak256};
+
+const ERROR_PUBKEY_BLAKE160_HASH: i8 = -31;
+
+fn sign_message(key: &Privkey, message: [u8; 32]) -> Bytes {
+    // calculate tron signing message
+    let message = {
+        let mut hasher = Keccak256::new();
+        hasher.update("\x19TRON Signed Message:\n32");
+        hasher.update(&message);
+        let buf = hasher.finalize();
+        let mut signing_message = [0u8; 32];
+        signing_message.copy_from_slice(&buf[..]);
+        ckb_types::H256::from(signing_message)
+    };
+    let sig = key.sign_recoverable(&message).expect("sign");
+    let mut signature = [0u8; 65];
+    signature.copy_from_slice(&sig.serialize());
+    // v is 27 or 28
+    signature[64] += 27;
+    signature.to_vec().into()
+}
+
+fn verify_tx(
+    signature: &Bytes,
+    pubkey_hash: Bytes,
+    message: [u8; 32],
+) -> Result<u64, ckb_error::Error> {
+    let mut data_loader = DummyDataLoader::new();
+    let tx = gen_tx(
+        &mut data_loader,
+        &TRON_ACCOUNT_LOCK,
+        pubkey_hash,
+        Bytes::from(message.to_vec()),
+    );
+    let tx = tx
+        .as_advanced_builder()
+        .set_witnesses(vec![WitnessArgs::new_builder()
+            .lock(Some(signature.clone()).pack())
+            .build()
+            .as_bytes()
+            .pack()])
+        .build();
+    let resolved_tx = build_resolved_tx(&data_loader, &tx);
+    let mut verifier = TransactionScriptsVerifier::new(&resolved_tx, &data_loader);
+    verifier.set_debug_printer(|_script, msg| println!("[script debug] {}", msg));
+    verifier.verify(MAX_CYCLES)
+}
+
+#[test]
+fn test_sign_tron_message() {
+    let privkey = Generator::random_privkey();
+    let pubkey = privkey.pubkey().expect("pubkey");
+    let pubkey_hash = sha3_pubkey_hash(&pubkey);
+    let mut rng = thread_rng();
+    let mut message = [0u8; 32];
+    rng.fill(&mut message);
+    let signature = sign_message(&privkey, message);
+    verify_tx(&signature, pubkey_hash.clone(), message).expect("pass verification");
+    let valid = Secp256k1Tron::default()
+        .verify_signature(pubkey_hash, signature, message.into())
+        .unwrap();
+    assert!(valid);
+}
+
+#[test]
+fn test_wrong_tron_signature() {
+    let privkey = Generator::random_privkey();
+    let pubkey = privkey.pubkey().expect("pubkey");
+    let pubkey_hash = sha3_pubkey_hash(&pubkey);
+    let mut rng = thread_rng();
+    let mut message = [0u8; 32];
+    rng.fill(&mut message);
+    let signature = {
+        let mut wrong_message = [0u8; 32];
+        rng.fill(&mut wrong_message);
+        sign_message(&privkey, wrong_message)
+    };
+    let verify_result = verify_tx(&signature, pubkey_hash.clone(), message);
+    let script_cell_index = 0;
+    assert_error_eq!(
+        verify_result.unwrap_err(),
+        ScriptError::ValidationFailure(ERROR_PUBKEY_BLAKE160_HASH)
+            .input_lock_script(script_cell_index)
+    );
+    let valid = Secp256k1Tron::default()
+        .verify_signature(pubkey_hash, signature, message.into())
+        .unwrap();
+    assert!(!valid);
+}
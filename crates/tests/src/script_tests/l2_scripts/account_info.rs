@@ -0,0 +1,128 @@
+use super::{
+    new_block_info, DummyChainStore, ACCOUNT_INFO_PROGRAM, ACCOUNT_INFO_PROGRAM_CODE_HASH,
+};
+use gw_common::{
+    builtins::CKB_SUDT_ACCOUNT_ID,
+    h256_ext::H256Ext,
+    state::{
+        build_account_field_key, build_account_key, build_short_script_hash_to_script_hash_key,
+        State, GW_ACCOUNT_NONCE, SHORT_SCRIPT_HASH_LEN,
+    },
+    H256,
+};
+use gw_generator::{
+    account_lock_manage::AccountLockManage,
+    backend_manage::{Backend, BackendManage},
+    dummy_state::DummyState,
+    error::TransactionError,
+    syscalls::ERROR_NOT_FOUND,
+    traits::StateExt,
+    Generator, RunResult,
+};
+use gw_types::{
+    bytes::Bytes,
+    packed::{RawL2Transaction, Script},
+    prelude::*,
+};
+
+const QUERY_NONCE: u8 = 0;
+const QUERY_BALANCE: u8 = 1;
+const QUERY_SCRIPT_HASH: u8 = 2;
+const QUERY_ACCOUNT_COUNT: u8 = 3;
+
+fn query(
+    tree: &DummyState,
+    contract_id: u32,
+    query: u8,
+    args: &[u8],
+) -> Result<RunResult, TransactionError> {
+    let backend_manage = BackendManage::default();
+    backend_manage.register_backend(Backend::from_binaries(
+        ACCOUNT_INFO_PROGRAM.clone(),
+        ACCOUNT_INFO_PROGRAM.clone(),
+    ));
+    let generator = Generator::new(
+        backend_manage,
+        AccountLockManage::default(),
+        Default::default(),
+        &Default::default(),
+        &Default::default(),
+    )
+    .expect("create generator");
+    let mut query_args = vec![query];
+    query_args.extend_from_slice(args);
+    let raw_tx = RawL2Transaction::new_builder()
+        .from_id(0u32.pack())
+        .to_id(contract_id.pack())
+        .args(Bytes::from(query_args).pack())
+        .build();
+    generator.execute_transaction(&DummyChainStore, tree, &new_block_info(0, 1, 0), &raw_tx)
+}
+
+#[test]
+fn test_account_info_syscalls() {
+    let mut tree = DummyState::default();
+    let contract_id = tree
+        .create_account_from_script(
+            Script::new_builder()
+                .code_hash(ACCOUNT_INFO_PROGRAM_CODE_HASH.pack())
+                .args([0u8; 20].to_vec().pack())
+                .build(),
+        )
+        .expect("create account");
+    let script = Script::new_builder()
+        .code_hash([0u8; 32].pack())
+        .args([1u8; 20].to_vec().pack())
+        .build();
+    let a_id = tree
+        .create_account_from_script(script.clone())
+        .expect("create account");
+    // as if the rollup config enables the short script hash index
+    tree.register_short_script_hash(&script.hash().into())
+        .expect("register short script hash");
+    tree.set_nonce(a_id, 3).expect("set nonce");
+    tree.mint_sudt(CKB_SUDT_ACCOUNT_ID, a_id, 42).expect("mint");
+
+    // nonce
+    let run_result = query(&tree, contract_id, QUERY_NONCE, &a_id.to_le_bytes()).expect("query");
+    assert_eq!(run_result.return_data, 3u32.to_le_bytes().to_vec());
+    let nonce_key = build_account_field_key(a_id, GW_ACCOUNT_NONCE);
+    assert_eq!(
+        run_result.read_values.get(&nonce_key),
+        Some(&H256::from_u32(3))
+    );
+
+    // balance
+    let mut args = CKB_SUDT_ACCOUNT_ID.to_le_bytes().to_vec();
+    args.extend_from_slice(&a_id.to_le_bytes());
+    let run_result = query(&tree, contract_id, QUERY_BALANCE, &args).expect("query");
+    assert_eq!(run_result.return_data, 42u128.to_le_bytes().to_vec());
+    let balance_key = build_account_key(CKB_SUDT_ACCOUNT_ID, H256::from_u32(a_id).as_slice());
+    assert_eq!(
+        run_result.read_values.get(&balance_key),
+        Some(&H256::from_u128(42))
+    );
+
+    // script hash by short script hash
+    let script_hash = script.hash();
+    let short_script_hash = &script_hash[..SHORT_SCRIPT_HASH_LEN];
+    let run_result =
+        query(&tree, contract_id, QUERY_SCRIPT_HASH, short_script_hash).expect("query");
+    assert_eq!(run_result.return_data, script_hash.to_vec());
+    let short_script_hash_key = build_short_script_hash_to_script_hash_key(short_script_hash);
+    assert_eq!(
+        run_result.read_values.get(&short_script_hash_key),
+        Some(&script_hash.into())
+    );
+    let err = query(&tree, contract_id, QUERY_SCRIPT_HASH, &[0u8; 20]).unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InvalidExitCode(ERROR_NOT_FOUND as i8)
+    );
+
+    // account count
+    let run_result = query(&tree, contract_id, QUERY_ACCOUNT_COUNT, &[]).expect("query");
+    let count = tree.get_account_count().expect("count");
+    assert_eq!(run_result.return_data, count.to_le_bytes().to_vec());
+    assert!(run_result.read_account_count);
+}
@@ -1,17 +1,33 @@
-use super::{new_block_info, DummyChainStore, SUM_PROGRAM, SUM_PROGRAM_CODE_HASH};
-use gw_common::H256;
+use super::{
+    new_block_info, DummyChainStore, EXAMPLES_DIR, PROXY_PROGRAM, PROXY_PROGRAM_CODE_HASH,
+    SUM_BIN_NAME, SUM_PROGRAM, SUM_PROGRAM_CODE_HASH,
+};
+use gw_common::{blake2b::new_blake2b, builtins::CKB_SUDT_ACCOUNT_ID, state::State, H256};
+use gw_config::{BackendConfig, GeneratorConfig, GenesisConfig};
 use gw_generator::{
     account_lock_manage::{always_success::AlwaysSuccess, AccountLockManage},
     backend_manage::{Backend, BackendManage},
     dummy_state::DummyState,
+    error::{BackendError, TransactionError},
+    generator::StateTransitionArgs,
+    genesis::build_genesis_from_store,
+    parallel::ParallelExecutor,
+    syscalls::ERROR_NOT_SUPPORTED,
     traits::StateExt,
     Generator,
 };
+use gw_store::{
+    state_db::{StateDBTransaction, StateDBVersion},
+    Store,
+};
+use gw_traits::CodeStore;
 use gw_types::{
     bytes::Bytes,
-    packed::{RawL2Transaction, Script},
+    core::ScriptHashType,
+    packed::{L2Block, L2Transaction, RawL2Block, RawL2Transaction, RollupConfig, Script},
     prelude::*,
 };
+use std::path::PathBuf;
 
 #[test]
 fn test_example_sum() {
@@ -31,7 +47,7 @@ fn test_example_sum() {
 
     // run handle message
     {
-        let mut backend_manage = BackendManage::default();
+        let backend_manage = BackendManage::default();
         // NOTICE in this test we won't need SUM validator
         backend_manage.register_backend(Backend::from_binaries(
             SUM_PROGRAM.clone(),
@@ -40,7 +56,14 @@ fn test_example_sum() {
         let mut account_lock_manage = AccountLockManage::default();
         account_lock_manage
             .register_lock_algorithm(H256::zero(), Box::new(AlwaysSuccess::default()));
-        let generator = Generator::new(backend_manage, account_lock_manage, Default::default());
+        let generator = Generator::new(
+            backend_manage,
+            account_lock_manage,
+            Default::default(),
+            &Default::default(),
+            &Default::default(),
+        )
+        .expect("create generator");
         let mut sum_value = init_value;
         for (number, add_value) in &[(1u64, 7u64), (2u64, 16u64)] {
             let block_info = new_block_info(0, *number, 0);
@@ -59,8 +82,702 @@ fn test_example_sum() {
             };
             sum_value += add_value;
             assert_eq!(return_value, sum_value);
+            assert!(run_result.used_cycles > 0);
             tree.apply_run_result(&run_result).expect("update state");
             println!("result {:?}", run_result);
         }
     }
 }
+
+#[test]
+fn test_example_sum_backend_from_config() {
+    let mut tree = DummyState::default();
+    let chain_view = DummyChainStore;
+    let from_id: u32 = 2;
+
+    let contract_id = tree
+        .create_account_from_script(
+            Script::new_builder()
+                .code_hash(SUM_PROGRAM_CODE_HASH.pack())
+                .args([0u8; 20].to_vec().pack())
+                .build(),
+        )
+        .expect("create account");
+
+    let sum_path: PathBuf = [EXAMPLES_DIR, SUM_BIN_NAME].iter().collect();
+    // NOTICE in this test we won't need SUM validator
+    let config = BackendConfig {
+        validator_path: sum_path.clone(),
+        generator_path: sum_path,
+        validator_code_hash: *SUM_PROGRAM_CODE_HASH,
+        validator_script_type_hash: None,
+    };
+
+    // invalid configs
+    let err = BackendManage::from_config(&[BackendConfig {
+        validator_code_hash: [0u8; 32],
+        ..config.clone()
+    }])
+    .map(|_| ())
+    .unwrap_err();
+    assert_eq!(
+        err,
+        BackendError::CodeHashMismatch {
+            expected: H256::zero(),
+            actual: (*SUM_PROGRAM_CODE_HASH).into(),
+        }
+    );
+    let err = BackendManage::from_config(&[BackendConfig {
+        generator_path: PathBuf::from("not-exists"),
+        ..config.clone()
+    }])
+    .map(|_| ())
+    .unwrap_err();
+    assert!(matches!(err, BackendError::LoadBinary { .. }));
+
+    let generator = Generator::new(
+        BackendManage::default(),
+        AccountLockManage::default(),
+        Default::default(),
+        &Default::default(),
+        &Default::default(),
+    )
+    .expect("create generator");
+    let block_info = new_block_info(0, 1, 0);
+    let raw_tx = RawL2Transaction::new_builder()
+        .from_id(from_id.pack())
+        .to_id(contract_id.pack())
+        .args(Bytes::from(7u64.to_le_bytes().to_vec()).pack())
+        .build();
+    let err = generator
+        .execute_transaction(&chain_view, &tree, &block_info, &raw_tx)
+        .unwrap_err();
+    assert!(matches!(err, TransactionError::BackendNotFound { .. }));
+
+    // register the backend while the generator is running
+    let code_hashes = generator
+        .backend_manage()
+        .load_backends(&[config])
+        .expect("load backends");
+    assert_eq!(code_hashes, vec![(*SUM_PROGRAM_CODE_HASH).into()]);
+    let run_result = generator
+        .execute_transaction(&chain_view, &tree, &block_info, &raw_tx)
+        .expect("execute");
+    assert_eq!(run_result.return_data, 7u64.to_le_bytes().to_vec());
+}
+
+#[test]
+fn test_example_sum_type_hash() {
+    let mut tree = DummyState::default();
+    let chain_view = DummyChainStore;
+    let from_id: u32 = 2;
+    let sum_type_hash = [42u8; 32];
+
+    let backend_manage = BackendManage::default();
+    // NOTICE in this test we won't need SUM validator
+    backend_manage.register_backend(Backend {
+        validator_script_type_hash: Some(sum_type_hash.into()),
+        ..Backend::from_binaries(SUM_PROGRAM.clone(), SUM_PROGRAM.clone())
+    });
+    let generator = Generator::new(
+        backend_manage,
+        AccountLockManage::default(),
+        Default::default(),
+        &Default::default(),
+        &Default::default(),
+    )
+    .expect("create generator");
+    let block_info = new_block_info(0, 1, 0);
+
+    // resolve backends by the data hash and the type hash
+    for (hash_type, code_hash) in &[
+        (ScriptHashType::Data, *SUM_PROGRAM_CODE_HASH),
+        (ScriptHashType::Type, sum_type_hash),
+    ] {
+        let contract_id = tree
+            .create_account_from_script(
+                Script::new_builder()
+                    .code_hash(code_hash.pack())
+                    .hash_type((*hash_type).into())
+                    .args([0u8; 20].to_vec().pack())
+                    .build(),
+            )
+            .expect("create account");
+        let raw_tx = RawL2Transaction::new_builder()
+            .from_id(from_id.pack())
+            .to_id(contract_id.pack())
+            .args(Bytes::from(7u64.to_le_bytes().to_vec()).pack())
+            .build();
+        let run_result = generator
+            .execute_transaction(&chain_view, &tree, &block_info, &raw_tx)
+            .expect("execute");
+        assert_eq!(run_result.return_data, 7u64.to_le_bytes().to_vec());
+    }
+
+    // the hash type must match the code hash
+    let contract_id = tree
+        .create_account_from_script(
+            Script::new_builder()
+                .code_hash(SUM_PROGRAM_CODE_HASH.pack())
+                .hash_type(ScriptHashType::Type.into())
+                .args([1u8; 20].to_vec().pack())
+                .build(),
+        )
+        .expect("create account");
+    let raw_tx = RawL2Transaction::new_builder()
+        .from_id(from_id.pack())
+        .to_id(contract_id.pack())
+        .args(Bytes::from(7u64.to_le_bytes().to_vec()).pack())
+        .build();
+    let err = generator
+        .execute_transaction(&chain_view, &tree, &block_info, &raw_tx)
+        .unwrap_err();
+    assert!(matches!(err, TransactionError::BackendNotFound { .. }));
+}
+
+fn build_parallel_generator(execution_threads: usize) -> Generator {
+    let backend_manage = BackendManage::default();
+    backend_manage.register_backend(Backend::from_binaries(
+        SUM_PROGRAM.clone(),
+        SUM_PROGRAM.clone(),
+    ));
+    Generator::new(
+        backend_manage,
+        AccountLockManage::default(),
+        Default::default(),
+        &Default::default(),
+        &GeneratorConfig {
+            execution_threads,
+            ..Default::default()
+        },
+    )
+    .expect("create generator")
+}
+
+// create the sum contracts and mint CKB to the senders
+fn setup_parallel_state<S: State + CodeStore>(
+    tree: &mut S,
+    contracts_count: u8,
+    senders: &[u32],
+) -> Vec<u32> {
+    let contracts = (0..contracts_count)
+        .map(|i| {
+            tree.create_account_from_script(
+                Script::new_builder()
+                    .code_hash(SUM_PROGRAM_CODE_HASH.pack())
+                    .args([i; 20].to_vec().pack())
+                    .build(),
+            )
+            .expect("create account")
+        })
+        .collect();
+    for sender_id in senders {
+        tree.mint_sudt(CKB_SUDT_ACCOUNT_ID, *sender_id, 1_000_000_000)
+            .expect("mint");
+    }
+    contracts
+}
+
+// the i-th tx calls the contract `to(i)`
+fn build_parallel_txs(
+    senders: &[u32],
+    contracts: &[u32],
+    to: impl Fn(usize) -> usize,
+) -> Vec<L2Transaction> {
+    let mut nonces = vec![0u32; senders.len()];
+    (0..16u64)
+        .map(|i| {
+            let sender_index = i as usize % senders.len();
+            let nonce = nonces[sender_index];
+            nonces[sender_index] += 1;
+            let raw_tx = RawL2Transaction::new_builder()
+                .from_id(senders[sender_index].pack())
+                .to_id(contracts[to(i as usize)].pack())
+                .nonce(nonce.pack())
+                .args(Bytes::from(i.to_le_bytes().to_vec()).pack())
+                .build();
+            L2Transaction::new_builder().raw(raw_tx).build()
+        })
+        .collect()
+}
+
+#[test]
+fn test_example_sum_parallel() {
+    let block_producer_id: u32 = 20;
+    let senders: Vec<u32> = (30..34).collect();
+    let contracts_count = 16;
+    let contracts = setup_parallel_state(&mut DummyState::default(), contracts_count, &senders);
+    // txs to the same contract conflict with each other, txs to different contracts don't
+    let conflicting_txs = build_parallel_txs(&senders, &contracts, |i| i % 3);
+    let independent_txs = build_parallel_txs(&senders, &contracts, |i| i);
+
+    for txs in vec![conflicting_txs, independent_txs] {
+        let l2block = L2Block::new_builder()
+            .raw(
+                RawL2Block::new_builder()
+                    .number(1u64.pack())
+                    .block_producer_id(block_producer_id.pack())
+                    .build(),
+            )
+            .transactions(txs.pack())
+            .build();
+        let mut roots = Vec::new();
+        let mut receipts = Vec::new();
+        for execution_threads in &[1, 4] {
+            let mut tree = DummyState::default();
+            setup_parallel_state(&mut tree, contracts_count, &senders);
+            let generator = build_parallel_generator(*execution_threads);
+            let result = generator
+                .apply_state_transition(
+                    &DummyChainStore,
+                    &mut tree,
+                    StateTransitionArgs {
+                        l2block: l2block.clone(),
+                        deposition_requests: Vec::new(),
+                    },
+                )
+                .expect("apply state transition");
+            roots.push(tree.calculate_root().expect("root"));
+            receipts.push(
+                result
+                    .receipts
+                    .into_iter()
+                    .map(|receipt| receipt.as_bytes())
+                    .collect::<Vec<_>>(),
+            );
+        }
+        assert_eq!(receipts[0].len(), 16);
+        assert_eq!(receipts[0], receipts[1]);
+        assert_eq!(roots[0], roots[1]);
+    }
+}
+
+#[test]
+fn test_example_sum_parallel_touched_keys() {
+    let block_producer_id: u32 = 20;
+    let senders: Vec<u32> = (30..34).collect();
+    let block_info = new_block_info(block_producer_id, 1, 0);
+
+    let mut touched_keys = Vec::new();
+    for execution_threads in &[1, 4] {
+        let store = Store::open_tmp().unwrap();
+        let db = store.begin_transaction();
+        build_genesis_from_store(
+            &db,
+            &GenesisConfig { timestamp: 0 },
+            &RollupConfig::default(),
+        )
+        .expect("genesis");
+        let state_db = StateDBTransaction::from_version(db, StateDBVersion::from_genesis());
+        let mut tree = state_db.account_state_tree().unwrap();
+        let contracts = setup_parallel_state(&mut tree, 3, &senders);
+        let raw_txs: Vec<RawL2Transaction> = build_parallel_txs(&senders, &contracts, |i| i % 3)
+            .into_iter()
+            .map(|tx| tx.raw())
+            .collect();
+        tree.tracker_mut().enable();
+
+        let generator = build_parallel_generator(*execution_threads);
+        let mut executor = ParallelExecutor::new(
+            &generator,
+            &DummyChainStore,
+            &tree,
+            block_info.clone(),
+            raw_txs.clone(),
+        );
+        // the speculative reads are not tracked
+        assert!(tree
+            .tracker_mut()
+            .touched_keys()
+            .expect("tracker")
+            .borrow()
+            .is_empty());
+        for tx_index in 0..raw_txs.len() {
+            let run_result = executor.execute(&tree, tx_index).expect("execute");
+            executor.apply(&mut tree, &run_result).expect("apply");
+        }
+        let keys = tree
+            .tracker_mut()
+            .touched_keys()
+            .expect("tracker")
+            .borrow()
+            .clone();
+        touched_keys.push(keys);
+    }
+    assert!(!touched_keys[0].is_empty());
+    assert_eq!(touched_keys[0], touched_keys[1]);
+}
+
+#[test]
+fn test_example_sum_call() {
+    let mut tree = DummyState::default();
+    let chain_view = DummyChainStore;
+    let from_id: u32 = 2;
+    let contract_id = tree
+        .create_account_from_script(
+            Script::new_builder()
+                .code_hash(SUM_PROGRAM_CODE_HASH.pack())
+                .args([0u8; 20].to_vec().pack())
+                .build(),
+        )
+        .expect("create account");
+    let backend_manage = BackendManage::default();
+    backend_manage.register_backend(Backend::from_binaries(
+        SUM_PROGRAM.clone(),
+        SUM_PROGRAM.clone(),
+    ));
+    let generator = Generator::new(
+        backend_manage,
+        AccountLockManage::default(),
+        Default::default(),
+        &Default::default(),
+        &Default::default(),
+    )
+    .expect("create generator");
+    let block_info = new_block_info(0, 1, 0);
+    let root = tree.calculate_root().expect("root");
+    for _ in 0..2 {
+        let raw_tx = RawL2Transaction::new_builder()
+            .from_id(from_id.pack())
+            .to_id(contract_id.pack())
+            .args(Bytes::from(7u64.to_le_bytes().to_vec()).pack())
+            .build();
+        let call_result = generator
+            .call(&chain_view, &tree, &block_info, &raw_tx)
+            .expect("call");
+        // the writes of the previous call are discarded
+        assert_eq!(call_result.return_data, 7u64.to_le_bytes().to_vec());
+        assert!(call_result.used_cycles > 0);
+    }
+    assert_eq!(tree.calculate_root().expect("root"), root);
+    assert_eq!(tree.get_nonce(from_id).expect("nonce"), 0);
+}
+
+#[test]
+fn test_example_sum_estimate() {
+    let mut tree = DummyState::default();
+    let chain_view = DummyChainStore;
+    let from_id: u32 = 2;
+    let contract_id = tree
+        .create_account_from_script(
+            Script::new_builder()
+                .code_hash(SUM_PROGRAM_CODE_HASH.pack())
+                .args([0u8; 20].to_vec().pack())
+                .build(),
+        )
+        .expect("create account");
+    let backend_manage = BackendManage::default();
+    backend_manage.register_backend(Backend::from_binaries(
+        SUM_PROGRAM.clone(),
+        SUM_PROGRAM.clone(),
+    ));
+    let generator = Generator::new(
+        backend_manage,
+        AccountLockManage::default(),
+        Default::default(),
+        &RollupConfig::new_builder()
+            .fee_per_cycle(2u64.pack())
+            .fee_per_write_byte(3u64.pack())
+            .build(),
+        &Default::default(),
+    )
+    .expect("create generator");
+    let block_info = new_block_info(0, 1, 0);
+    let raw_tx = RawL2Transaction::new_builder()
+        .from_id(from_id.pack())
+        .to_id(contract_id.pack())
+        .args(Bytes::from(7u64.to_le_bytes().to_vec()).pack())
+        .build();
+    let estimation = generator
+        .estimate_transaction(&chain_view, &tree, &block_info, &raw_tx)
+        .expect("estimate");
+    assert!(estimation.used_cycles > 0);
+    assert_eq!(estimation.cycles, estimation.used_cycles);
+    // the counter & the nonce
+    assert_eq!(estimation.write_count, 2);
+    assert_eq!(estimation.write_data_bytes, 0);
+    assert_eq!(
+        estimation.fee,
+        generator.calculate_fee(estimation.used_cycles, estimation.write_bytes)
+    );
+
+    // the tx can be executed with the estimated cycles
+    let run_result = generator
+        .execute_transaction(&chain_view, &tree, &block_info, &raw_tx)
+        .expect("execute");
+    assert_eq!(run_result.used_cycles, estimation.used_cycles);
+
+    // failed txs can't be estimated
+    let raw_tx = raw_tx
+        .as_builder()
+        .args(Bytes::from(vec![0u8; 3]).pack())
+        .build();
+    let err = generator
+        .estimate_transaction(&chain_view, &tree, &block_info, &raw_tx)
+        .unwrap_err();
+    assert!(matches!(err, TransactionError::InvalidExitCode(_)));
+}
+
+#[test]
+fn test_example_sum_exceeded_max_cycles() {
+    let mut tree = DummyState::default();
+    let chain_view = DummyChainStore;
+    let from_id: u32 = 2;
+
+    let contract_id = tree
+        .create_account_from_script(
+            Script::new_builder()
+                .code_hash(SUM_PROGRAM_CODE_HASH.pack())
+                .args([0u8; 20].to_vec().pack())
+                .build(),
+        )
+        .expect("create account");
+
+    let backend_manage = BackendManage::default();
+    backend_manage.register_backend(Backend::from_binaries(
+        SUM_PROGRAM.clone(),
+        SUM_PROGRAM.clone(),
+    ));
+    let account_lock_manage = AccountLockManage::default();
+    let max_cycles = 100u64;
+    let generator = Generator::new(
+        backend_manage,
+        account_lock_manage,
+        Default::default(),
+        &RollupConfig::new_builder()
+            .max_cycles(max_cycles.pack())
+            .build(),
+        &Default::default(),
+    )
+    .expect("create generator");
+    let block_info = new_block_info(0, 1, 0);
+    let raw_tx = RawL2Transaction::new_builder()
+        .from_id(from_id.pack())
+        .to_id(contract_id.pack())
+        .args(Bytes::from(7u64.to_le_bytes().to_vec()).pack())
+        .build();
+    let err = generator
+        .execute_transaction(&chain_view, &tree, &block_info, &raw_tx)
+        .unwrap_err();
+    assert_eq!(err, TransactionError::ExceededMaxCycles { max_cycles });
+}
+
+#[test]
+fn test_example_sum_fee() {
+    let mut tree = DummyState::default();
+    let chain_view = DummyChainStore;
+    let from_id: u32 = 2;
+    let block_producer_id: u32 = 3;
+
+    let contract_id = tree
+        .create_account_from_script(
+            Script::new_builder()
+                .code_hash(SUM_PROGRAM_CODE_HASH.pack())
+                .args([0u8; 20].to_vec().pack())
+                .build(),
+        )
+        .expect("create account");
+
+    let backend_manage = BackendManage::default();
+    backend_manage.register_backend(Backend::from_binaries(
+        SUM_PROGRAM.clone(),
+        SUM_PROGRAM.clone(),
+    ));
+    let account_lock_manage = AccountLockManage::default();
+    let generator = Generator::new(
+        backend_manage,
+        account_lock_manage,
+        Default::default(),
+        &RollupConfig::new_builder()
+            .fee_per_cycle(1u64.pack())
+            .fee_per_write_byte(10u64.pack())
+            .build(),
+        &Default::default(),
+    )
+    .expect("create generator");
+    let block_info = new_block_info(block_producer_id, 1, 0);
+    let raw_tx = RawL2Transaction::new_builder()
+        .from_id(from_id.pack())
+        .to_id(contract_id.pack())
+        .args(Bytes::from(7u64.to_le_bytes().to_vec()).pack())
+        .build();
+    let estimation = generator
+        .estimate_transaction(&chain_view, &tree, &block_info, &raw_tx)
+        .expect("estimate");
+    assert!(estimation.write_bytes > 0);
+    assert_eq!(
+        estimation.fee,
+        estimation.used_cycles as u128 + estimation.write_bytes as u128 * 10
+    );
+
+    // the fee is not charged, the sender has no balance
+    let run_result = generator
+        .execute_transaction(&chain_view, &tree, &block_info, &raw_tx)
+        .expect("execute");
+    assert_eq!(run_result.write_bytes, estimation.write_bytes);
+    tree.apply_run_result(&run_result).expect("update state");
+    assert_eq!(
+        tree.get_sudt_balance(CKB_SUDT_ACCOUNT_ID, from_id).unwrap(),
+        0
+    );
+    assert_eq!(
+        tree.get_sudt_balance(CKB_SUDT_ACCOUNT_ID, block_producer_id)
+            .unwrap(),
+        0
+    );
+}
+
+#[test]
+fn test_example_sum_trace() {
+    let mut tree = DummyState::default();
+    let chain_view = DummyChainStore;
+    let from_id: u32 = 2;
+
+    let contract_id = tree
+        .create_account_from_script(
+            Script::new_builder()
+                .code_hash(SUM_PROGRAM_CODE_HASH.pack())
+                .args([0u8; 20].to_vec().pack())
+                .build(),
+        )
+        .expect("create account");
+
+    let backend_manage = BackendManage::default();
+    backend_manage.register_backend(Backend::from_binaries(
+        SUM_PROGRAM.clone(),
+        SUM_PROGRAM.clone(),
+    ));
+    let account_lock_manage = AccountLockManage::default();
+    let generator = Generator::new(
+        backend_manage,
+        account_lock_manage,
+        Default::default(),
+        &Default::default(),
+        &Default::default(),
+    )
+    .expect("create generator");
+    let block_info = new_block_info(0, 1, 0);
+    let raw_tx = RawL2Transaction::new_builder()
+        .from_id(from_id.pack())
+        .to_id(contract_id.pack())
+        .args(Bytes::from(7u64.to_le_bytes().to_vec()).pack())
+        .build();
+    let (result, trace) =
+        generator.execute_transaction_with_trace(&chain_view, &tree, &block_info, &raw_tx);
+    let run_result = result.expect("execute");
+    assert_eq!(trace.exit_code, Some(0));
+    assert_eq!(trace.used_cycles, run_result.used_cycles);
+    assert_eq!(
+        trace.syscalls.iter().map(|s| s.cycles_delta).sum::<u64>(),
+        trace.syscalls.last().expect("syscalls").cycles
+    );
+    let store = trace
+        .syscalls
+        .iter()
+        .find(|syscall| syscall.name == "store")
+        .expect("store syscall");
+    assert_eq!(store.return_code, Some(0));
+    assert_eq!(store.writes.len(), 1);
+    let (key, value) = store.writes[0];
+    assert_eq!(run_result.write_values.get(&key), Some(&value));
+    let set_return_data = trace
+        .syscalls
+        .iter()
+        .find(|syscall| syscall.name == "set_return_data")
+        .expect("set return data syscall");
+    assert_eq!(set_return_data.output, run_result.return_data);
+}
+
+pub(super) fn proxy_args(propagate_failure: bool, to_id: u32, callee_args: &[u8]) -> Vec<u8> {
+    let mut args = vec![propagate_failure as u8];
+    args.extend_from_slice(&to_id.to_le_bytes());
+    args.extend_from_slice(callee_args);
+    args
+}
+
+fn proxy_calls(tree: &DummyState, proxy_id: u32) -> u64 {
+    let mut key = [0u8; 32];
+    let mut hasher = new_blake2b();
+    hasher.update(b"calls");
+    hasher.finalize(&mut key);
+    let value = tree.get_value(proxy_id, &key.into()).expect("get value");
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(&value.as_slice()[..8]);
+    u64::from_le_bytes(buf)
+}
+
+#[test]
+fn test_example_call() {
+    let mut tree = DummyState::default();
+    let chain_view = DummyChainStore;
+    let from_id: u32 = 2;
+
+    let sum_id = tree
+        .create_account_from_script(
+            Script::new_builder()
+                .code_hash(SUM_PROGRAM_CODE_HASH.pack())
+                .args([0u8; 20].to_vec().pack())
+                .build(),
+        )
+        .expect("create account");
+    let proxy_id = tree
+        .create_account_from_script(
+            Script::new_builder()
+                .code_hash(PROXY_PROGRAM_CODE_HASH.pack())
+                .args([0u8; 20].to_vec().pack())
+                .build(),
+        )
+        .expect("create account");
+
+    let backend_manage = BackendManage::default();
+    backend_manage.register_backend(Backend::from_binaries(
+        SUM_PROGRAM.clone(),
+        SUM_PROGRAM.clone(),
+    ));
+    backend_manage.register_backend(Backend::from_binaries(
+        PROXY_PROGRAM.clone(),
+        PROXY_PROGRAM.clone(),
+    ));
+    let generator = Generator::new(
+        backend_manage,
+        AccountLockManage::default(),
+        Default::default(),
+        &Default::default(),
+        &Default::default(),
+    )
+    .expect("create generator");
+    let block_info = new_block_info(0, 1, 0);
+    let execute = |tree: &DummyState, args: Vec<u8>| {
+        let raw_tx = RawL2Transaction::new_builder()
+            .from_id(from_id.pack())
+            .to_id(proxy_id.pack())
+            .args(Bytes::from(args).pack())
+            .build();
+        generator.execute_transaction(&chain_view, tree, &block_info, &raw_tx)
+    };
+
+    // nested calls are not supported, the callee is not executed
+    let run_result =
+        execute(&tree, proxy_args(false, sum_id, &7u64.to_le_bytes())).expect("execute");
+    assert_eq!(run_result.return_data, vec![ERROR_NOT_SUPPORTED]);
+    tree.apply_run_result(&run_result).expect("update state");
+    assert_eq!(proxy_calls(&tree, proxy_id), 1);
+    let sum_result = {
+        let raw_tx = RawL2Transaction::new_builder()
+            .from_id(from_id.pack())
+            .to_id(sum_id.pack())
+            .args(Bytes::from(0u64.to_le_bytes().to_vec()).pack())
+            .build();
+        generator
+            .execute_transaction(&chain_view, &tree, &block_info, &raw_tx)
+            .expect("execute")
+    };
+    assert_eq!(sum_result.return_data, 0u64.to_le_bytes().to_vec());
+
+    // the caller fails if it propagates the error
+    let err = execute(&tree, proxy_args(true, sum_id, &7u64.to_le_bytes())).unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InvalidExitCode(ERROR_NOT_SUPPORTED as i8)
+    );
+}
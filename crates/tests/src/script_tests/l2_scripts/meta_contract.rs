@@ -1,15 +1,22 @@
-use super::{new_block_info, run_contract};
+use super::{new_block_info, run_contract, run_contract_get_result, DummyChainStore};
 use core::panic;
-use gw_common::state::State;
+use gw_common::state::{build_short_script_hash_to_script_hash_key, State, SHORT_SCRIPT_HASH_LEN};
 use gw_common::H256;
 use gw_generator::builtin_scripts::META_CONTRACT_VALIDATOR_CODE_HASH;
 use gw_generator::{
-    dummy_state::DummyState, error::TransactionError, syscalls::ERROR_DUPLICATED_SCRIPT_HASH,
+    account_lock_manage::AccountLockManage,
+    backend_manage::BackendManage,
+    dummy_state::DummyState,
+    error::TransactionError,
+    logs::{parse_log, GwLog},
+    syscalls::{ERROR_DUPLICATED_SCRIPT_HASH, ERROR_UNKNOWN_SCRIPT_CODE_HASH},
     traits::StateExt,
+    Generator,
 };
 use gw_store::Store;
 use gw_types::{
-    packed::{CreateAccount, MetaContractArgs, Script},
+    core::ScriptHashType,
+    packed::{CreateAccount, MetaContractArgs, RawL2Transaction, RollupConfig, Script},
     prelude::*,
 };
 
@@ -52,7 +59,7 @@ fn test_meta_contract() {
                 .build(),
         )
         .build();
-    let return_data = run_contract(
+    let run_result = run_contract_get_result(
         &db,
         &mut tree,
         a_id,
@@ -63,10 +70,18 @@ fn test_meta_contract() {
     .expect("execute");
     let account_id = {
         let mut buf = [0u8; 4];
-        buf.copy_from_slice(&return_data);
+        buf.copy_from_slice(&run_result.return_data);
         u32::from_le_bytes(buf)
     };
     assert_ne!(account_id, 0);
+    assert_eq!(run_result.logs.len(), 1);
+    assert_eq!(
+        parse_log(&run_result.logs[0]).expect("parse log"),
+        GwLog::AccountCreated {
+            account_id,
+            script_hash: contract_script.hash().into(),
+        }
+    );
 
     let script_hash = tree.get_script_hash(account_id).expect("get script hash");
     assert_ne!(script_hash, H256::zero(), "script hash must exists");
@@ -137,3 +152,138 @@ fn test_duplicated_script_hash() {
     };
     assert_eq!(err_code, ERROR_DUPLICATED_SCRIPT_HASH as i8);
 }
+
+#[test]
+fn test_create_account_not_allowed() {
+    let mut tree = DummyState::default();
+    // init accounts
+    let meta_contract_id = tree
+        .create_account_from_script(
+            Script::new_builder()
+                .code_hash(Into::<[u8; 32]>::into(META_CONTRACT_VALIDATOR_CODE_HASH.clone()).pack())
+                .args([0u8; 32].to_vec().pack())
+                .build(),
+        )
+        .expect("create account");
+    let a_id = tree
+        .create_account_from_script(
+            Script::new_builder()
+                .code_hash([0u8; 32].pack())
+                .args([0u8; 20].to_vec().pack())
+                .build(),
+        )
+        .expect("create account");
+
+    let contract_type_hash = [42u8; 32];
+    let rollup_config = RollupConfig::new_builder()
+        .allowed_eoa_type_hashes(vec![[1u8; 32].pack()].pack())
+        .allowed_contract_type_hashes(vec![contract_type_hash.pack()].pack())
+        .build();
+    let generator = Generator::new(
+        BackendManage::default(),
+        AccountLockManage::default(),
+        Default::default(),
+        &rollup_config,
+        &Default::default(),
+    )
+    .expect("create generator");
+    let block_info = new_block_info(a_id, 1, 0);
+    let create_account = |script: Script| {
+        let args = MetaContractArgs::new_builder()
+            .set(CreateAccount::new_builder().script(script).build())
+            .build();
+        let raw_tx = RawL2Transaction::new_builder()
+            .from_id(a_id.pack())
+            .to_id(meta_contract_id.pack())
+            .args(args.as_bytes().pack())
+            .build();
+        generator.execute_transaction(&DummyChainStore, &tree, &block_info, &raw_tx)
+    };
+
+    // the script of the new account must be allowed
+    let err = create_account(
+        Script::new_builder()
+            .code_hash(contract_type_hash.pack())
+            .args(vec![42].pack())
+            .build(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InvalidExitCode(ERROR_UNKNOWN_SCRIPT_CODE_HASH as i8)
+    );
+
+    let run_result = create_account(
+        Script::new_builder()
+            .code_hash(contract_type_hash.pack())
+            .hash_type(ScriptHashType::Type.into())
+            .args(vec![42].pack())
+            .build(),
+    )
+    .expect("create account");
+    assert_eq!(run_result.logs.len(), 1);
+}
+
+#[test]
+fn test_create_account_short_script_hash_index() {
+    let mut tree = DummyState::default();
+    // init accounts
+    let meta_contract_id = tree
+        .create_account_from_script(
+            Script::new_builder()
+                .code_hash(Into::<[u8; 32]>::into(META_CONTRACT_VALIDATOR_CODE_HASH.clone()).pack())
+                .args([0u8; 32].to_vec().pack())
+                .build(),
+        )
+        .expect("create account");
+    let a_id = tree
+        .create_account_from_script(
+            Script::new_builder()
+                .code_hash([0u8; 32].pack())
+                .args([0u8; 20].to_vec().pack())
+                .build(),
+        )
+        .expect("create account");
+
+    let contract_type_hash = [42u8; 32];
+    let script = Script::new_builder()
+        .code_hash(contract_type_hash.pack())
+        .hash_type(ScriptHashType::Type.into())
+        .args(vec![42].pack())
+        .build();
+    let short_script_hash_key =
+        build_short_script_hash_to_script_hash_key(&script.hash()[..SHORT_SCRIPT_HASH_LEN]);
+    let args = MetaContractArgs::new_builder()
+        .set(CreateAccount::new_builder().script(script).build())
+        .build();
+    let raw_tx = RawL2Transaction::new_builder()
+        .from_id(a_id.pack())
+        .to_id(meta_contract_id.pack())
+        .args(args.as_bytes().pack())
+        .build();
+    let block_info = new_block_info(a_id, 1, 0);
+    let create_account = |short_script_hash_index: u8| {
+        let rollup_config = RollupConfig::new_builder()
+            .allowed_contract_type_hashes(vec![contract_type_hash.pack()].pack())
+            .short_script_hash_index(short_script_hash_index.into())
+            .build();
+        let generator = Generator::new(
+            BackendManage::default(),
+            AccountLockManage::default(),
+            Default::default(),
+            &rollup_config,
+            &Default::default(),
+        )
+        .expect("create generator");
+        generator
+            .execute_transaction(&DummyChainStore, &tree, &block_info, &raw_tx)
+            .expect("create account")
+    };
+
+    // the short script hash is not indexed by default
+    let run_result = create_account(0);
+    assert!(!run_result.write_values.contains_key(&short_script_hash_key));
+
+    let run_result = create_account(1);
+    assert!(run_result.write_values.contains_key(&short_script_hash_key));
+}
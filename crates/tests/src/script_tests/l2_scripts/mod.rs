@@ -2,7 +2,7 @@ use gw_common::blake2b::new_blake2b;
 use gw_common::state::State;
 use gw_common::H256;
 use gw_generator::backend_manage::BackendManage;
-use gw_generator::{account_lock_manage::AccountLockManage, Generator};
+use gw_generator::{account_lock_manage::AccountLockManage, Generator, RunResult};
 use gw_generator::{error::TransactionError, traits::StateExt};
 use gw_store::transaction::StoreTransaction;
 use gw_traits::{ChainStore, CodeStore};
@@ -11,12 +11,19 @@ use gw_types::{bytes::Bytes, packed::BlockInfo, prelude::*};
 use lazy_static::lazy_static;
 use std::{fs, io::Read, path::PathBuf};
 
+mod account_info;
 mod examples;
 mod meta_contract;
+mod preimage_index;
 mod sudt;
+mod transfer;
+mod vm_engine;
 
 const EXAMPLES_DIR: &'static str = "../../c/build/examples";
 const SUM_BIN_NAME: &'static str = "sum-generator";
+const PROXY_BIN_NAME: &'static str = "proxy-generator";
+const ACCOUNT_INFO_BIN_NAME: &'static str = "account-info-generator";
+const TRANSFER_BIN_NAME: &'static str = "transfer-generator";
 
 lazy_static! {
     static ref SUM_PROGRAM: Bytes = {
@@ -35,6 +42,54 @@ lazy_static! {
         hasher.finalize(&mut buf);
         buf
     };
+    static ref PROXY_PROGRAM: Bytes = {
+        let mut buf = Vec::new();
+        let mut path = PathBuf::new();
+        path.push(&EXAMPLES_DIR);
+        path.push(&PROXY_BIN_NAME);
+        let mut f = fs::File::open(&path).expect("load program");
+        f.read_to_end(&mut buf).expect("read program");
+        Bytes::from(buf.to_vec())
+    };
+    static ref PROXY_PROGRAM_CODE_HASH: [u8; 32] = {
+        let mut buf = [0u8; 32];
+        let mut hasher = new_blake2b();
+        hasher.update(&PROXY_PROGRAM);
+        hasher.finalize(&mut buf);
+        buf
+    };
+    static ref ACCOUNT_INFO_PROGRAM: Bytes = {
+        let mut buf = Vec::new();
+        let mut path = PathBuf::new();
+        path.push(&EXAMPLES_DIR);
+        path.push(&ACCOUNT_INFO_BIN_NAME);
+        let mut f = fs::File::open(&path).expect("load program");
+        f.read_to_end(&mut buf).expect("read program");
+        Bytes::from(buf.to_vec())
+    };
+    static ref ACCOUNT_INFO_PROGRAM_CODE_HASH: [u8; 32] = {
+        let mut buf = [0u8; 32];
+        let mut hasher = new_blake2b();
+        hasher.update(&ACCOUNT_INFO_PROGRAM);
+        hasher.finalize(&mut buf);
+        buf
+    };
+    static ref TRANSFER_PROGRAM: Bytes = {
+        let mut buf = Vec::new();
+        let mut path = PathBuf::new();
+        path.push(&EXAMPLES_DIR);
+        path.push(&TRANSFER_BIN_NAME);
+        let mut f = fs::File::open(&path).expect("load program");
+        f.read_to_end(&mut buf).expect("read program");
+        Bytes::from(buf.to_vec())
+    };
+    static ref TRANSFER_PROGRAM_CODE_HASH: [u8; 32] = {
+        let mut buf = [0u8; 32];
+        let mut hasher = new_blake2b();
+        hasher.update(&TRANSFER_PROGRAM);
+        hasher.finalize(&mut buf);
+        buf
+    };
 }
 
 pub fn new_block_info(block_producer_id: u32, number: u64, timestamp: u64) -> BlockInfo {
@@ -53,13 +108,25 @@ impl ChainStore for DummyChainStore {
 }
 
 pub fn run_contract<S: State + CodeStore>(
-    _db: &StoreTransaction,
+    db: &StoreTransaction,
     tree: &mut S,
     from_id: u32,
     to_id: u32,
     args: Bytes,
     block_info: &BlockInfo,
 ) -> Result<Vec<u8>, TransactionError> {
+    let run_result = run_contract_get_result(db, tree, from_id, to_id, args, block_info)?;
+    Ok(run_result.return_data)
+}
+
+pub fn run_contract_get_result<S: State + CodeStore>(
+    _db: &StoreTransaction,
+    tree: &mut S,
+    from_id: u32,
+    to_id: u32,
+    args: Bytes,
+    block_info: &BlockInfo,
+) -> Result<RunResult, TransactionError> {
     let raw_tx = RawL2Transaction::new_builder()
         .from_id(from_id.pack())
         .to_id(to_id.pack())
@@ -67,9 +134,16 @@ pub fn run_contract<S: State + CodeStore>(
         .build();
     let backend_manage = BackendManage::default();
     let account_lock_manage = AccountLockManage::default();
-    let generator = Generator::new(backend_manage, account_lock_manage, Default::default());
+    let generator = Generator::new(
+        backend_manage,
+        account_lock_manage,
+        Default::default(),
+        &Default::default(),
+        &Default::default(),
+    )
+    .expect("create generator");
     let chain_view = DummyChainStore;
     let run_result = generator.execute_transaction(&chain_view, tree, block_info, &raw_tx)?;
     tree.apply_run_result(&run_result).expect("update state");
-    Ok(run_result.return_data)
+    Ok(run_result)
 }
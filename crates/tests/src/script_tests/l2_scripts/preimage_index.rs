@@ -0,0 +1,107 @@
+use super::{new_block_info, DummyChainStore, SUM_PROGRAM, SUM_PROGRAM_CODE_HASH};
+use gw_common::{blake2b::new_blake2b, h256_ext::H256Ext, state::State, H256};
+use gw_config::GenesisConfig;
+use gw_generator::{
+    account_lock_manage::AccountLockManage,
+    backend_manage::{Backend, BackendManage},
+    genesis::build_genesis_from_store,
+    traits::StateExt,
+    Generator,
+};
+use gw_store::{
+    state_db::{StateDBTransaction, StateDBVersion},
+    Store,
+};
+use gw_types::{
+    bytes::Bytes,
+    packed::{RawL2Transaction, RollupConfig, Script},
+    prelude::*,
+};
+
+#[test]
+fn test_account_storage_iteration() {
+    let store = Store::open_tmp().unwrap();
+    let db = store.begin_transaction();
+    db.enable_preimage_index().unwrap();
+    build_genesis_from_store(
+        &db,
+        &GenesisConfig { timestamp: 0 },
+        &RollupConfig::default(),
+    )
+    .expect("genesis");
+    db.commit().unwrap();
+
+    let db = store.begin_transaction();
+    assert!(db.is_preimage_index_enabled());
+    let state_db = StateDBTransaction::from_version(db.clone(), StateDBVersion::from_genesis());
+    let mut tree = state_db.account_state_tree().unwrap();
+    let contract_id = tree
+        .create_account_from_script(
+            Script::new_builder()
+                .code_hash(SUM_PROGRAM_CODE_HASH.pack())
+                .args([0u8; 20].to_vec().pack())
+                .build(),
+        )
+        .expect("create account");
+    assert!(tree.get_account_storage(contract_id).unwrap().is_empty());
+
+    // update_value
+    let keys = [H256::from_u32(2), H256::from_u32(1)];
+    for key in &keys {
+        tree.update_value(contract_id, key, H256::from_u32(42))
+            .unwrap();
+    }
+    // the deleted values are skipped
+    tree.update_value(contract_id, &H256::from_u32(3), H256::from_u32(42))
+        .unwrap();
+    tree.update_value(contract_id, &H256::from_u32(3), H256::zero())
+        .unwrap();
+    assert_eq!(
+        tree.get_account_storage(contract_id).unwrap(),
+        vec![
+            (H256::from_u32(1), H256::from_u32(42)),
+            (H256::from_u32(2), H256::from_u32(42))
+        ]
+    );
+
+    // SYS_STORE
+    let backend_manage = BackendManage::default();
+    backend_manage.register_backend(Backend::from_binaries(
+        SUM_PROGRAM.clone(),
+        SUM_PROGRAM.clone(),
+    ));
+    let generator = Generator::new(
+        backend_manage,
+        AccountLockManage::default(),
+        Default::default(),
+        &Default::default(),
+        &Default::default(),
+    )
+    .expect("create generator");
+    let raw_tx = RawL2Transaction::new_builder()
+        .from_id(0u32.pack())
+        .to_id(contract_id.pack())
+        .args(Bytes::from(7u64.to_le_bytes().to_vec()).pack())
+        .build();
+    let run_result = generator
+        .execute_transaction(&DummyChainStore, &tree, &new_block_info(0, 1, 0), &raw_tx)
+        .expect("execute");
+    tree.apply_run_result(&run_result).expect("update state");
+    let counter_key = {
+        let mut key = [0u8; 32];
+        let mut hasher = new_blake2b();
+        hasher.update(b"counter");
+        hasher.finalize(&mut key);
+        H256::from(key)
+    };
+    let storage = tree.get_account_storage(contract_id).unwrap();
+    assert_eq!(storage.len(), 3);
+    assert!(storage.contains(&(counter_key, H256::from_u64(7))));
+
+    // the index is reverted along with the state
+    drop(tree);
+    drop(state_db);
+    drop(db);
+    let db = store.begin_transaction();
+    assert!(db.get_account_storage_keys(contract_id).is_empty());
+}
@@ -1,9 +1,12 @@
-use super::{new_block_info, run_contract};
+use super::{new_block_info, run_contract, run_contract_get_result};
 use gw_common::state::State;
 use gw_common::{h256_ext::H256Ext, H256};
 use gw_generator::dummy_state::DummyState;
 use gw_generator::{
-    builtin_scripts::SUDT_VALIDATOR_CODE_HASH, error::TransactionError, traits::StateExt,
+    builtin_scripts::SUDT_VALIDATOR_CODE_HASH,
+    error::TransactionError,
+    logs::{parse_log, GwLog},
+    traits::StateExt,
 };
 use gw_store::Store;
 use gw_types::{
@@ -103,9 +106,32 @@ fn test_sudt() {
                     .build(),
             )
             .build();
-        let return_data = run_contract(&db, &mut tree, a_id, sudt_id, args.as_bytes(), &block_info)
-            .expect("execute");
-        assert!(return_data.is_empty());
+        let run_result =
+            run_contract_get_result(&db, &mut tree, a_id, sudt_id, args.as_bytes(), &block_info)
+                .expect("execute");
+        assert!(run_result.return_data.is_empty());
+        let logs: Vec<GwLog> = run_result
+            .logs
+            .iter()
+            .map(|log| parse_log(log).expect("parse log"))
+            .collect();
+        assert_eq!(
+            logs,
+            vec![
+                GwLog::SudtTransfer {
+                    sudt_id,
+                    from_id: a_id,
+                    to_id: block_producer_id,
+                    amount: fee,
+                },
+                GwLog::SudtTransfer {
+                    sudt_id,
+                    from_id: a_id,
+                    to_id: b_id,
+                    amount: value,
+                },
+            ]
+        );
 
         {
             let args = SUDTArgs::new_builder()
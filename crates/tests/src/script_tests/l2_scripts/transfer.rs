@@ -0,0 +1,222 @@
+use super::{new_block_info, DummyChainStore, TRANSFER_PROGRAM, TRANSFER_PROGRAM_CODE_HASH};
+use gw_common::{
+    builtins::CKB_SUDT_ACCOUNT_ID,
+    h256_ext::H256Ext,
+    state::{build_sudt_allowance_key, State},
+};
+use gw_generator::{
+    account_lock_manage::AccountLockManage,
+    backend_manage::{Backend, BackendManage},
+    builtin_scripts::SUDT_VALIDATOR_CODE_HASH,
+    dummy_state::DummyState,
+    logs::{parse_log, GwLog},
+    syscalls::{
+        ERROR_ACCOUNT_NOT_EXISTS, ERROR_INSUFFICIENT_BALANCE, ERROR_INVALID_SUDT,
+        ERROR_PERMISSION_DENIED, ERROR_TO_ID, SUCCESS,
+    },
+    traits::StateExt,
+    Generator, RunResult,
+};
+use gw_types::{
+    bytes::Bytes,
+    packed::{RawL2Transaction, SUDTApprove, SUDTArgs, Script},
+    prelude::*,
+};
+
+fn transfer_args((sudt_id, from_id, to_id, amount): (u32, u32, u32, u128)) -> Vec<u8> {
+    let mut args = Vec::new();
+    args.extend_from_slice(&sudt_id.to_le_bytes());
+    args.extend_from_slice(&from_id.to_le_bytes());
+    args.extend_from_slice(&to_id.to_le_bytes());
+    args.extend_from_slice(&amount.to_le_bytes());
+    args
+}
+
+fn transfer(
+    generator: &Generator,
+    tree: &mut DummyState,
+    sender_id: u32,
+    contract_id: u32,
+    transfer: (u32, u32, u32, u128),
+) -> RunResult {
+    execute(
+        generator,
+        tree,
+        sender_id,
+        contract_id,
+        transfer_args(transfer),
+    )
+}
+
+fn approve(
+    generator: &Generator,
+    tree: &mut DummyState,
+    owner_id: u32,
+    sudt_id: u32,
+    spender_id: u32,
+    amount: u128,
+) {
+    let args = SUDTArgs::new_builder()
+        .set(
+            SUDTApprove::new_builder()
+                .spender(spender_id.pack())
+                .amount(amount.pack())
+                .build(),
+        )
+        .build();
+    execute(generator, tree, owner_id, sudt_id, args.as_slice().to_vec());
+}
+
+fn allowance(tree: &DummyState, sudt_id: u32, owner_id: u32, spender_id: u32) -> u128 {
+    tree.get_value(sudt_id, &build_sudt_allowance_key(owner_id, spender_id))
+        .expect("get value")
+        .to_u128()
+}
+
+fn execute(
+    generator: &Generator,
+    tree: &mut DummyState,
+    sender_id: u32,
+    to_id: u32,
+    args: Vec<u8>,
+) -> RunResult {
+    let raw_tx = RawL2Transaction::new_builder()
+        .from_id(sender_id.pack())
+        .to_id(to_id.pack())
+        .nonce(tree.get_nonce(sender_id).expect("nonce").pack())
+        .args(Bytes::from(args).pack())
+        .build();
+    let run_result = generator
+        .execute_transaction(&DummyChainStore, tree, &new_block_info(0, 1, 0), &raw_tx)
+        .expect("execute");
+    tree.apply_run_result(&run_result).expect("update state");
+    run_result
+}
+
+#[test]
+fn test_transfer_sudt() {
+    let mut tree = DummyState::default();
+    tree.create_account_from_script(
+        Script::new_builder()
+            .code_hash([0u8; 32].pack())
+            .args([0u8; 20].to_vec().pack())
+            .build(),
+    )
+    .expect("create account");
+    let ckb_id = tree
+        .create_account_from_script(
+            Script::new_builder()
+                .code_hash(Into::<[u8; 32]>::into(SUDT_VALIDATOR_CODE_HASH.clone()).pack())
+                .args([0u8; 32].to_vec().pack())
+                .build(),
+        )
+        .expect("create account");
+    assert_eq!(ckb_id, CKB_SUDT_ACCOUNT_ID);
+    let contract_id = tree
+        .create_account_from_script(
+            Script::new_builder()
+                .code_hash(TRANSFER_PROGRAM_CODE_HASH.pack())
+                .args([0u8; 20].to_vec().pack())
+                .build(),
+        )
+        .expect("create account");
+    let mut eoa_ids = Vec::new();
+    for i in 1..=2u8 {
+        let id = tree
+            .create_account_from_script(
+                Script::new_builder()
+                    .code_hash([0u8; 32].pack())
+                    .args([i; 20].to_vec().pack())
+                    .build(),
+            )
+            .expect("create account");
+        eoa_ids.push(id);
+    }
+    let (a_id, b_id) = (eoa_ids[0], eoa_ids[1]);
+    tree.mint_sudt(ckb_id, contract_id, 1000).expect("mint");
+    tree.mint_sudt(ckb_id, a_id, 1000).expect("mint");
+
+    let backend_manage = BackendManage::default();
+    backend_manage.register_backend(Backend::from_binaries(
+        TRANSFER_PROGRAM.clone(),
+        TRANSFER_PROGRAM.clone(),
+    ));
+    let generator = Generator::new(
+        backend_manage,
+        AccountLockManage::default(),
+        Default::default(),
+        &Default::default(),
+        &Default::default(),
+    )
+    .expect("create generator");
+
+    // transfer from the contract
+    let run_result = transfer(
+        &generator,
+        &mut tree,
+        a_id,
+        contract_id,
+        (ckb_id, contract_id, b_id, 100),
+    );
+    assert_eq!(run_result.return_data, vec![SUCCESS]);
+    assert_eq!(tree.get_sudt_balance(ckb_id, contract_id).unwrap(), 900);
+    assert_eq!(tree.get_sudt_balance(ckb_id, b_id).unwrap(), 100);
+    assert_eq!(run_result.logs.len(), 1);
+    assert_eq!(
+        parse_log(&run_result.logs[0]).expect("parse log"),
+        GwLog::SudtTransfer {
+            sudt_id: ckb_id,
+            from_id: contract_id,
+            to_id: b_id,
+            amount: 100,
+        }
+    );
+
+    // the contract can't drain the sender without an allowance
+    let run_result = transfer(
+        &generator,
+        &mut tree,
+        a_id,
+        contract_id,
+        (ckb_id, a_id, b_id, 200),
+    );
+    assert_eq!(run_result.return_data, vec![ERROR_PERMISSION_DENIED]);
+    assert!(run_result.logs.is_empty());
+    assert_eq!(tree.get_sudt_balance(ckb_id, a_id).unwrap(), 1000);
+
+    // transfer from the sender consumes the allowance
+    approve(&generator, &mut tree, a_id, ckb_id, contract_id, 300);
+    assert_eq!(allowance(&tree, ckb_id, a_id, contract_id), 300);
+    let run_result = transfer(
+        &generator,
+        &mut tree,
+        a_id,
+        contract_id,
+        (ckb_id, a_id, b_id, 200),
+    );
+    assert_eq!(run_result.return_data, vec![SUCCESS]);
+    assert_eq!(tree.get_sudt_balance(ckb_id, a_id).unwrap(), 800);
+    assert_eq!(tree.get_sudt_balance(ckb_id, b_id).unwrap(), 300);
+    assert_eq!(allowance(&tree, ckb_id, a_id, contract_id), 100);
+
+    // failed transfers change nothing
+    let failures = [
+        // exceeds the allowance
+        ((ckb_id, a_id, b_id, 101), ERROR_PERMISSION_DENIED),
+        // no allowance
+        ((ckb_id, b_id, a_id, 1), ERROR_PERMISSION_DENIED),
+        ((ckb_id, a_id, a_id, 1), ERROR_TO_ID),
+        ((ckb_id, contract_id, b_id, 901), ERROR_INSUFFICIENT_BALANCE),
+        ((contract_id, a_id, b_id, 1), ERROR_INVALID_SUDT),
+        ((ckb_id, contract_id, 100, 1), ERROR_ACCOUNT_NOT_EXISTS),
+    ];
+    for (args, expected_code) in failures.iter() {
+        let run_result = transfer(&generator, &mut tree, a_id, contract_id, *args);
+        assert_eq!(run_result.return_data, vec![*expected_code]);
+        assert!(run_result.logs.is_empty());
+    }
+    assert_eq!(tree.get_sudt_balance(ckb_id, a_id).unwrap(), 800);
+    assert_eq!(tree.get_sudt_balance(ckb_id, b_id).unwrap(), 300);
+    assert_eq!(tree.get_sudt_balance(ckb_id, contract_id).unwrap(), 900);
+    assert_eq!(allowance(&tree, ckb_id, a_id, contract_id), 100);
+}
@@ -0,0 +1,259 @@
+//! Differential tests of the VM engines,
+//! every engine must produce the same results as the `Asm` engine
+
+use super::{
+    examples::proxy_args, new_block_info, DummyChainStore, PROXY_PROGRAM, PROXY_PROGRAM_CODE_HASH,
+    SUM_PROGRAM, SUM_PROGRAM_CODE_HASH,
+};
+use gw_common::{builtins::CKB_SUDT_ACCOUNT_ID, state::State, H256};
+use gw_config::{GeneratorConfig, VMEngine};
+use gw_generator::{
+    account_lock_manage::AccountLockManage,
+    backend_manage::{Backend, BackendManage},
+    builtin_scripts::SUDT_VALIDATOR_CODE_HASH,
+    dummy_state::DummyState,
+    error::TransactionError,
+    trace::ExecutionTrace,
+    traits::StateExt,
+    Generator, RunResult,
+};
+use gw_types::{
+    bytes::Bytes,
+    packed::{RawL2Transaction, RollupConfig, SUDTArgs, SUDTQuery, SUDTTransfer, Script},
+    prelude::*,
+};
+
+const ENGINES: [VMEngine; 3] = [VMEngine::Asm, VMEngine::Interpreter, VMEngine::Instrumented];
+
+type TxOutput = (Result<RunResult, TransactionError>, ExecutionTrace);
+
+// accounts: 0 meta contract, 1 CKB sUDT, 2 sum, 3 ~ 4 proxy, 5 ~ 6 EOA
+fn setup_state() -> DummyState {
+    let mut tree = DummyState::default();
+    tree.create_account_from_script(
+        Script::new_builder()
+            .code_hash([0u8; 32].pack())
+            .args([0u8; 20].to_vec().pack())
+            .build(),
+    )
+    .expect("create account");
+    tree.create_account_from_script(
+        Script::new_builder()
+            .code_hash(Into::<[u8; 32]>::into(SUDT_VALIDATOR_CODE_HASH.clone()).pack())
+            .args([0u8; 32].to_vec().pack())
+            .build(),
+    )
+    .expect("create account");
+    tree.create_account_from_script(
+        Script::new_builder()
+            .code_hash(SUM_PROGRAM_CODE_HASH.pack())
+            .args([0u8; 20].to_vec().pack())
+            .build(),
+    )
+    .expect("create account");
+    for i in 0..2u8 {
+        tree.create_account_from_script(
+            Script::new_builder()
+                .code_hash(PROXY_PROGRAM_CODE_HASH.pack())
+                .args([i; 20].to_vec().pack())
+                .build(),
+        )
+        .expect("create account");
+    }
+    for i in 0..2u8 {
+        let id = tree
+            .create_account_from_script(
+                Script::new_builder()
+                    .code_hash([0u8; 32].pack())
+                    .args([i + 1; 20].to_vec().pack())
+                    .build(),
+            )
+            .expect("create account");
+        tree.mint_sudt(CKB_SUDT_ACCOUNT_ID, id, 1_000_000_000)
+            .expect("mint");
+    }
+    tree
+}
+
+// run the txs one by one on each engine, the results of accepted txs are applied
+fn run_on_engines(
+    rollup_config: &RollupConfig,
+    config: GeneratorConfig,
+    raw_txs: &[RawL2Transaction],
+) -> Vec<(Vec<TxOutput>, H256)> {
+    ENGINES
+        .iter()
+        .map(|engine| {
+            let backend_manage = BackendManage::default();
+            backend_manage.register_backend(Backend::from_binaries(
+                SUM_PROGRAM.clone(),
+                SUM_PROGRAM.clone(),
+            ));
+            backend_manage.register_backend(Backend::from_binaries(
+                PROXY_PROGRAM.clone(),
+                PROXY_PROGRAM.clone(),
+            ));
+            let generator = Generator::new(
+                backend_manage,
+                AccountLockManage::default(),
+                Default::default(),
+                rollup_config,
+                &GeneratorConfig {
+                    vm_engine: *engine,
+                    ..config.clone()
+                },
+            )
+            .expect("create generator");
+            let mut tree = setup_state();
+            let block_info = new_block_info(0, 1, 0);
+            let outputs = raw_txs
+                .iter()
+                .map(|raw_tx| {
+                    let (result, trace) = generator.execute_transaction_with_trace(
+                        &DummyChainStore,
+                        &tree,
+                        &block_info,
+                        raw_tx,
+                    );
+                    if let Ok(run_result) = result.as_ref() {
+                        tree.apply_run_result(run_result).expect("update state");
+                    }
+                    (result, trace)
+                })
+                .collect();
+            (outputs, tree.calculate_root().expect("root"))
+        })
+        .collect()
+}
+
+fn assert_same_outputs(outputs: &[(Vec<TxOutput>, H256)]) {
+    let (expected_txs, expected_root) = &outputs[0];
+    for (engine, (txs, root)) in ENGINES.iter().zip(outputs.iter()).skip(1) {
+        assert_eq!(txs.len(), expected_txs.len());
+        for (index, ((result, trace), (expected_result, expected_trace))) in
+            txs.iter().zip(expected_txs.iter()).enumerate()
+        {
+            assert_eq!(result, expected_result, "{:?} tx {}", engine, index);
+            assert_eq!(
+                trace.syscalls, expected_trace.syscalls,
+                "{:?} tx {}",
+                engine, index
+            );
+            assert_eq!(trace.exit_code, expected_trace.exit_code);
+            // the engines check the max cycles at different granularity,
+            // so the cycles are the same only if the VM exits normally
+            if expected_trace.exit_code.is_some() {
+                assert_eq!(trace.used_cycles, expected_trace.used_cycles);
+            }
+        }
+        assert_eq!(root, expected_root, "{:?}", engine);
+    }
+}
+
+fn build_raw_tx(from_id: u32, to_id: u32, nonce: u32, args: Vec<u8>) -> RawL2Transaction {
+    RawL2Transaction::new_builder()
+        .from_id(from_id.pack())
+        .to_id(to_id.pack())
+        .nonce(nonce.pack())
+        .args(Bytes::from(args).pack())
+        .build()
+}
+
+fn sudt_transfer_args(to: u32, amount: u128, fee: u128) -> Vec<u8> {
+    SUDTArgs::new_builder()
+        .set(
+            SUDTTransfer::new_builder()
+                .to(to.pack())
+                .amount(amount.pack())
+                .fee(fee.pack())
+                .build(),
+        )
+        .build()
+        .as_slice()
+        .to_vec()
+}
+
+#[test]
+fn test_vm_engines_differential() {
+    let (sum_id, proxy_ids, a_id, b_id) = (2, [3, 4], 5, 6);
+    let sudt_query_args = SUDTArgs::new_builder()
+        .set(SUDTQuery::new_builder().account_id(b_id.pack()).build())
+        .build()
+        .as_slice()
+        .to_vec();
+    let raw_txs = vec![
+        build_raw_tx(a_id, sum_id, 0, 7u64.to_le_bytes().to_vec()),
+        build_raw_tx(b_id, sum_id, 0, 42u64.to_le_bytes().to_vec()),
+        // invalid args
+        build_raw_tx(a_id, sum_id, 1, vec![0u8; 3]),
+        build_raw_tx(
+            a_id,
+            CKB_SUDT_ACCOUNT_ID,
+            1,
+            sudt_transfer_args(b_id, 1000, 10),
+        ),
+        // insufficient balance
+        build_raw_tx(
+            a_id,
+            CKB_SUDT_ACCOUNT_ID,
+            2,
+            sudt_transfer_args(b_id, 1_000_000_000_000, 10),
+        ),
+        build_raw_tx(b_id, CKB_SUDT_ACCOUNT_ID, 1, sudt_query_args),
+        // nested calls, rejected by both engines
+        build_raw_tx(
+            a_id,
+            proxy_ids[0],
+            2,
+            proxy_args(false, sum_id, &7u64.to_le_bytes()),
+        ),
+        build_raw_tx(
+            b_id,
+            proxy_ids[0],
+            2,
+            proxy_args(false, proxy_ids[1], &proxy_args(true, sum_id, &[0u8; 3])),
+        ),
+    ];
+    let outputs = run_on_engines(&Default::default(), Default::default(), &raw_txs);
+    assert_same_outputs(&outputs);
+    let results: Vec<bool> = outputs[0].0.iter().map(|(r, _)| r.is_ok()).collect();
+    assert_eq!(
+        results,
+        vec![true, true, false, true, false, true, true, true]
+    );
+}
+
+#[test]
+fn test_vm_engines_exceeded_max_cycles() {
+    let raw_txs = vec![build_raw_tx(5, 2, 0, 7u64.to_le_bytes().to_vec())];
+    let max_cycles = 100u64;
+    let outputs = run_on_engines(
+        &RollupConfig::new_builder()
+            .max_cycles(max_cycles.pack())
+            .build(),
+        Default::default(),
+        &raw_txs,
+    );
+    assert_same_outputs(&outputs);
+    assert_eq!(
+        outputs[0].0[0].0,
+        Err(TransactionError::ExceededMaxCycles { max_cycles })
+    );
+}
+
+#[test]
+fn test_vm_engines_coverage() {
+    let raw_txs = vec![build_raw_tx(5, 2, 0, 7u64.to_le_bytes().to_vec())];
+    let outputs = run_on_engines(&Default::default(), Default::default(), &raw_txs);
+    for (engine, (txs, _root)) in ENGINES.iter().zip(outputs.iter()) {
+        let coverage = &txs[0].1.coverage;
+        if *engine == VMEngine::Instrumented {
+            assert!(!coverage.is_empty());
+            // every instruction consumes at least one cycle
+            let executed: u64 = coverage.values().sum();
+            assert!(executed > 0 && executed <= txs[0].1.used_cycles);
+        } else {
+            assert!(coverage.is_empty(), "{:?}", engine);
+        }
+    }
+}
@@ -2,7 +2,6 @@ use super::*;
 use crate::script_tests::utils::layer1::build_simple_tx;
 use crate::testing_tool::chain::{
     apply_block_result, construct_block, setup_chain_with_account_lock_manage,
-    ALWAYS_SUCCESS_CODE_HASH,
 };
 use ckb_types::{
     packed::CellInput,
@@ -59,8 +58,8 @@ fn test_cancel_challenge_via_withdrawal() {
             .args(Pack::pack(&Bytes::from(b"sender".to_vec())))
             .build();
         let receiver_script = Script::new_builder()
-            .code_hash(Pack::pack(&ALWAYS_SUCCESS_CODE_HASH.clone()))
-            .hash_type(ScriptHashType::Data.into())
+            .code_hash(Pack::pack(&eoa_lock_type_hash.clone()))
+            .hash_type(ScriptHashType::Type.into())
             .args(Pack::pack(&Bytes::from(b"receiver".to_vec())))
             .build();
         let deposition_requests = vec![
@@ -74,8 +73,8 @@ fn test_cancel_challenge_via_withdrawal() {
                 .build(),
         ];
         let produce_block_result = {
-            let mem_pool = chain.mem_pool.lock();
-            construct_block(&chain, &mem_pool, deposition_requests.clone()).unwrap()
+            let mut mem_pool = chain.mem_pool.lock();
+            construct_block(&chain, &mut mem_pool, deposition_requests.clone()).unwrap()
         };
         let rollup_cell = gw_types::packed::CellOutput::new_unchecked(rollup_cell.as_bytes());
         apply_block_result(
@@ -98,7 +97,7 @@ fn test_cancel_challenge_via_withdrawal() {
         let produce_block_result = {
             let mut mem_pool = chain.mem_pool.lock();
             mem_pool.push_withdrawal_request(withdrawal).unwrap();
-            construct_block(&chain, &mem_pool, Vec::default()).unwrap()
+            construct_block(&chain, &mut mem_pool, Vec::default()).unwrap()
         };
         apply_block_result(&mut chain, rollup_cell, produce_block_result, vec![]);
         sender_script
@@ -79,8 +79,8 @@ fn test_revert() {
                 .build(),
         ];
         let produce_block_result = {
-            let mem_pool = chain.mem_pool.lock();
-            construct_block(&chain, &mem_pool, deposition_requests.clone()).unwrap()
+            let mut mem_pool = chain.mem_pool.lock();
+            construct_block(&chain, &mut mem_pool, deposition_requests.clone()).unwrap()
         };
         let rollup_cell = gw_types::packed::CellOutput::new_unchecked(rollup_cell.as_bytes());
         apply_block_result(
@@ -125,7 +125,7 @@ fn test_revert() {
                 .build();
             let mut mem_pool = chain.mem_pool.lock();
             mem_pool.push_transaction(tx).unwrap();
-            construct_block(&chain, &mem_pool, Vec::default()).unwrap()
+            construct_block(&chain, &mut mem_pool, Vec::default()).unwrap()
         };
         let prev_block_merkle = chain.local_state.last_global_state().block();
         apply_block_result(&mut chain, rollup_cell, produce_block_result, vec![]);
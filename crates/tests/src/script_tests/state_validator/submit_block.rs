@@ -1,19 +1,30 @@
 use super::*;
 use crate::testing_tool::chain::setup_chain;
 use crate::{script_tests::utils::layer1::build_simple_tx, testing_tool::chain::construct_block};
+use ckb_error::assert_error_eq;
+use ckb_script::ScriptError;
 use ckb_types::{
     packed::CellInput,
     prelude::{Pack as CKBPack, Unpack},
 };
+use gw_common::{
+    merkle_utils::calculate_merkle_root,
+    smt::{Blake2bHasher, CompiledMerkleProof},
+};
 use gw_types::{
     bytes::Bytes,
     core::ScriptHashType,
     packed::{
-        CustodianLockArgs, DepositionLockArgs, RollupAction, RollupActionUnion, RollupConfig,
-        RollupSubmitBlock, Script, StakeLockArgs, WithdrawalLockArgs,
+        Byte32Vec, CustodianLockArgs, DepositionLockArgs, L2Transaction, RollupAction,
+        RollupActionUnion, RollupConfig, RollupSubmitBlock, Script, StakeLockArgs,
+        WithdrawalLockArgs,
     },
+    MAX_SIGNATURE_SIZE,
 };
 
+// Error::InvalidTxsState of the state validator
+const INVALID_TXS_STATE: i8 = 20;
+
 #[test]
 fn test_submit_block() {
     let rollup_type_script = {
@@ -77,8 +88,8 @@ fn test_submit_block() {
     ctx.verify_tx(tx).expect("return success");
     // submit a new block
     let block_result = {
-        let mem_pool = chain.mem_pool.lock();
-        construct_block(&chain, &mem_pool, Vec::default()).unwrap()
+        let mut mem_pool = chain.mem_pool.lock();
+        construct_block(&chain, &mut mem_pool, Vec::default()).unwrap()
     };
     // verify submit block
     let rollup_cell_data = block_result.global_state.as_bytes();
@@ -281,8 +292,8 @@ fn test_check_reverted_cells_in_submit_block() {
     };
     // submit a new block
     let block_result = {
-        let mem_pool = chain.mem_pool.lock();
-        construct_block(&chain, &mem_pool, Vec::default()).unwrap()
+        let mut mem_pool = chain.mem_pool.lock();
+        construct_block(&chain, &mut mem_pool, Vec::default()).unwrap()
     };
     // verify submit block
     let rollup_cell_data = block_result.global_state.as_bytes();
@@ -331,3 +342,145 @@ fn test_check_reverted_cells_in_submit_block() {
     .build();
     ctx.verify_tx(tx).expect("return success");
 }
+
+#[test]
+fn test_submit_block_with_oversized_signature() {
+    let rollup_type_script = {
+        Script::new_builder()
+            .code_hash(Pack::pack(&*STATE_VALIDATOR_CODE_HASH))
+            .hash_type(ScriptHashType::Data.into())
+            .build()
+    };
+    // rollup lock & config
+    let stake_lock_type = build_type_id_script(b"stake_lock_type_id");
+    let stake_script_type_hash: [u8; 32] = stake_lock_type.calc_script_hash().unpack();
+    let rollup_config = RollupConfig::new_builder()
+        .stake_script_type_hash(Pack::pack(&stake_script_type_hash))
+        .build();
+    // setup chain
+    let chain = setup_chain(rollup_type_script.clone(), rollup_config.clone());
+    // deploy scripts
+    let param = CellContextParam {
+        stake_lock_type: stake_lock_type.clone(),
+        ..Default::default()
+    };
+    let mut ctx = CellContext::new(&rollup_config, param);
+    let stake_capacity = 10000_00000000u64;
+    let input_stake_cell = {
+        let cell = build_rollup_locked_cell(
+            &rollup_type_script.hash(),
+            &stake_script_type_hash,
+            stake_capacity,
+            StakeLockArgs::default().as_bytes(),
+        );
+        let out_point = ctx.insert_cell(cell, Bytes::default());
+        CellInput::new_builder().previous_output(out_point).build()
+    };
+    let output_stake_cell = {
+        let lock_args = StakeLockArgs::new_builder()
+            .stake_block_number(Pack::pack(&1))
+            .build();
+        build_rollup_locked_cell(
+            &rollup_type_script.hash(),
+            &stake_script_type_hash,
+            stake_capacity,
+            lock_args.as_bytes(),
+        )
+    };
+    let capacity = 1000_00000000u64;
+    let rollup_cell = build_always_success_cell(capacity, Some(state_validator_script()));
+    let initial_rollup_cell_data = chain.local_state.last_global_state().as_bytes();
+    let block_result = {
+        let mut mem_pool = chain.mem_pool.lock();
+        construct_block(&chain, &mut mem_pool, Vec::default()).unwrap()
+    };
+    // add a tx to the produced empty block and keep the block consistent,
+    // the validator doesn't execute the tx, so the post account root is the prev one
+    let build_block = |signature_size: usize| {
+        let tx = L2Transaction::new_builder()
+            .signature(Pack::pack(&Bytes::from(vec![0u8; signature_size])))
+            .build();
+        let raw_block = block_result.block.raw();
+        let submit_transactions = raw_block.submit_transactions();
+        let submit_transactions = submit_transactions
+            .clone()
+            .as_builder()
+            .tx_witness_root(Pack::pack(
+                &calculate_merkle_root(vec![tx.witness_hash()]).unwrap(),
+            ))
+            .tx_count(Pack::pack(&1u32))
+            .compacted_post_root_list(
+                Byte32Vec::new_builder()
+                    .push(submit_transactions.compacted_prev_root_hash())
+                    .build(),
+            )
+            .build();
+        let block = block_result
+            .block
+            .clone()
+            .as_builder()
+            .raw(
+                raw_block
+                    .as_builder()
+                    .submit_transactions(submit_transactions)
+                    .build(),
+            )
+            .transactions(Pack::pack(&vec![tx]))
+            .build();
+        let block_proof = CompiledMerkleProof(block.block_proof().raw_data().to_vec());
+        let post_block_root: [u8; 32] = block_proof
+            .compute_root::<Blake2bHasher>(vec![(block.smt_key().into(), block.hash().into())])
+            .unwrap()
+            .into();
+        let global_state = block_result.global_state.clone();
+        let global_state = global_state
+            .clone()
+            .as_builder()
+            .block(
+                global_state
+                    .block()
+                    .as_builder()
+                    .merkle_root(Pack::pack(&post_block_root))
+                    .build(),
+            )
+            .tip_block_hash(Pack::pack(&block.hash()))
+            .build();
+        (block, global_state)
+    };
+    let mut verify_block = |signature_size: usize| {
+        let (block, global_state) = build_block(signature_size);
+        let witness = {
+            let rollup_action = RollupAction::new_builder()
+                .set(RollupActionUnion::RollupSubmitBlock(
+                    RollupSubmitBlock::new_builder().block(block).build(),
+                ))
+                .build();
+            ckb_types::packed::WitnessArgs::new_builder()
+                .output_type(CKBPack::pack(&Some(rollup_action.as_bytes())))
+                .build()
+        };
+        let tx = build_simple_tx(
+            &mut ctx.inner,
+            (rollup_cell.clone(), initial_rollup_cell_data.clone()),
+            (rollup_cell.clone(), global_state.as_bytes()),
+        )
+        .as_advanced_builder()
+        .input(input_stake_cell.clone())
+        .output(output_stake_cell.clone())
+        .output_data(CKBPack::pack(&Bytes::default()))
+        .cell_dep(ctx.stake_lock_dep.clone())
+        .cell_dep(ctx.always_success_dep.clone())
+        .cell_dep(ctx.state_validator_dep.clone())
+        .cell_dep(ctx.rollup_config_dep.clone())
+        .witness(CKBPack::pack(&witness.as_bytes()))
+        .build();
+        ctx.verify_tx(tx)
+    };
+
+    verify_block(MAX_SIGNATURE_SIZE).expect("max size signature");
+    let err = verify_block(MAX_SIGNATURE_SIZE + 1).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(INVALID_TXS_STATE).input_type_script(0)
+    );
+}
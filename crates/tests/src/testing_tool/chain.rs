@@ -1,7 +1,7 @@
 use gw_block_producer::block_producer::{produce_block, ProduceBlockParam, ProduceBlockResult};
 use gw_chain::chain::{Chain, L1Action, L1ActionContext, SyncEvent, SyncParam};
 use gw_common::blake2b::new_blake2b;
-use gw_config::{ChainConfig, GenesisConfig};
+use gw_config::{ChainConfig, GeneratorConfig, GenesisConfig, MemPoolConfig};
 use gw_generator::{
     account_lock_manage::{always_success::AlwaysSuccess, AccountLockManage},
     backend_manage::BackendManage,
@@ -67,11 +67,16 @@ pub fn setup_chain_with_account_lock_manage(
         rollup_config: rollup_config.clone(),
     };
     let rollup_script_hash = config.rollup_type_script.hash().into();
-    let generator = Arc::new(Generator::new(
-        backend_manage,
-        account_lock_manage,
-        rollup_script_hash,
-    ));
+    let generator = Arc::new(
+        Generator::new(
+            backend_manage,
+            account_lock_manage,
+            rollup_script_hash,
+            &rollup_config,
+            &GeneratorConfig::default(),
+        )
+        .expect("create generator"),
+    );
     init_genesis(
         &store,
         &genesis_config,
@@ -80,7 +85,13 @@ pub fn setup_chain_with_account_lock_manage(
         rollup_script_hash,
     )
     .unwrap();
-    let mem_pool = MemPool::create(store.clone(), Arc::clone(&generator)).unwrap();
+    let mem_pool = MemPool::create(
+        store.clone(),
+        Arc::clone(&generator),
+        &MemPoolConfig::default(),
+        &rollup_config,
+    )
+    .unwrap();
     Chain::create(config, store, generator, Arc::new(Mutex::new(mem_pool))).unwrap()
 }
 
@@ -135,8 +146,17 @@ pub fn apply_block_result(
 
 pub fn construct_block(
     chain: &Chain,
-    mem_pool: &MemPool,
+    mem_pool: &mut MemPool,
+    deposition_requests: Vec<DepositionRequest>,
+) -> anyhow::Result<ProduceBlockResult> {
+    construct_block_with_max_cycles(chain, mem_pool, deposition_requests, std::u64::MAX)
+}
+
+pub fn construct_block_with_max_cycles(
+    chain: &Chain,
+    mem_pool: &mut MemPool,
     deposition_requests: Vec<DepositionRequest>,
+    max_block_cycles: u64,
 ) -> anyhow::Result<ProduceBlockResult> {
     let block_producer_id = 0u32;
     let timestamp = 0;
@@ -146,16 +166,7 @@ pub fn construct_block(
     let parent_block = chain.store().get_tip_block().unwrap();
     let rollup_config = chain.rollup_config();
     let rollup_config_hash = chain.rollup_config_hash().clone().into();
-    let mut txs = Vec::new();
-    let mut withdrawal_requests = Vec::new();
-    for (_, entry) in mem_pool.pending() {
-        // notice we either choice txs or withdrawals from an entry to avoid nonce conflict
-        if !entry.txs.is_empty() {
-            txs.extend(entry.txs.iter().cloned());
-        } else if !entry.withdrawals.is_empty() {
-            withdrawal_requests.extend(entry.withdrawals.iter().cloned());
-        }
-    }
+    let (txs, withdrawal_requests) = mem_pool.package()?;
 
     let param = ProduceBlockParam {
         db,
@@ -169,6 +180,13 @@ pub fn construct_block(
         rollup_config,
         rollup_config_hash: &rollup_config_hash,
         max_withdrawal_capacity,
+        max_block_cycles,
     };
-    produce_block(param)
+    let block_result = produce_block(param)?;
+    // the unused ones are picked again by the next blocks if they are kept in the mem pool
+    mem_pool.discard_unused(
+        &block_result.unused_transactions,
+        &block_result.unused_withdrawal_requests,
+    )?;
+    Ok(block_result)
 }
@@ -0,0 +1,140 @@
+use gw_common::{builtins::CKB_SUDT_ACCOUNT_ID, state::State, H256};
+use gw_generator::{
+    account_lock_manage::{always_success::AlwaysSuccess, AccountLockManage},
+    backend_manage::BackendManage,
+    dummy_state::DummyState,
+    error::{AccountError, Error, TransactionValidateError},
+    traits::StateExt,
+    Generator,
+};
+use gw_types::{
+    bytes::Bytes,
+    core::ScriptHashType,
+    packed::{DepositionRequest, L2Transaction, RawL2Transaction, RollupConfig, Script},
+    prelude::*,
+};
+
+const EOA_TYPE_HASH: [u8; 32] = [1u8; 32];
+const CONTRACT_TYPE_HASH: [u8; 32] = [2u8; 32];
+
+fn build_script(code_hash: [u8; 32], hash_type: ScriptHashType, args: &[u8]) -> Script {
+    Script::new_builder()
+        .code_hash(code_hash.pack())
+        .hash_type(hash_type.into())
+        .args(Bytes::from(args.to_vec()).pack())
+        .build()
+}
+
+fn build_rollup_config() -> RollupConfig {
+    RollupConfig::new_builder()
+        .allowed_eoa_type_hashes(vec![EOA_TYPE_HASH.pack()].pack())
+        .allowed_contract_type_hashes(vec![CONTRACT_TYPE_HASH.pack()].pack())
+        .build()
+}
+
+#[test]
+fn test_deposit_allowed_eoa_scripts() {
+    let rollup_config = build_rollup_config();
+    let mut tree = DummyState::default();
+    let deposit = |script: Script| {
+        DepositionRequest::new_builder()
+            .capacity(100u64.pack())
+            .script(script)
+            .build()
+    };
+
+    // hash type must be Type
+    let script = build_script(EOA_TYPE_HASH, ScriptHashType::Data, b"alice");
+    let err = tree
+        .apply_deposition_request(&rollup_config, &deposit(script.clone()))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Error::Account(AccountError::UnknownEOAScript {
+            script_hash: script.hash().into()
+        })
+    );
+    // contract scripts are not EOA
+    let script = build_script(CONTRACT_TYPE_HASH, ScriptHashType::Type, b"alice");
+    let err = tree
+        .apply_deposition_request(&rollup_config, &deposit(script.clone()))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Error::Account(AccountError::UnknownEOAScript {
+            script_hash: script.hash().into()
+        })
+    );
+
+    let script = build_script(EOA_TYPE_HASH, ScriptHashType::Type, b"alice");
+    tree.apply_deposition_request(&rollup_config, &deposit(script.clone()))
+        .expect("deposit");
+    let id = tree
+        .get_account_id_by_script_hash(&script.hash().into())
+        .unwrap()
+        .expect("account");
+    assert_eq!(tree.get_sudt_balance(CKB_SUDT_ACCOUNT_ID, id).unwrap(), 100);
+}
+
+#[test]
+fn test_transaction_allowed_scripts() {
+    let rollup_config = build_rollup_config();
+    let mut account_lock_manage = AccountLockManage::default();
+    account_lock_manage.register_lock_algorithm(EOA_TYPE_HASH.into(), Box::new(AlwaysSuccess));
+    let generator = Generator::new(
+        BackendManage::default(),
+        account_lock_manage,
+        H256::zero(),
+        &rollup_config,
+        &Default::default(),
+    )
+    .expect("create generator");
+    let mut tree = DummyState::default();
+    let eoa_script = build_script(EOA_TYPE_HASH, ScriptHashType::Type, b"alice");
+    let eoa_id = tree
+        .create_account_from_script(eoa_script.clone())
+        .expect("create account");
+    let contract_id = tree
+        .create_account_from_script(build_script(
+            CONTRACT_TYPE_HASH,
+            ScriptHashType::Type,
+            b"contract",
+        ))
+        .expect("create account");
+    let unknown_script = build_script(CONTRACT_TYPE_HASH, ScriptHashType::Data, b"unknown");
+    let unknown_id = tree
+        .create_account_from_script(unknown_script.clone())
+        .expect("create account");
+    let build_tx = |from_id: u32, to_id: u32| {
+        L2Transaction::new_builder()
+            .raw(
+                RawL2Transaction::new_builder()
+                    .from_id(from_id.pack())
+                    .to_id(to_id.pack())
+                    .build(),
+            )
+            .build()
+    };
+
+    generator
+        .check_transaction_signature(&tree, &build_tx(eoa_id, contract_id))
+        .expect("allowed");
+    // the receiver must be an allowed contract
+    let err = generator
+        .check_transaction_signature(&tree, &build_tx(eoa_id, unknown_id))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionValidateError::Account(AccountError::UnknownContractScript {
+            script_hash: unknown_script.hash().into()
+        })
+    );
+    // the sender must be an allowed EOA
+    let err = generator
+        .check_transaction_signature(&tree, &build_tx(contract_id, eoa_id))
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        TransactionValidateError::Account(AccountError::UnknownEOAScript { .. })
+    ));
+}
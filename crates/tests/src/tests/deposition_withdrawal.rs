@@ -1,9 +1,7 @@
 use anyhow::Result;
 use gw_common::{builtins::CKB_SUDT_ACCOUNT_ID, state::State, H256};
-use gw_generator::{
-    error::{DepositionError, WithdrawalError},
-    Error,
-};
+use gw_generator::{error::DepositionError, Error};
+use gw_mem_pool::{status::EntryStatus, Error as MemPoolError};
 use gw_store::state_db::StateDBVersion;
 use gw_types::{
     packed::{CellOutput, DepositionRequest, RawWithdrawalRequest, Script, WithdrawalRequest},
@@ -30,8 +28,8 @@ fn deposite_to_chain(
         .script(user_script)
         .build()];
     let block_result = {
-        let mem_pool = chain.mem_pool.lock();
-        construct_block(chain, &mem_pool, deposition_requests.clone())?
+        let mut mem_pool = chain.mem_pool.lock();
+        construct_block(chain, &mut mem_pool, deposition_requests.clone())?
     };
     // deposit
     apply_block_result(
@@ -63,7 +61,7 @@ fn withdrawal_from_chain(
     let block_result = {
         let mut mem_pool = chain.mem_pool.lock();
         mem_pool.push_withdrawal_request(withdrawal)?;
-        construct_block(chain, &mem_pool, Vec::default()).unwrap()
+        construct_block(chain, &mut mem_pool, Vec::default()).unwrap()
     };
     // deposit
     apply_block_result(chain, rollup_cell.clone(), block_result, Vec::new());
@@ -212,8 +210,8 @@ fn test_overdraft() {
         0,
     )
     .unwrap_err();
-    let err: Error = err.downcast().unwrap();
-    assert_eq!(err, Error::Withdrawal(WithdrawalError::Overdraft));
+    let err: MemPoolError = err.downcast().unwrap();
+    assert_eq!(err, MemPoolError::InsufficientBalance);
 }
 
 #[test]
@@ -241,3 +239,133 @@ fn test_deposit_faked_ckb() {
     let err: Error = err.downcast().unwrap();
     assert_eq!(err, Error::Deposition(DepositionError::DepositFakedCKB));
 }
+
+#[test]
+fn test_withdrawal_reserved_balance() {
+    let rollup_type_script = Script::default();
+    let mut chain = setup_chain(rollup_type_script.clone(), Default::default());
+    let capacity = 500_00000000;
+    let user_script = Script::new_builder()
+        .code_hash(ALWAYS_SUCCESS_CODE_HASH.pack())
+        .args(vec![42].pack())
+        .build();
+    let user_script_hash: H256 = user_script.hash().into();
+    let rollup_cell = CellOutput::new_builder()
+        .type_(Some(rollup_type_script).pack())
+        .build();
+    // deposit
+    deposite_to_chain(
+        &mut chain,
+        rollup_cell,
+        user_script,
+        capacity,
+        H256::zero(),
+        0,
+    )
+    .unwrap();
+    let build_withdrawal = |capacity: u64, nonce: u32| {
+        let raw = RawWithdrawalRequest::new_builder()
+            .nonce(nonce.pack())
+            .capacity(capacity.pack())
+            .account_script_hash(user_script_hash.pack())
+            .sudt_script_hash(H256::zero().pack())
+            .build();
+        WithdrawalRequest::new_builder().raw(raw).build()
+    };
+    let mut mem_pool = chain.mem_pool.lock();
+    let user_id = {
+        let state = mem_pool.state_db().account_state_tree().unwrap();
+        state
+            .get_account_id_by_script_hash(&user_script_hash)
+            .unwrap()
+            .expect("account exists")
+    };
+    // each withdrawal is affordable on its own, but not both of them
+    mem_pool
+        .push_withdrawal_request(build_withdrawal(300_00000000, 0))
+        .unwrap();
+    let reserved = mem_pool
+        .reserved_balance(user_id)
+        .expect("reserved balance")
+        .get(CKB_SUDT_ACCOUNT_ID);
+    assert_eq!(reserved, 300_00000000);
+    let err = mem_pool
+        .push_withdrawal_request(build_withdrawal(400_00000000, 1))
+        .unwrap_err();
+    assert_eq!(err, MemPoolError::InsufficientBalance);
+    // the rest balance can still be withdrawn
+    mem_pool
+        .push_withdrawal_request(build_withdrawal(200_00000000, 1))
+        .unwrap();
+    let reserved = mem_pool
+        .reserved_balance(user_id)
+        .expect("reserved balance")
+        .get(CKB_SUDT_ACCOUNT_ID);
+    assert_eq!(reserved, capacity as u128);
+    assert_eq!(
+        mem_pool.pending().get(&user_id).unwrap().withdrawals.len(),
+        2
+    );
+
+    // both withdrawals are packaged into the next block
+    let block_result = construct_block(&chain, &mut mem_pool, Vec::new()).unwrap();
+    assert!(block_result.unused_withdrawal_requests.is_empty());
+    assert_eq!(block_result.block.withdrawals().len(), 2);
+}
+
+#[test]
+fn test_withdrawal_status() {
+    let rollup_type_script = Script::default();
+    let mut chain = setup_chain(rollup_type_script.clone(), Default::default());
+    let capacity = 500_00000000;
+    let user_script = Script::new_builder()
+        .code_hash(ALWAYS_SUCCESS_CODE_HASH.pack())
+        .args(vec![42].pack())
+        .build();
+    let user_script_hash: H256 = user_script.hash().into();
+    let rollup_cell = CellOutput::new_builder()
+        .type_(Some(rollup_type_script).pack())
+        .build();
+    // deposit
+    deposite_to_chain(
+        &mut chain,
+        rollup_cell.clone(),
+        user_script,
+        capacity,
+        H256::zero(),
+        0,
+    )
+    .unwrap();
+    // withdrawal
+    let withdrawal = {
+        let raw = RawWithdrawalRequest::new_builder()
+            .capacity(100_00000000u64.pack())
+            .account_script_hash(user_script_hash.pack())
+            .sudt_script_hash(H256::zero().pack())
+            .build();
+        WithdrawalRequest::new_builder().raw(raw).build()
+    };
+    let withdrawal_hash: H256 = withdrawal.hash().into();
+    let block_result = {
+        let mut mem_pool = chain.mem_pool.lock();
+        assert_eq!(mem_pool.withdrawal_status(&withdrawal_hash), None);
+        mem_pool.push_withdrawal_request(withdrawal).unwrap();
+        assert_eq!(
+            mem_pool.withdrawal_status(&withdrawal_hash),
+            Some(&EntryStatus::Pending)
+        );
+        construct_block(&chain, &mut mem_pool, Vec::default()).unwrap()
+    };
+    let block_hash: H256 = block_result.block.hash().into();
+    let block_number: u64 = block_result.block.raw().number().unpack();
+    apply_block_result(&mut chain, rollup_cell, block_result, Vec::new());
+    // the default rollup config has no finality blocks, so the block is finalized immediately
+    let mem_pool = chain.mem_pool.lock();
+    assert_eq!(
+        mem_pool.withdrawal_status(&withdrawal_hash),
+        Some(&EntryStatus::Finalized {
+            block_number,
+            block_hash
+        })
+    );
+}
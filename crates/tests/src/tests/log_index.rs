@@ -0,0 +1,139 @@
+use crate::testing_tool::chain::setup_chain;
+use gw_common::{h256_ext::H256Ext, H256};
+use gw_generator::logs::{build_account_created_log, build_log, build_sudt_transfer_log};
+use gw_store::log_index::LogFilter;
+use gw_types::{
+    bytes::Bytes,
+    core::LogType,
+    packed::{L2Block, L2Transaction, LogItem, RawL2Block, RawL2Transaction, Script, TxReceipt},
+    prelude::*,
+};
+
+fn build_receipt(tx: &L2Transaction, logs: Vec<LogItem>) -> TxReceipt {
+    TxReceipt::new_builder()
+        .tx_witness_hash(tx.witness_hash().pack())
+        .logs(logs.pack())
+        .build()
+}
+
+#[test]
+fn test_log_index() {
+    let chain = setup_chain(Script::default(), Default::default());
+    let db = chain.store().begin_transaction();
+
+    let txs: Vec<L2Transaction> = (0..2u32)
+        .map(|nonce| {
+            L2Transaction::new_builder()
+                .raw(RawL2Transaction::new_builder().nonce(nonce.pack()).build())
+                .build()
+        })
+        .collect();
+    let sudt_id = 1;
+    let contract_id = 5;
+    let topic = H256::from_u32(42);
+    let receipts = vec![
+        build_receipt(
+            &txs[0],
+            vec![
+                build_sudt_transfer_log(sudt_id, 2, 3, 100),
+                build_account_created_log(4, H256::from_u32(4)),
+            ],
+        ),
+        build_receipt(
+            &txs[1],
+            vec![
+                build_log(contract_id, LogType::Contract, &[topic], Bytes::default()),
+                build_sudt_transfer_log(sudt_id, 3, 2, 50),
+            ],
+        ),
+    ];
+    let genesis = db.get_tip_block().expect("genesis");
+    let block = L2Block::new_builder()
+        .raw(
+            RawL2Block::new_builder()
+                .number(1u64.pack())
+                .parent_block_hash(genesis.hash().pack())
+                .build(),
+        )
+        .transactions(txs.clone().pack())
+        .build();
+    db.insert_block(
+        block.clone(),
+        Default::default(),
+        Default::default(),
+        receipts,
+        Vec::new(),
+    )
+    .expect("insert block");
+    db.attach_block(block.clone()).expect("attach block");
+
+    let filter = LogFilter {
+        from_block: 0,
+        to_block: 1,
+        ..Default::default()
+    };
+    // scan all logs
+    let entries = db.get_logs(&filter).expect("get logs");
+    let positions: Vec<(u32, u32)> = entries
+        .iter()
+        .map(|entry| (entry.tx_index, entry.log_index))
+        .collect();
+    assert_eq!(positions, vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+    assert!(entries
+        .iter()
+        .all(|entry| entry.block_number == 1 && entry.block_hash == block.hash().into()));
+    assert_eq!(entries[2].tx_hash, txs[1].hash().into());
+
+    // filter by account id & log type
+    let entries = db
+        .get_logs(&LogFilter {
+            account_id: Some(sudt_id),
+            log_type: Some(LogType::SudtTransfer),
+            ..filter.clone()
+        })
+        .expect("get logs");
+    assert_eq!(entries.len(), 2);
+    assert_eq!(
+        entries[1].log.as_slice(),
+        build_sudt_transfer_log(sudt_id, 3, 2, 50).as_slice()
+    );
+
+    // filter by topic
+    let entries = db
+        .get_logs(&LogFilter {
+            topic: Some(topic),
+            ..filter.clone()
+        })
+        .expect("get logs");
+    assert_eq!(entries.len(), 1);
+    let account_id: u32 = entries[0].log.account_id().unpack();
+    assert_eq!(account_id, contract_id);
+    let entries = db
+        .get_logs(&LogFilter {
+            log_type: Some(LogType::AccountCreated),
+            ..filter.clone()
+        })
+        .expect("get logs");
+    assert_eq!(entries.len(), 1);
+
+    // filter by block range
+    let entries = db
+        .get_logs(&LogFilter {
+            from_block: 2,
+            to_block: 10,
+            account_id: Some(sudt_id),
+            ..Default::default()
+        })
+        .expect("get logs");
+    assert!(entries.is_empty());
+
+    // the index is removed with the detached block
+    db.detach_block(&block).expect("detach block");
+    let entries = db
+        .get_logs(&LogFilter {
+            account_id: Some(sudt_id),
+            ..filter.clone()
+        })
+        .expect("get logs");
+    assert!(entries.is_empty());
+}
@@ -0,0 +1,790 @@
+use crate::testing_tool::chain::{
+    build_sync_tx, construct_block, construct_block_with_max_cycles, setup_chain,
+    ALWAYS_SUCCESS_CODE_HASH,
+};
+use gw_chain::chain::{Chain, L1Action, L1ActionContext, RevertedL1Action, SyncEvent, SyncParam};
+use gw_common::{builtins::CKB_SUDT_ACCOUNT_ID, state::State, H256};
+use gw_config::MemPoolConfig;
+use gw_mem_pool::{
+    policy::PolicyError,
+    pool::{MemPool, ResetReport},
+    status::{DropReason, EntryStatus},
+    Error as MemPoolError,
+};
+use gw_types::{
+    bytes::Bytes,
+    packed::{
+        CellOutput, DepositionRequest, GlobalState, HeaderInfo, L2Block, L2Transaction,
+        RawL2Transaction, RawWithdrawalRequest, SUDTArgs, SUDTArgsUnion, SUDTTransfer, Script,
+        WithdrawalRequest, WitnessArgs,
+    },
+    prelude::*,
+    MAX_SIGNATURE_SIZE,
+};
+use std::{sync::Arc, time::Duration};
+
+fn user_script(id: u8) -> Script {
+    Script::new_builder()
+        .code_hash(ALWAYS_SUCCESS_CODE_HASH.pack())
+        .args(vec![id].pack())
+        .build()
+}
+
+fn deposit(script: Script, capacity: u64) -> DepositionRequest {
+    DepositionRequest::new_builder()
+        .capacity(capacity.pack())
+        .sudt_script_hash(H256::zero().pack())
+        .script(script)
+        .build()
+}
+
+fn withdrawal(account_script: &Script, capacity: u64, nonce: u32) -> WithdrawalRequest {
+    let raw = RawWithdrawalRequest::new_builder()
+        .nonce(nonce.pack())
+        .capacity(capacity.pack())
+        .account_script_hash(account_script.hash().pack())
+        .sudt_script_hash(H256::zero().pack())
+        .build();
+    WithdrawalRequest::new_builder().raw(raw).build()
+}
+
+fn transfer(from_id: u32, to_id: u32, amount: u128, fee: u128, nonce: u32) -> L2Transaction {
+    let args = SUDTArgs::new_builder()
+        .set(SUDTArgsUnion::SUDTTransfer(
+            SUDTTransfer::new_builder()
+                .amount(amount.pack())
+                .fee(fee.pack())
+                .to(to_id.pack())
+                .build(),
+        ))
+        .build();
+    let raw = RawL2Transaction::new_builder()
+        .from_id(from_id.pack())
+        .to_id(CKB_SUDT_ACCOUNT_ID.pack())
+        .nonce(nonce.pack())
+        .args(args.as_bytes().pack())
+        .build();
+    L2Transaction::new_builder().raw(raw).build()
+}
+
+fn rollup_cell() -> CellOutput {
+    CellOutput::new_builder()
+        .type_(Some(Script::default()).pack())
+        .build()
+}
+
+// produce a block from the mem pool of the chain, the block is not synced
+fn produce_action(chain: &Chain, deposits: Vec<DepositionRequest>, number: u64) -> L1Action {
+    let block_result = {
+        let mut mem_pool = chain.mem_pool.lock();
+        construct_block(chain, &mut mem_pool, deposits.clone()).unwrap()
+    };
+    L1Action {
+        context: L1ActionContext::SubmitTxs {
+            deposition_requests: deposits,
+        },
+        transaction: build_sync_tx(rollup_cell(), block_result),
+        header_info: HeaderInfo::new_builder().number(number.pack()).build(),
+    }
+}
+
+fn revert_action(action: L1Action) -> RevertedL1Action {
+    let L1Action {
+        transaction,
+        header_info,
+        context,
+    } = action;
+    RevertedL1Action {
+        prev_global_state: GlobalState::default(),
+        transaction,
+        header_info,
+        context,
+    }
+}
+
+fn sync(chain: &mut Chain, updates: Vec<L1Action>, reverts: Vec<RevertedL1Action>) {
+    let event = chain.sync(SyncParam { updates, reverts }).unwrap();
+    assert_eq!(event, SyncEvent::Success);
+}
+
+// the block submitted by the action
+fn block_of(action: &L1Action) -> L2Block {
+    let witness = action.transaction.witnesses().get(0).expect("witness");
+    let witness_args = WitnessArgs::from_slice(&witness.raw_data()).expect("witness args");
+    let block = witness_args.output_type().to_opt().expect("block");
+    L2Block::from_slice(&block.raw_data()).expect("block")
+}
+
+#[test]
+fn test_unused_tx_is_dropped() {
+    let mut chain = setup_chain(Script::default(), Default::default());
+    let alice = user_script(42);
+    let action = produce_action(&chain, vec![deposit(alice.clone(), 500_00000000)], 1);
+    sync(&mut chain, vec![action], Vec::new());
+
+    let alice_id = {
+        let mem_pool = chain.mem_pool.lock();
+        let state = mem_pool.state_db().account_state_tree().unwrap();
+        state
+            .get_account_id_by_script_hash(&alice.hash().into())
+            .unwrap()
+            .expect("alice")
+    };
+    // the tx passes the admission, but the execution fails with the invalid args
+    let tx = {
+        let raw = RawL2Transaction::new_builder()
+            .from_id(alice_id.pack())
+            .to_id(CKB_SUDT_ACCOUNT_ID.pack())
+            .nonce(0u32.pack())
+            .args(vec![42u8].pack())
+            .build();
+        L2Transaction::new_builder().raw(raw).build()
+    };
+    let tx_hash: H256 = tx.hash().into();
+    let mut mem_pool = chain.mem_pool.lock();
+    mem_pool.push_transaction(tx).unwrap();
+    assert_eq!(mem_pool.tx_status(&tx_hash), Some(&EntryStatus::Pending));
+
+    let block_result = construct_block(&chain, &mut mem_pool, Vec::new()).unwrap();
+    assert_eq!(block_result.unused_transactions.len(), 1);
+    // the unused tx is not picked again by the next blocks
+    assert_eq!(
+        mem_pool.tx_status(&tx_hash),
+        Some(&EntryStatus::Dropped(DropReason::Unused))
+    );
+    assert!(mem_pool.pending().get(&alice_id).is_none());
+    let block_result = construct_block(&chain, &mut mem_pool, Vec::new()).unwrap();
+    assert!(block_result.unused_transactions.is_empty());
+    assert_eq!(block_result.block.transactions().len(), 0);
+}
+
+#[test]
+fn test_stale_withdrawal_is_dropped() {
+    let rollup_type_script = Script::default();
+    let mut chain = setup_chain(rollup_type_script.clone(), Default::default());
+    let mut other_chain = setup_chain(rollup_type_script, Default::default());
+    let alice = user_script(42);
+    let action1 = produce_action(&chain, vec![deposit(alice.clone(), 500_00000000)], 1);
+    sync(&mut chain, vec![action1.clone()], Vec::new());
+    sync(&mut other_chain, vec![action1], Vec::new());
+
+    // another withdrawal with the same nonce is packaged by the other block producer
+    let pending_withdrawal = withdrawal(&alice, 100_00000000, 0);
+    let pending_hash: H256 = pending_withdrawal.hash().into();
+    chain
+        .mem_pool
+        .lock()
+        .push_withdrawal_request(pending_withdrawal)
+        .unwrap();
+    let packaged_withdrawal = withdrawal(&alice, 200_00000000, 0);
+    let packaged_hash: H256 = packaged_withdrawal.hash().into();
+    other_chain
+        .mem_pool
+        .lock()
+        .push_withdrawal_request(packaged_withdrawal)
+        .unwrap();
+    let action2 = produce_action(&other_chain, Vec::new(), 2);
+    let block = block_of(&action2);
+    let block_number: u64 = block.raw().number().unpack();
+    let block_hash: H256 = block.hash().into();
+    sync(&mut chain, vec![action2], Vec::new());
+
+    let mem_pool = chain.mem_pool.lock();
+    assert_eq!(
+        mem_pool.withdrawal_status(&pending_hash),
+        Some(&EntryStatus::Dropped(DropReason::Stale))
+    );
+    // the default rollup config has no finality blocks, so the block is finalized immediately
+    assert_eq!(
+        mem_pool.withdrawal_status(&packaged_hash),
+        Some(&EntryStatus::Finalized {
+            block_number,
+            block_hash
+        })
+    );
+    assert!(mem_pool.pending().is_empty());
+    assert!(mem_pool.queue().is_empty());
+}
+
+#[test]
+fn test_unaffordable_withdrawal_is_queued_after_reorg() {
+    let rollup_type_script = Script::default();
+    let mut chain = setup_chain(rollup_type_script.clone(), Default::default());
+    let mut other_chain = setup_chain(rollup_type_script, Default::default());
+    let alice = user_script(42);
+    let bob = user_script(43);
+    let action1 = produce_action(&chain, vec![deposit(alice.clone(), 500_00000000)], 1);
+    sync(&mut chain, vec![action1.clone()], Vec::new());
+    sync(&mut other_chain, vec![action1], Vec::new());
+    // the fork block 2 deposits to bob instead of alice
+    let fork_action2 = produce_action(&other_chain, vec![deposit(bob, 500_00000000)], 2);
+    let action2 = produce_action(&chain, vec![deposit(alice.clone(), 500_00000000)], 2);
+    sync(&mut chain, vec![action2.clone()], Vec::new());
+
+    // affordable with the deposits of block 1 & 2
+    let alice_withdrawal = withdrawal(&alice, 800_00000000, 0);
+    let withdrawal_hash: H256 = alice_withdrawal.hash().into();
+    chain
+        .mem_pool
+        .lock()
+        .push_withdrawal_request(alice_withdrawal)
+        .unwrap();
+    assert_eq!(
+        chain.mem_pool.lock().withdrawal_status(&withdrawal_hash),
+        Some(&EntryStatus::Pending)
+    );
+
+    sync(&mut chain, vec![fork_action2], vec![revert_action(action2)]);
+    let mem_pool = chain.mem_pool.lock();
+    assert_eq!(
+        mem_pool.withdrawal_status(&withdrawal_hash),
+        Some(&EntryStatus::Queued)
+    );
+    assert!(mem_pool.pending().is_empty());
+    assert_eq!(mem_pool.queue().len(), 1);
+}
+
+#[test]
+fn test_reverted_withdrawal_is_reinjected() {
+    let rollup_type_script = Script::default();
+    let mut chain = setup_chain(rollup_type_script.clone(), Default::default());
+    let mut other_chain = setup_chain(rollup_type_script, Default::default());
+    let alice = user_script(42);
+    let bob = user_script(43);
+    let action1 = produce_action(
+        &chain,
+        vec![
+            deposit(alice.clone(), 500_00000000),
+            deposit(bob.clone(), 500_00000000),
+        ],
+        1,
+    );
+    sync(&mut chain, vec![action1.clone()], Vec::new());
+    sync(&mut other_chain, vec![action1], Vec::new());
+
+    // alice's and bob's withdrawals are packaged into block 2
+    let alice_withdrawal = withdrawal(&alice, 100_00000000, 0);
+    let alice_hash: H256 = alice_withdrawal.hash().into();
+    let bob_withdrawal = withdrawal(&bob, 100_00000000, 0);
+    let bob_hash: H256 = bob_withdrawal.hash().into();
+    {
+        let mut mem_pool = chain.mem_pool.lock();
+        mem_pool.push_withdrawal_request(alice_withdrawal).unwrap();
+        mem_pool.push_withdrawal_request(bob_withdrawal).unwrap();
+    }
+    let action2 = produce_action(&chain, Vec::new(), 2);
+    let block = block_of(&action2);
+    let block_number: u64 = block.raw().number().unpack();
+    let block_hash: H256 = block.hash().into();
+    sync(&mut chain, vec![action2.clone()], Vec::new());
+    assert_eq!(
+        chain.mem_pool.lock().withdrawal_status(&alice_hash),
+        Some(&EntryStatus::Finalized {
+            block_number,
+            block_hash
+        })
+    );
+
+    // the fork block 2 consumes the nonce of bob with another withdrawal
+    other_chain
+        .mem_pool
+        .lock()
+        .push_withdrawal_request(withdrawal(&bob, 200_00000000, 0))
+        .unwrap();
+    let fork_action2 = produce_action(&other_chain, Vec::new(), 2);
+
+    sync(&mut chain, vec![fork_action2], vec![revert_action(action2)]);
+    let mem_pool = chain.mem_pool.lock();
+    // alice's withdrawal is re-injected into the pool
+    assert_eq!(
+        mem_pool.withdrawal_status(&alice_hash),
+        Some(&EntryStatus::Pending)
+    );
+    // bob's withdrawal is rejected, since the nonce is consumed by the fork block
+    assert_eq!(
+        mem_pool.withdrawal_status(&bob_hash),
+        Some(&EntryStatus::Dropped(DropReason::Rejected(
+            MemPoolError::nonce(1, 0)
+        )))
+    );
+}
+
+#[test]
+fn test_deep_reorg_reset_report() {
+    let rollup_type_script = Script::default();
+    let mut chain = setup_chain(rollup_type_script.clone(), Default::default());
+    let mut other_chain = setup_chain(rollup_type_script, Default::default());
+    let users: Vec<Script> = (1..=4).map(user_script).collect();
+    let carol = user_script(9);
+    let action1 = produce_action(
+        &chain,
+        users
+            .iter()
+            .map(|user| deposit(user.clone(), 500_00000000))
+            .collect(),
+        1,
+    );
+    sync(&mut chain, vec![action1.clone()], Vec::new());
+    sync(&mut other_chain, vec![action1], Vec::new());
+
+    // block 2 ~ 5, each block packages a withdrawal of a user
+    let withdrawals: Vec<WithdrawalRequest> = users
+        .iter()
+        .map(|user| withdrawal(user, 100_00000000, 0))
+        .collect();
+    let mut actions = Vec::new();
+    for (i, withdrawal) in withdrawals.iter().enumerate() {
+        chain
+            .mem_pool
+            .lock()
+            .push_withdrawal_request(withdrawal.clone())
+            .unwrap();
+        let action = produce_action(&chain, Vec::new(), i as u64 + 2);
+        sync(&mut chain, vec![action.clone()], Vec::new());
+        actions.push(action);
+    }
+    // a mem pool which is notified by the test instead of the chain, to inspect the reset report
+    let mut mem_pool = MemPool::create(
+        chain.store().clone(),
+        Arc::clone(&chain.generator),
+        &MemPoolConfig::default(),
+        chain.rollup_config(),
+    )
+    .unwrap();
+    let tip_block_hash = chain.store().get_tip_block_hash().unwrap();
+    assert_eq!(
+        mem_pool.notify_new_tip(tip_block_hash).unwrap(),
+        ResetReport::default()
+    );
+
+    // the fork block 2 consumes the nonce of the third user with another withdrawal,
+    // and the fork block 6, which is higher than the old tip, packages the withdrawal of the last user
+    let mut fork_actions = Vec::new();
+    for number in 2..=7u64 {
+        match number {
+            2 => other_chain
+                .mem_pool
+                .lock()
+                .push_withdrawal_request(withdrawal(&users[2], 200_00000000, 0))
+                .unwrap(),
+            6 => other_chain
+                .mem_pool
+                .lock()
+                .push_withdrawal_request(withdrawals[3].clone())
+                .unwrap(),
+            _ => {}
+        }
+        let action = produce_action(
+            &other_chain,
+            vec![deposit(carol.clone(), 100_00000000)],
+            number,
+        );
+        sync(&mut other_chain, vec![action.clone()], Vec::new());
+        fork_actions.push(action);
+    }
+    let fork_block6 = block_of(&fork_actions[4]);
+    sync(
+        &mut chain,
+        fork_actions,
+        actions.into_iter().rev().map(revert_action).collect(),
+    );
+    let tip_block = chain.store().get_tip_block().unwrap();
+    let tip_block_number: u64 = tip_block.raw().number().unpack();
+    assert_eq!(tip_block_number, 7);
+
+    // the withdrawals of the 4 reverted blocks are re-injected in the chain order
+    let withdrawal_hashes: Vec<H256> = withdrawals
+        .iter()
+        .map(|withdrawal| withdrawal.hash().into())
+        .collect();
+    let report = mem_pool.notify_new_tip(tip_block.hash().into()).unwrap();
+    assert_eq!(
+        report,
+        ResetReport {
+            reinjected_txs: Vec::new(),
+            reinjected_withdrawals: withdrawal_hashes[..2].to_vec(),
+            dropped_txs: Vec::new(),
+            dropped_withdrawals: vec![(withdrawal_hashes[2], MemPoolError::nonce(1, 0))],
+        }
+    );
+    assert_eq!(
+        mem_pool.withdrawal_status(&withdrawal_hashes[2]),
+        Some(&EntryStatus::Dropped(DropReason::Rejected(
+            MemPoolError::nonce(1, 0)
+        )))
+    );
+    // the withdrawal included by the new chain isn't re-injected
+    assert_eq!(mem_pool.withdrawal_status(&withdrawal_hashes[3]), None);
+    assert_eq!(
+        chain
+            .mem_pool
+            .lock()
+            .withdrawal_status(&withdrawal_hashes[3]),
+        Some(&EntryStatus::Finalized {
+            block_number: 6,
+            block_hash: fork_block6.hash().into(),
+        })
+    );
+}
+
+#[test]
+fn test_reorg_reinjects_all_entries_of_an_account() {
+    let rollup_type_script = Script::default();
+    let mut chain = setup_chain(rollup_type_script.clone(), Default::default());
+    let mut other_chain = setup_chain(rollup_type_script, Default::default());
+    let alice = user_script(1);
+    let bob = user_script(2);
+    let action1 = produce_action(
+        &chain,
+        vec![
+            deposit(alice.clone(), 500_00000000),
+            deposit(bob.clone(), 500_00000000),
+        ],
+        1,
+    );
+    sync(&mut chain, vec![action1.clone()], Vec::new());
+    sync(&mut other_chain, vec![action1], Vec::new());
+    let (alice_id, bob_id) = {
+        let mem_pool = chain.mem_pool.lock();
+        let state = mem_pool.state_db().account_state_tree().unwrap();
+        let get_id = |script: &Script| {
+            state
+                .get_account_id_by_script_hash(&script.hash().into())
+                .unwrap()
+                .expect("account")
+        };
+        (get_id(&alice), get_id(&bob))
+    };
+
+    // block 2 packages a withdrawal and the following txs of alice
+    let alice_withdrawal = withdrawal(&alice, 100_00000000, 0);
+    let txs = vec![
+        transfer(alice_id, bob_id, 10_00000000, 0, 1),
+        transfer(alice_id, bob_id, 20_00000000, 0, 2),
+    ];
+    {
+        let mut mem_pool = chain.mem_pool.lock();
+        mem_pool
+            .push_withdrawal_request(alice_withdrawal.clone())
+            .unwrap();
+        for tx in &txs {
+            mem_pool.push_transaction(tx.clone()).unwrap();
+        }
+    }
+    let action2 = produce_action(&chain, Vec::new(), 2);
+    let block2 = block_of(&action2);
+    assert_eq!(block2.withdrawals().len(), 1);
+    assert_eq!(block2.transactions().len(), 2);
+    sync(&mut chain, vec![action2.clone()], Vec::new());
+    // a mem pool which is notified by the test instead of the chain, to inspect the reset report
+    let mut mem_pool = MemPool::create(
+        chain.store().clone(),
+        Arc::clone(&chain.generator),
+        &MemPoolConfig::default(),
+        chain.rollup_config(),
+    )
+    .unwrap();
+    let tip_block_hash = chain.store().get_tip_block_hash().unwrap();
+    mem_pool.notify_new_tip(tip_block_hash).unwrap();
+
+    // the fork block 2 packages nothing of alice
+    let fork_action = produce_action(&other_chain, Vec::new(), 2);
+    sync(&mut chain, vec![fork_action], vec![revert_action(action2)]);
+
+    // the withdrawal is re-injected before the txs, so all the nonces are accepted
+    let tip_block_hash = chain.store().get_tip_block_hash().unwrap();
+    let report = mem_pool.notify_new_tip(tip_block_hash).unwrap();
+    let tx_hashes: Vec<H256> = txs.iter().map(|tx| tx.hash().into()).collect();
+    assert_eq!(
+        report,
+        ResetReport {
+            reinjected_txs: tx_hashes.clone(),
+            reinjected_withdrawals: vec![alice_withdrawal.hash().into()],
+            dropped_txs: Vec::new(),
+            dropped_withdrawals: Vec::new(),
+        }
+    );
+    for tx_hash in &tx_hashes {
+        assert_eq!(mem_pool.tx_status(tx_hash), Some(&EntryStatus::Pending));
+    }
+}
+
+#[test]
+fn test_admission_policies() {
+    let mut chain = setup_chain(Script::default(), Default::default());
+    let alice = user_script(42);
+    let action = produce_action(&chain, vec![deposit(alice.clone(), 500_00000000)], 1);
+    sync(&mut chain, vec![action], Vec::new());
+    let alice_id = {
+        let mem_pool = chain.mem_pool.lock();
+        let state = mem_pool.state_db().account_state_tree().unwrap();
+        state
+            .get_account_id_by_script_hash(&alice.hash().into())
+            .unwrap()
+            .expect("alice")
+    };
+    let create_mem_pool = |config: &MemPoolConfig| {
+        MemPool::create(
+            chain.store().clone(),
+            Arc::clone(&chain.generator),
+            config,
+            chain.rollup_config(),
+        )
+        .unwrap()
+    };
+
+    // the accounts of a denied lock can't push anything
+    let mut mem_pool = create_mem_pool(&MemPoolConfig {
+        denied_code_hashes: vec![*ALWAYS_SUCCESS_CODE_HASH],
+        ..Default::default()
+    });
+    let err = mem_pool
+        .push_withdrawal_request(withdrawal(&alice, 100_00000000, 0))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        MemPoolError::Policy(PolicyError::Denied {
+            code_hash: (*ALWAYS_SUCCESS_CODE_HASH).into()
+        })
+    );
+
+    // the pending objects of an account are limited
+    let mut mem_pool = create_mem_pool(&MemPoolConfig {
+        max_pending_per_account: 2,
+        ..Default::default()
+    });
+    for (nonce, capacity) in [100_00000000, 150_00000000].iter().enumerate() {
+        mem_pool
+            .push_withdrawal_request(withdrawal(&alice, *capacity, nonce as u32))
+            .unwrap();
+    }
+    let err = mem_pool
+        .push_withdrawal_request(withdrawal(&alice, 120_00000000, 2))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        MemPoolError::Policy(PolicyError::TooManyPending {
+            account_id: alice_id,
+            max: 2
+        })
+    );
+
+    // only the listed locks are allowed
+    let mut mem_pool = create_mem_pool(&MemPoolConfig {
+        allowed_code_hashes: vec![[1u8; 32]],
+        ..Default::default()
+    });
+    let err = mem_pool
+        .push_withdrawal_request(withdrawal(&alice, 100_00000000, 0))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        MemPoolError::Policy(PolicyError::NotAllowed {
+            code_hash: (*ALWAYS_SUCCESS_CODE_HASH).into()
+        })
+    );
+    let mut mem_pool = create_mem_pool(&MemPoolConfig {
+        allowed_code_hashes: vec![*ALWAYS_SUCCESS_CODE_HASH],
+        ..Default::default()
+    });
+    mem_pool
+        .push_withdrawal_request(withdrawal(&alice, 100_00000000, 0))
+        .unwrap();
+
+    // the txs must pay the min fee in CKB to the block producer
+    let mut mem_pool = create_mem_pool(&MemPoolConfig {
+        min_fee: 1000,
+        ..Default::default()
+    });
+    let err = mem_pool
+        .push_transaction(transfer(alice_id, alice_id, 1, 999, 0))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        MemPoolError::Policy(PolicyError::FeeTooLow {
+            min: 1000,
+            actual: 999
+        })
+    );
+    // a tx to a contract other than the CKB sUDT pays no fee
+    let contract_call = {
+        let raw = RawL2Transaction::new_builder()
+            .from_id(alice_id.pack())
+            .to_id(alice_id.pack())
+            .build();
+        L2Transaction::new_builder().raw(raw).build()
+    };
+    let err = mem_pool.push_transaction(contract_call).unwrap_err();
+    assert_eq!(
+        err,
+        MemPoolError::Policy(PolicyError::FeeTooLow {
+            min: 1000,
+            actual: 0
+        })
+    );
+    mem_pool
+        .push_transaction(transfer(alice_id, alice_id, 1, 1000, 0))
+        .unwrap();
+
+    // the objects an account pushes in a window are limited
+    let mut mem_pool = create_mem_pool(&MemPoolConfig {
+        rate_limit_per_account: 1,
+        rate_limit_window_secs: 60,
+        ..Default::default()
+    });
+    mem_pool
+        .push_withdrawal_request(withdrawal(&alice, 100_00000000, 0))
+        .unwrap();
+    let err = mem_pool
+        .push_transaction(transfer(alice_id, alice_id, 1, 0, 1))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        MemPoolError::Policy(PolicyError::RateLimited {
+            account_id: alice_id,
+            limit: 1,
+            window: Duration::from_secs(60)
+        })
+    );
+}
+
+#[test]
+fn test_oversized_signature() {
+    let mut chain = setup_chain(Script::default(), Default::default());
+    let alice = user_script(42);
+    let action = produce_action(&chain, vec![deposit(alice.clone(), 500_00000000)], 1);
+    sync(&mut chain, vec![action], Vec::new());
+    let mut mem_pool = chain.mem_pool.lock();
+    let alice_id = {
+        let state = mem_pool.state_db().account_state_tree().unwrap();
+        state
+            .get_account_id_by_script_hash(&alice.hash().into())
+            .unwrap()
+            .expect("alice")
+    };
+    let oversized_signature = Bytes::from(vec![0u8; MAX_SIGNATURE_SIZE + 1]).pack();
+
+    let tx = transfer(alice_id, alice_id, 1, 0, 0)
+        .as_builder()
+        .signature(oversized_signature.clone())
+        .build();
+    let err = mem_pool.push_transaction(tx).unwrap_err();
+    assert_eq!(
+        err,
+        MemPoolError::SignatureOversize {
+            max_bytes: MAX_SIGNATURE_SIZE,
+            actual_bytes: MAX_SIGNATURE_SIZE + 1
+        }
+    );
+    let request = withdrawal(&alice, 100_00000000, 0)
+        .as_builder()
+        .signature(oversized_signature)
+        .build();
+    let err = mem_pool.push_withdrawal_request(request).unwrap_err();
+    assert_eq!(
+        err,
+        MemPoolError::SignatureOversize {
+            max_bytes: MAX_SIGNATURE_SIZE,
+            actual_bytes: MAX_SIGNATURE_SIZE + 1
+        }
+    );
+
+    // the max size signature is accepted
+    let max_signature = Bytes::from(vec![0u8; MAX_SIGNATURE_SIZE]).pack();
+    let tx = transfer(alice_id, alice_id, 1, 0, 0)
+        .as_builder()
+        .signature(max_signature)
+        .build();
+    mem_pool.push_transaction(tx).unwrap();
+}
+
+#[test]
+fn test_withdrawal_nonces() {
+    let mut chain = setup_chain(Script::default(), Default::default());
+    let alice = user_script(42);
+    let action = produce_action(&chain, vec![deposit(alice.clone(), 500_00000000)], 1);
+    sync(&mut chain, vec![action], Vec::new());
+
+    let mut mem_pool = chain.mem_pool.lock();
+    mem_pool
+        .push_withdrawal_request(withdrawal(&alice, 100_00000000, 0))
+        .unwrap();
+    // the nonce is used by the pending withdrawal
+    let err = mem_pool
+        .push_withdrawal_request(withdrawal(&alice, 200_00000000, 0))
+        .unwrap_err();
+    assert_eq!(err, MemPoolError::NonceConflict { nonce: 0 });
+    // the nonce must follow the pending withdrawal
+    let err = mem_pool
+        .push_withdrawal_request(withdrawal(&alice, 200_00000000, 2))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        MemPoolError::NonceTooHigh {
+            expected: 1,
+            actual: 2
+        }
+    );
+    let withdrawals = vec![
+        withdrawal(&alice, 100_00000000, 0),
+        withdrawal(&alice, 200_00000000, 1),
+    ];
+    mem_pool
+        .push_withdrawal_request(withdrawals[1].clone())
+        .unwrap();
+
+    // the withdrawals are packaged in the nonce order
+    let block_result = construct_block(&chain, &mut mem_pool, Vec::new()).unwrap();
+    assert!(block_result.unused_withdrawal_requests.is_empty());
+    let packaged: Vec<WithdrawalRequest> = block_result.block.withdrawals().into_iter().collect();
+    assert_eq!(packaged, withdrawals);
+}
+
+#[test]
+fn test_txs_over_block_cycles_are_kept() {
+    let mut chain = setup_chain(Script::default(), Default::default());
+    let alice = user_script(42);
+    let bob = user_script(43);
+    let deposits = vec![
+        deposit(alice.clone(), 500_00000000),
+        deposit(bob.clone(), 500_00000000),
+    ];
+    let action = produce_action(&chain, deposits, 1);
+    sync(&mut chain, vec![action], Vec::new());
+    let (alice_id, bob_id) = {
+        let mem_pool = chain.mem_pool.lock();
+        let state = mem_pool.state_db().account_state_tree().unwrap();
+        let get_id = |script: &Script| {
+            state
+                .get_account_id_by_script_hash(&script.hash().into())
+                .unwrap()
+                .expect("account")
+        };
+        (get_id(&alice), get_id(&bob))
+    };
+
+    let mut mem_pool = chain.mem_pool.lock();
+    let txs = vec![
+        transfer(alice_id, bob_id, 10_00000000, 0, 0),
+        transfer(alice_id, bob_id, 20_00000000, 0, 1),
+    ];
+    for tx in &txs {
+        mem_pool.push_transaction(tx.clone()).unwrap();
+    }
+
+    // the first tx exceeds the cycles budget, the following one is not packaged either
+    let block_result =
+        construct_block_with_max_cycles(&chain, &mut mem_pool, Vec::new(), 0).unwrap();
+    assert!(block_result.unused_transactions.is_empty());
+    assert_eq!(block_result.block.transactions().len(), 0);
+    for tx in &txs {
+        let tx_hash: H256 = tx.hash().into();
+        assert_eq!(mem_pool.tx_status(&tx_hash), Some(&EntryStatus::Pending));
+    }
+
+    // both of them are packaged by the next block
+    let block_result = construct_block(&chain, &mut mem_pool, Vec::new()).unwrap();
+    assert!(block_result.unused_transactions.is_empty());
+    let packaged: Vec<L2Transaction> = block_result.block.transactions().into_iter().collect();
+    assert_eq!(packaged, txs);
+}
@@ -1,2 +1,5 @@
+mod allowed_scripts;
 mod deposition_withdrawal;
+mod log_index;
+mod mem_pool;
 mod sync;
@@ -1,7 +1,7 @@
 use crate::testing_tool::chain::{build_sync_tx, construct_block, setup_chain};
-use gw_chain::chain::{L1Action, L1ActionContext, RevertedL1Action, SyncEvent, SyncParam};
-use gw_common::{state::State, H256};
-use gw_store::state_db::StateDBVersion;
+use gw_chain::chain::{Chain, L1Action, L1ActionContext, RevertedL1Action, SyncEvent, SyncParam};
+use gw_common::{builtins::CKB_SUDT_ACCOUNT_ID, h256_ext::H256Ext, state::State, H256};
+use gw_store::state_db::{StateDBTransaction, StateDBVersion};
 use gw_types::{
     packed::{CellOutput, DepositionRequest, GlobalState, HeaderInfo, Script},
     prelude::*,
@@ -18,8 +18,8 @@ fn test_sync_a_block() {
         .script(user_script)
         .build();
     let block_result = {
-        let mem_pool = chain.mem_pool.lock();
-        construct_block(&chain, &mem_pool, vec![deposition.clone()]).unwrap()
+        let mut mem_pool = chain.mem_pool.lock();
+        construct_block(&chain, &mut mem_pool, vec![deposition.clone()]).unwrap()
     };
     assert_eq!(
         {
@@ -73,8 +73,9 @@ fn test_layer1_fork() {
             .script(charlie_script)
             .build();
         let chain = setup_chain(rollup_type_script.clone(), Default::default());
-        let mem_pool = chain.mem_pool.lock();
-        let block_result = construct_block(&chain, &mem_pool, vec![deposition.clone()]).unwrap();
+        let mut mem_pool = chain.mem_pool.lock();
+        let block_result =
+            construct_block(&chain, &mut mem_pool, vec![deposition.clone()]).unwrap();
 
         L1Action {
             context: L1ActionContext::SubmitTxs {
@@ -91,8 +92,8 @@ fn test_layer1_fork() {
         .script(alice_script)
         .build();
     let block_result = {
-        let mem_pool = chain.mem_pool.lock();
-        construct_block(&chain, &mem_pool, vec![deposition.clone()]).unwrap()
+        let mut mem_pool = chain.mem_pool.lock();
+        construct_block(&chain, &mut mem_pool, vec![deposition.clone()]).unwrap()
     };
     let action1 = L1Action {
         context: L1ActionContext::SubmitTxs {
@@ -114,8 +115,8 @@ fn test_layer1_fork() {
         .script(bob_script)
         .build();
     let block_result = {
-        let mem_pool = chain.mem_pool.lock();
-        construct_block(&chain, &mem_pool, vec![deposition.clone()]).unwrap()
+        let mut mem_pool = chain.mem_pool.lock();
+        construct_block(&chain, &mut mem_pool, vec![deposition.clone()]).unwrap()
     };
     let action2 = L1Action {
         context: L1ActionContext::SubmitTxs {
@@ -181,3 +182,168 @@ fn test_layer1_fork() {
         );
     }
 }
+
+#[test]
+fn test_revert_preimage_index() {
+    let rollup_type_script = Script::default();
+    let mut chain = setup_chain(rollup_type_script.clone(), Default::default());
+    let mut other_chain = setup_chain(rollup_type_script.clone(), Default::default());
+    {
+        let db = chain.store().begin_transaction();
+        db.enable_preimage_index().unwrap();
+        db.commit().unwrap();
+    }
+    let rollup_cell = CellOutput::new_builder()
+        .type_(Some(rollup_type_script).pack())
+        .build();
+    let build_action = |chain: &Chain, deposition: DepositionRequest, number: u64| {
+        let block_result = {
+            let mut mem_pool = chain.mem_pool.lock();
+            construct_block(chain, &mut mem_pool, vec![deposition.clone()]).unwrap()
+        };
+        L1Action {
+            context: L1ActionContext::SubmitTxs {
+                deposition_requests: vec![deposition],
+            },
+            transaction: build_sync_tx(rollup_cell.clone(), block_result),
+            header_info: HeaderInfo::new_builder().number(number.pack()).build(),
+        }
+    };
+    let alice_deposition = DepositionRequest::new_builder()
+        .capacity(100u64.pack())
+        .script(Script::new_builder().args(vec![42].pack()).build())
+        .build();
+    let bob_deposition = DepositionRequest::new_builder()
+        .capacity(100u64.pack())
+        .script(Script::new_builder().args(vec![43].pack()).build())
+        .build();
+
+    // block 1 creates alice, block 2 creates bob
+    let action1 = build_action(&chain, alice_deposition.clone(), 1);
+    for chain in &mut [&mut chain, &mut other_chain] {
+        let param = SyncParam {
+            updates: vec![action1.clone()],
+            reverts: Default::default(),
+        };
+        assert_eq!(chain.sync(param).unwrap(), SyncEvent::Success);
+    }
+    let action2 = build_action(&chain, bob_deposition, 2);
+    let param = SyncParam {
+        updates: vec![action2.clone()],
+        reverts: Default::default(),
+    };
+    assert_eq!(chain.sync(param).unwrap(), SyncEvent::Success);
+    let (alice_id, bob_id) = {
+        let db = chain.store().begin_transaction();
+        let state_db = StateDBTransaction::from_version(db, StateDBVersion::from_genesis());
+        let tree = state_db.account_state_tree().unwrap();
+        let account_id = |args: u8| {
+            let script_hash = Script::new_builder().args(vec![args].pack()).build().hash();
+            tree.get_account_id_by_script_hash(&script_hash.into())
+                .unwrap()
+                .expect("account")
+        };
+        (account_id(42), account_id(43))
+    };
+    let balance_keys = |chain: &Chain| {
+        chain
+            .store()
+            .begin_transaction()
+            .get_account_storage_keys(CKB_SUDT_ACCOUNT_ID)
+    };
+    let keys = balance_keys(&chain);
+    assert!(keys.contains(&H256::from_u32(alice_id)));
+    assert!(keys.contains(&H256::from_u32(bob_id)));
+
+    // the fork block 2 deposits to alice again, the balance key of bob is removed from the index
+    let fork_action2 = build_action(&other_chain, alice_deposition, 2);
+    let L1Action {
+        transaction,
+        header_info,
+        context,
+    } = action2;
+    let param = SyncParam {
+        updates: vec![fork_action2],
+        reverts: vec![RevertedL1Action {
+            prev_global_state: GlobalState::default(),
+            transaction,
+            header_info,
+            context,
+        }],
+    };
+    assert_eq!(chain.sync(param).unwrap(), SyncEvent::Success);
+    let keys = balance_keys(&chain);
+    assert!(keys.contains(&H256::from_u32(alice_id)));
+    assert!(!keys.contains(&H256::from_u32(bob_id)));
+}
+
+#[test]
+fn test_partial_revert_replays_tip_block() {
+    let rollup_type_script = Script::default();
+    let mut chain = setup_chain(rollup_type_script.clone(), Default::default());
+    let rollup_cell = CellOutput::new_builder()
+        .type_(Some(rollup_type_script).pack())
+        .build();
+    let user_script = |args: u8| Script::new_builder().args(vec![args].pack()).build();
+    let build_action = |chain: &Chain, args: u8, number: u64| {
+        let deposition = DepositionRequest::new_builder()
+            .capacity(100u64.pack())
+            .script(user_script(args))
+            .build();
+        let block_result = {
+            let mut mem_pool = chain.mem_pool.lock();
+            construct_block(chain, &mut mem_pool, vec![deposition.clone()]).unwrap()
+        };
+        L1Action {
+            context: L1ActionContext::SubmitTxs {
+                deposition_requests: vec![deposition],
+            },
+            transaction: build_sync_tx(rollup_cell.clone(), block_result),
+            header_info: HeaderInfo::new_builder().number(number.pack()).build(),
+        }
+    };
+
+    // block 1 ~ 3, each block creates an account
+    let mut actions = Vec::new();
+    for number in 1..=3u64 {
+        let action = build_action(&chain, number as u8, number);
+        let param = SyncParam {
+            updates: vec![action.clone()],
+            reverts: Default::default(),
+        };
+        assert_eq!(chain.sync(param).unwrap(), SyncEvent::Success);
+        actions.push(action);
+    }
+
+    // revert block 3 only, the state is replayed to the post state of block 2
+    let L1Action {
+        transaction,
+        header_info,
+        context,
+    } = actions.pop().unwrap();
+    let param = SyncParam {
+        updates: Vec::new(),
+        reverts: vec![RevertedL1Action {
+            prev_global_state: GlobalState::default(),
+            transaction,
+            header_info,
+            context,
+        }],
+    };
+    assert_eq!(chain.sync(param).unwrap(), SyncEvent::Success);
+    let tip_block_number: u64 = chain.local_state().tip().raw().number().unpack();
+    assert_eq!(tip_block_number, 2);
+    let db = chain.store().begin_transaction();
+    let state_db = StateDBTransaction::from_version(
+        db,
+        StateDBVersion::from_block_hash(chain.local_state().tip().hash().into()),
+    );
+    let tree = state_db.account_state_tree().unwrap();
+    let account_id = |args: u8| {
+        tree.get_account_id_by_script_hash(&user_script(args).hash().into())
+            .unwrap()
+    };
+    assert!(account_id(1).is_some());
+    assert!(account_id(2).is_some());
+    assert!(account_id(3).is_none());
+}
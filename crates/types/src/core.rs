@@ -105,3 +105,47 @@ impl TryFrom<Byte> for ChallengeTargetType {
         v.try_into()
     }
 }
+
+/// Log type
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[repr(u8)]
+pub enum LogType {
+    /// Emitted by contracts, the topics & data are defined by the contract
+    Contract = 0,
+    /// sUDT transfer, topics: [from_id, to_id], data: amount (u128 LE)
+    SudtTransfer = 1,
+    /// Account creation, topics: [script_hash], emitted by the new account
+    AccountCreated = 2,
+}
+
+impl Into<u8> for LogType {
+    fn into(self: LogType) -> u8 {
+        self as u8
+    }
+}
+
+impl TryFrom<u8> for LogType {
+    type Error = u8;
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(LogType::Contract),
+            1 => Ok(LogType::SudtTransfer),
+            2 => Ok(LogType::AccountCreated),
+            n => return Err(n),
+        }
+    }
+}
+
+impl Into<Byte> for LogType {
+    fn into(self: LogType) -> Byte {
+        (self as u8).into()
+    }
+}
+
+impl TryFrom<Byte> for LogType {
+    type Error = u8;
+    fn try_from(value: Byte) -> Result<Self, Self::Error> {
+        let v: u8 = value.into();
+        v.try_into()
+    }
+}
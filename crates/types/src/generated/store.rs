@@ -980,6 +980,8 @@ impl ::core::fmt::Display for LogItem {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "account_id", self.account_id())?;
+        write!(f, ", {}: {}", "log_type", self.log_type())?;
+        write!(f, ", {}: {}", "topics", self.topics())?;
         write!(f, ", {}: {}", "data", self.data())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
@@ -991,13 +993,14 @@ impl ::core::fmt::Display for LogItem {
 impl ::core::default::Default for LogItem {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            20, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            33, 0, 0, 0, 20, 0, 0, 0, 24, 0, 0, 0, 25, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
         ];
         LogItem::new_unchecked(v.into())
     }
 }
 impl LogItem {
-    pub const FIELD_COUNT: usize = 2;
+    pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1020,11 +1023,23 @@ impl LogItem {
         let end = molecule::unpack_number(&slice[8..]) as usize;
         Uint32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn data(&self) -> Bytes {
+    pub fn log_type(&self) -> Byte {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn topics(&self) -> Byte32Vec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte32Vec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn data(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
+            let end = molecule::unpack_number(&slice[20..]) as usize;
             Bytes::new_unchecked(self.0.slice(start..end))
         } else {
             Bytes::new_unchecked(self.0.slice(start..))
@@ -1058,6 +1073,8 @@ impl molecule::prelude::Entity for LogItem {
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
             .account_id(self.account_id())
+            .log_type(self.log_type())
+            .topics(self.topics())
             .data(self.data())
     }
 }
@@ -1081,6 +1098,8 @@ impl<'r> ::core::fmt::Display for LogItemReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "account_id", self.account_id())?;
+        write!(f, ", {}: {}", "log_type", self.log_type())?;
+        write!(f, ", {}: {}", "topics", self.topics())?;
         write!(f, ", {}: {}", "data", self.data())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
@@ -1090,7 +1109,7 @@ impl<'r> ::core::fmt::Display for LogItemReader<'r> {
     }
 }
 impl<'r> LogItemReader<'r> {
-    pub const FIELD_COUNT: usize = 2;
+    pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1113,11 +1132,23 @@ impl<'r> LogItemReader<'r> {
         let end = molecule::unpack_number(&slice[8..]) as usize;
         Uint32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn data(&self) -> BytesReader<'r> {
+    pub fn log_type(&self) -> ByteReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn topics(&self) -> Byte32VecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte32VecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn data(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
+            let end = molecule::unpack_number(&slice[20..]) as usize;
             BytesReader::new_unchecked(&self.as_slice()[start..end])
         } else {
             BytesReader::new_unchecked(&self.as_slice()[start..])
@@ -1176,21 +1207,33 @@ impl<'r> molecule::prelude::Reader<'r> for LogItemReader<'r> {
             return ve!(Self, OffsetsNotMatch);
         }
         Uint32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        BytesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        ByteReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Byte32VecReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        BytesReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
 pub struct LogItemBuilder {
     pub(crate) account_id: Uint32,
+    pub(crate) log_type: Byte,
+    pub(crate) topics: Byte32Vec,
     pub(crate) data: Bytes,
 }
 impl LogItemBuilder {
-    pub const FIELD_COUNT: usize = 2;
+    pub const FIELD_COUNT: usize = 4;
     pub fn account_id(mut self, v: Uint32) -> Self {
         self.account_id = v;
         self
     }
+    pub fn log_type(mut self, v: Byte) -> Self {
+        self.log_type = v;
+        self
+    }
+    pub fn topics(mut self, v: Byte32Vec) -> Self {
+        self.topics = v;
+        self
+    }
     pub fn data(mut self, v: Bytes) -> Self {
         self.data = v;
         self
@@ -1202,6 +1245,8 @@ impl molecule::prelude::Builder for LogItemBuilder {
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
             + self.account_id.as_slice().len()
+            + self.log_type.as_slice().len()
+            + self.topics.as_slice().len()
             + self.data.as_slice().len()
     }
     fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
@@ -1210,12 +1255,18 @@ impl molecule::prelude::Builder for LogItemBuilder {
         offsets.push(total_size);
         total_size += self.account_id.as_slice().len();
         offsets.push(total_size);
+        total_size += self.log_type.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.topics.as_slice().len();
+        offsets.push(total_size);
         total_size += self.data.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
         writer.write_all(self.account_id.as_slice())?;
+        writer.write_all(self.log_type.as_slice())?;
+        writer.write_all(self.topics.as_slice())?;
         writer.write_all(self.data.as_slice())?;
         Ok(())
     }
@@ -1591,6 +1642,7 @@ impl ::core::fmt::Display for TxReceipt {
         )?;
         write!(f, ", {}: {}", "read_data_hashes", self.read_data_hashes())?;
         write!(f, ", {}: {}", "logs", self.logs())?;
+        write!(f, ", {}: {}", "used_cycles", self.used_cycles())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1601,16 +1653,16 @@ impl ::core::fmt::Display for TxReceipt {
 impl ::core::default::Default for TxReceipt {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            92, 0, 0, 0, 20, 0, 0, 0, 52, 0, 0, 0, 84, 0, 0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            104, 0, 0, 0, 24, 0, 0, 0, 56, 0, 0, 0, 88, 0, 0, 0, 92, 0, 0, 0, 96, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 4, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
         TxReceipt::new_unchecked(v.into())
     }
 }
 impl TxReceipt {
-    pub const FIELD_COUNT: usize = 4;
+    pub const FIELD_COUNT: usize = 5;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1648,11 +1700,17 @@ impl TxReceipt {
     pub fn logs(&self) -> LogItemVec {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        LogItemVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn used_cycles(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[20..]) as usize;
-            LogItemVec::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[24..]) as usize;
+            Uint64::new_unchecked(self.0.slice(start..end))
         } else {
-            LogItemVec::new_unchecked(self.0.slice(start..))
+            Uint64::new_unchecked(self.0.slice(start..))
         }
     }
     pub fn as_reader<'r>(&'r self) -> TxReceiptReader<'r> {
@@ -1686,6 +1744,7 @@ impl molecule::prelude::Entity for TxReceipt {
             .compacted_post_account_root(self.compacted_post_account_root())
             .read_data_hashes(self.read_data_hashes())
             .logs(self.logs())
+            .used_cycles(self.used_cycles())
     }
 }
 #[derive(Clone, Copy)]
@@ -1716,6 +1775,7 @@ impl<'r> ::core::fmt::Display for TxReceiptReader<'r> {
         )?;
         write!(f, ", {}: {}", "read_data_hashes", self.read_data_hashes())?;
         write!(f, ", {}: {}", "logs", self.logs())?;
+        write!(f, ", {}: {}", "used_cycles", self.used_cycles())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1724,7 +1784,7 @@ impl<'r> ::core::fmt::Display for TxReceiptReader<'r> {
     }
 }
 impl<'r> TxReceiptReader<'r> {
-    pub const FIELD_COUNT: usize = 4;
+    pub const FIELD_COUNT: usize = 5;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1762,11 +1822,17 @@ impl<'r> TxReceiptReader<'r> {
     pub fn logs(&self) -> LogItemVecReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        LogItemVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn used_cycles(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[20..]) as usize;
-            LogItemVecReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[24..]) as usize;
+            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            LogItemVecReader::new_unchecked(&self.as_slice()[start..])
+            Uint64Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
@@ -1825,6 +1891,7 @@ impl<'r> molecule::prelude::Reader<'r> for TxReceiptReader<'r> {
         Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Byte32VecReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         LogItemVecReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
         Ok(())
     }
 }
@@ -1834,9 +1901,10 @@ pub struct TxReceiptBuilder {
     pub(crate) compacted_post_account_root: Byte32,
     pub(crate) read_data_hashes: Byte32Vec,
     pub(crate) logs: LogItemVec,
+    pub(crate) used_cycles: Uint64,
 }
 impl TxReceiptBuilder {
-    pub const FIELD_COUNT: usize = 4;
+    pub const FIELD_COUNT: usize = 5;
     pub fn tx_witness_hash(mut self, v: Byte32) -> Self {
         self.tx_witness_hash = v;
         self
@@ -1853,6 +1921,10 @@ impl TxReceiptBuilder {
         self.logs = v;
         self
     }
+    pub fn used_cycles(mut self, v: Uint64) -> Self {
+        self.used_cycles = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for TxReceiptBuilder {
     type Entity = TxReceipt;
@@ -1863,6 +1935,7 @@ impl molecule::prelude::Builder for TxReceiptBuilder {
             + self.compacted_post_account_root.as_slice().len()
             + self.read_data_hashes.as_slice().len()
             + self.logs.as_slice().len()
+            + self.used_cycles.as_slice().len()
     }
     fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -1875,6 +1948,8 @@ impl molecule::prelude::Builder for TxReceiptBuilder {
         total_size += self.read_data_hashes.as_slice().len();
         offsets.push(total_size);
         total_size += self.logs.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.used_cycles.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
@@ -1883,6 +1958,7 @@ impl molecule::prelude::Builder for TxReceiptBuilder {
         writer.write_all(self.compacted_post_account_root.as_slice())?;
         writer.write_all(self.read_data_hashes.as_slice())?;
         writer.write_all(self.logs.as_slice())?;
+        writer.write_all(self.used_cycles.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
@@ -8,10 +8,12 @@ pub mod core;
 mod extension;
 mod generated;
 pub mod prelude;
+mod signature_message;
 mod std_traits;
 
 pub use generated::packed;
 pub use molecule::bytes;
+pub use signature_message::MAX_SIGNATURE_SIZE;
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "std")] {
@@ -19,8 +21,6 @@ cfg_if::cfg_if! {
         use std::borrow;
         use std::str;
         use std::string;
-
-        mod signature_message;
     } else {
         use alloc::vec;
         use alloc::borrow;
@@ -4,6 +4,10 @@ use sparse_merkle_tree::H256;
 use crate::packed::{RawL2Transaction, RawWithdrawalRequest};
 use crate::prelude::*;
 
+/// The max size of the signature of a L2 transaction or withdrawal request,
+/// the signature is variable-length bytes and the format is defined by the account lock.
+pub const MAX_SIGNATURE_SIZE: usize = 4096;
+
 impl RawL2Transaction {
     pub fn calc_message(
         &self,